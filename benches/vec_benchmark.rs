@@ -1,7 +1,7 @@
 use std::hash::{BuildHasher, RandomState};
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use no_std_collections::traits::vec::Vec as VecTrait;
+use no_std_collections::{traits::vec::Vec as VecTrait, StackVec};
 use rand::{Rng, RngCore};
 
 #[cfg(feature = "std")]
@@ -15,8 +15,8 @@ mod bench{
                 || {
                     let rng = RandomState::new();
                     let mut res = [0; 1000];
-                    for i in 0..1000 {
-                        res[i] = rng.hash_one(i);
+                    for (i, slot) in res.iter_mut().enumerate() {
+                        *slot = rng.hash_one(i);
                     }
                     (res, Vec::with_capacity(1000))
                 },
@@ -34,8 +34,8 @@ mod bench{
                 || {
                     let rng = RandomState::new();
                     let mut res = [0; 1000];
-                    for i in 0..1000 {
-                        res[i] = rng.hash_one(i);
+                    for (i, slot) in res.iter_mut().enumerate() {
+                        *slot = rng.hash_one(i);
                     }
                     (res, Vec::with_capacity(1000))
                 },
@@ -59,7 +59,7 @@ mod bench{
             b.iter_batched(
                 || {
                     let rng = RandomState::new();
-                    let res: Vec<u64> = (0..1000).into_iter().map(|i| rng.hash_one(i)).collect();
+                    let res: Vec<u64> = (0..1000).map(|i| rng.hash_one(i)).collect();
                     res
                 },
                 |mut vec| {
@@ -74,7 +74,7 @@ mod bench{
             b.iter_batched(
                 || {
                     let rng = RandomState::new();
-                    let res: Vec<u64> = (0..1000).into_iter().map(|i| rng.hash_one(i)).collect();
+                    let res: Vec<u64> = (0..1000).map(|i| rng.hash_one(i)).collect();
                     res
                 },
                 |mut vec| {
@@ -97,8 +97,8 @@ mod bench{
             b.iter_batched(
                 || {
                     (
-                        (1001..1).into_iter().map(|i| rng.gen_range(0..i)).collect(),
-                        (0..1000).into_iter().map(|_| rng.next_u32()).collect(),
+                        (1..1001).map(|i| rng.gen_range(0..i)).collect(),
+                        (0..1000).map(|_| rng.next_u32()).collect(),
                     )
                 },
                 |(order, values): (Vec<usize>, Vec<u32>)| {
@@ -115,8 +115,8 @@ mod bench{
             b.iter_batched(
                 || {
                     (
-                        (1001..1).into_iter().map(|i| rng.gen_range(0..i)).collect(),
-                        (0..1000).into_iter().map(|_| rng.next_u32()).collect(),
+                        (1..1001).map(|i| rng.gen_range(0..i)).collect(),
+                        (0..1000).map(|_| rng.next_u32()).collect(),
                     )
                 },
                 |(order, values): (Vec<usize>, Vec<u32>)| {
@@ -140,7 +140,7 @@ mod bench{
         group.bench_function("Checked", |b| {
             b.iter_batched(
                 || {
-                        (0..1000).into_iter().map(|_| rng.next_u32()).collect()
+                        (0..1000).map(|_| rng.next_u32()).collect()
                     
                 },
                 |values: Vec<u32>| {
@@ -155,7 +155,7 @@ mod bench{
         group.bench_function("Unchecked", |b| {
             b.iter_batched(
                 || {
-                        (0..1000).into_iter().map(|_| rng.next_u32()).collect()
+                        (0..1000).map(|_| rng.next_u32()).collect()
                     
                 },
                 |values: Vec<u32>| {
@@ -171,9 +171,133 @@ mod bench{
         group.finish();
     }
     
+    pub fn sort(c: &mut Criterion) {
+        let mut group = c.benchmark_group("Stable Sort");
+        let mut rng = rand::thread_rng();
+    
+        group.bench_function("Vec", |b| {
+            b.iter_batched(
+                || (0..1000).map(|_| rng.next_u32()).collect(),
+                |mut vec: Vec<u32>| {
+                    vec.sort();
+                    black_box(vec);
+                },
+                criterion::BatchSize::NumIterations(100),
+            );
+        });
+        group.bench_function("StackVec", |b| {
+            b.iter_batched(
+                || (0..1000).map(|_| rng.next_u32()).collect(),
+                |mut vec: StackVec<u32, 2000>| {
+                    vec.sort_stable_in_place();
+                    black_box(vec);
+                },
+                criterion::BatchSize::NumIterations(100),
+            );
+        });
+        group.bench_function("StackVec Full", |b| {
+            b.iter_batched(
+                || (0..1000).map(|_| rng.next_u32()).collect(),
+                |mut vec: StackVec<u32, 1000>| {
+                    vec.sort_stable_in_place();
+                    black_box(vec);
+                },
+                criterion::BatchSize::NumIterations(100),
+            );
+        });
+    
+        group.finish();
+    }
+    
+    pub fn radix_sort(c: &mut Criterion) {
+        let mut group = c.benchmark_group("Radix Sort");
+        let mut rng = rand::thread_rng();
+    
+        group.bench_function("sort_unstable u32", |b| {
+            b.iter_batched(
+                || (0..1000).map(|_| rng.next_u32()).collect(),
+                |mut vec: StackVec<u32, 1000>| {
+                    vec.sort_unstable();
+                    black_box(vec);
+                },
+                criterion::BatchSize::NumIterations(100),
+            );
+        });
+        group.bench_function("radix_sort u32", |b| {
+            b.iter_batched(
+                || (0..1000).map(|_| rng.next_u32()).collect(),
+                |mut vec: StackVec<u32, 2000>| {
+                    vec.radix_sort();
+                    black_box(vec);
+                },
+                criterion::BatchSize::NumIterations(100),
+            );
+        });
+        group.bench_function("sort_unstable u16", |b| {
+            b.iter_batched(
+                || (0..1000).map(|_| rng.gen::<u16>()).collect(),
+                |mut vec: StackVec<u16, 1000>| {
+                    vec.sort_unstable();
+                    black_box(vec);
+                },
+                criterion::BatchSize::NumIterations(100),
+            );
+        });
+        group.bench_function("radix_sort_with u16", |b| {
+            b.iter_batched(
+                || (0..1000).map(|_| rng.gen::<u16>()).collect(),
+                |mut vec: StackVec<u16, 1000>| {
+                    let mut scratch = StackVec::<u16, 1000>::new();
+                    vec.radix_sort_with(&mut scratch);
+                    black_box(vec);
+                },
+                criterion::BatchSize::NumIterations(100),
+            );
+        });
+    
+        group.finish();
+    }
+    
+    pub fn sort_by_cached_key(c: &mut Criterion) {
+        let mut group = c.benchmark_group("Sort By Cached Key");
+        let mut rng = rand::thread_rng();
+    
+        group.bench_function("Vec", |b| {
+            b.iter_batched(
+                || (0..1000).map(|_| rng.next_u32()).collect(),
+                |mut vec: Vec<u32>| {
+                    vec.sort_by_cached_key(|n| n.to_string());
+                    black_box(vec);
+                },
+                criterion::BatchSize::NumIterations(100),
+            );
+        });
+        group.bench_function("StackVec", |b| {
+            b.iter_batched(
+                || (0..1000).map(|_| rng.next_u32()).collect(),
+                |mut vec: StackVec<u32, 1000>| {
+                    vec.sort_by_cached_key(|n| n.to_string());
+                    black_box(vec);
+                },
+                criterion::BatchSize::NumIterations(100),
+            );
+        });
+    
+        group.finish();
+    }
+    
 }
 #[cfg(feature = "std")]
-criterion_group!(benches, bench::insert, bench::push, bench::remove, bench::with_capacity);
+criterion_group!(
+    benches,
+    bench::insert,
+    bench::push,
+    bench::remove,
+    bench::with_capacity,
+    bench::sort,
+    bench::radix_sort,
+    bench::sort_by_cached_key
+);
 
 #[cfg(feature = "std")]
 criterion_main!(benches);
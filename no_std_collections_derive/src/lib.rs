@@ -0,0 +1,283 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields};
+
+/// Generates a struct-of-arrays container for a struct with named fields.
+///
+/// For `struct Particle { .. }` this emits:
+///
+/// - `ParticleSoaIn<S: Storage>`, holding one column per field and keeping
+///   them in lock-step, with `{field}_column()` and `{field}_column_mut()`
+///   slice access;
+/// - `ParticleSoa<const N: usize>`, the same over `StackVec<_, N>` columns;
+/// - `ParticleRef<'a>` and `ParticleRefMut<'a>`, references to one row.
+#[proc_macro_derive(Soa)]
+pub fn derive_soa(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "`Soa` can not be derived for generic structs",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) if !fields.named.is_empty() => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "`Soa` needs a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "`Soa` can only be derived for structs",
+            ))
+        }
+    };
+
+    let krate = quote!(::no_std_collections);
+    let vec = quote!(#krate::traits::vec::Vec);
+    let slice_owner = quote!(#krate::traits::SliceOwner);
+    let storage = quote!(#krate::soa::Storage);
+
+    let vis = &input.vis;
+    let name = &input.ident;
+    let soa_in = format_ident!("{}SoaIn", name);
+    let soa = format_ident!("{}Soa", name);
+    let row_ref = format_ident!("{}Ref", name);
+    let row_mut = format_ident!("{}RefMut", name);
+
+    let idents: Vec<_> = fields
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap())
+        .collect();
+    let tys: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let field_vis: Vec<_> = fields.iter().map(|field| &field.vis).collect();
+    // No generated method ends in `_column`, so these can not collide.
+    let columns: Vec<_> = idents
+        .iter()
+        .map(|ident| format_ident!("{}_column", ident))
+        .collect();
+    let columns_mut: Vec<_> = idents
+        .iter()
+        .map(|ident| format_ident!("{}_column_mut", ident))
+        .collect();
+    let first = idents[0];
+
+    let soa_doc = format!("The fields of [`{name}`] stored as one column each, in `S`.");
+    let alias_doc = format!("A [`{soa_in}`] storing up to `N` rows inline.");
+    let ref_doc = format!("References to the fields of one row of a [`{soa_in}`].");
+    let mut_doc = format!("Mutable references to the fields of one row of a [`{soa_in}`].");
+
+    Ok(quote! {
+        #[doc = #soa_doc]
+        #vis struct #soa_in<S: #storage> {
+            #( #idents: S::Column<#tys>, )*
+        }
+
+        #[doc = #alias_doc]
+        #vis type #soa<const N: ::core::primitive::usize> = #soa_in<#krate::soa::Inline<N>>;
+
+        #[doc = #ref_doc]
+        #[derive(::core::clone::Clone, ::core::marker::Copy)]
+        #vis struct #row_ref<'a> {
+            #( #field_vis #idents: &'a #tys, )*
+        }
+
+        #[doc = #mut_doc]
+        #vis struct #row_mut<'a> {
+            #( #field_vis #idents: &'a mut #tys, )*
+        }
+
+        impl<S: #storage> #soa_in<S> {
+            #[inline]
+            pub fn new() -> Self {
+                Self {
+                    #( #idents: ::core::default::Default::default(), )*
+                }
+            }
+
+            #[inline]
+            pub fn len(&self) -> ::core::primitive::usize {
+                #slice_owner::len(&self.#first)
+            }
+
+            #[inline]
+            pub fn is_empty(&self) -> ::core::primitive::bool {
+                self.len() == 0
+            }
+
+            /// Rows that fit in every column without growing it.
+            #[inline]
+            pub fn capacity(&self) -> ::core::primitive::usize {
+                let capacity = ::core::primitive::usize::MAX;
+                #( let capacity = capacity.min(#vec::capacity(&self.#idents)); )*
+                capacity
+            }
+
+            /// # Panics
+            ///
+            /// Panics if a column can not grow.
+            #[inline]
+            pub fn push(&mut self, row: #name) {
+                if self.try_push(row).is_err() {
+                    ::core::panic!("Capacity overflow")
+                }
+            }
+
+            /// Appends `row`, or gives it back untouched if a column can not
+            /// grow.
+            pub fn try_push(
+                &mut self,
+                row: #name,
+            ) -> ::core::result::Result<(), #krate::traits::vec::CapacityError<#name>> {
+                #(
+                    if #vec::try_reserve(&mut self.#idents, 1).is_err() {
+                        return ::core::result::Result::Err(#krate::traits::vec::CapacityError::new(row));
+                    }
+                )*
+                let #name { #( #idents ),* } = row;
+                #( #vec::push(&mut self.#idents, #idents); )*
+                ::core::result::Result::Ok(())
+            }
+
+            /// # Panics
+            ///
+            /// Panics if a column was left shorter than the first one, which
+            /// only code in the defining module can do.
+            pub fn pop(&mut self) -> ::core::option::Option<#name> {
+                if self.is_empty() {
+                    return ::core::option::Option::None;
+                }
+                ::core::option::Option::Some(#name {
+                    #(
+                        #idents: #vec::pop(&mut self.#idents)
+                            .expect("`Soa` columns out of step"),
+                    )*
+                })
+            }
+
+            /// Removes row `index`, moving the last row into its place.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is out of bounds.
+            #[track_caller]
+            pub fn swap_remove(&mut self, index: ::core::primitive::usize) -> #name {
+                let len = self.len();
+                ::core::assert!(
+                    index < len,
+                    "swap_remove index (is {index}) should be < len (is {len})"
+                );
+                #name {
+                    #( #idents: #vec::swap_remove(&mut self.#idents, index), )*
+                }
+            }
+
+            #[inline]
+            pub fn truncate(&mut self, len: ::core::primitive::usize) {
+                #( #vec::truncate(&mut self.#idents, len); )*
+            }
+
+            #[inline]
+            pub fn clear(&mut self) {
+                #( #vec::clear(&mut self.#idents); )*
+            }
+
+            pub fn get(&self, index: ::core::primitive::usize) -> ::core::option::Option<#row_ref<'_>> {
+                if index >= self.len() {
+                    return ::core::option::Option::None;
+                }
+                ::core::option::Option::Some(#row_ref {
+                    #( #idents: &#slice_owner::as_slice(&self.#idents)[index], )*
+                })
+            }
+
+            pub fn get_mut(&mut self, index: ::core::primitive::usize) -> ::core::option::Option<#row_mut<'_>> {
+                if index >= self.len() {
+                    return ::core::option::Option::None;
+                }
+                ::core::option::Option::Some(#row_mut {
+                    #( #idents: &mut #slice_owner::as_mut_slice(&mut self.#idents)[index], )*
+                })
+            }
+
+            pub fn iter(&self) -> impl ::core::iter::Iterator<Item = #row_ref<'_>> + '_ {
+                #( let mut #idents = #slice_owner::as_slice(&self.#idents).iter(); )*
+                ::core::iter::from_fn(move || {
+                    ::core::option::Option::Some(#row_ref {
+                        #( #idents: #idents.next()?, )*
+                    })
+                })
+            }
+
+            pub fn iter_mut(&mut self) -> impl ::core::iter::Iterator<Item = #row_mut<'_>> + '_ {
+                #( let mut #idents = #slice_owner::as_mut_slice(&mut self.#idents).iter_mut(); )*
+                ::core::iter::from_fn(move || {
+                    ::core::option::Option::Some(#row_mut {
+                        #( #idents: #idents.next()?, )*
+                    })
+                })
+            }
+
+            #(
+                #[inline]
+                #field_vis fn #columns(&self) -> &[#tys] {
+                    #slice_owner::as_slice(&self.#idents)
+                }
+
+                #[inline]
+                #field_vis fn #columns_mut(&mut self) -> &mut [#tys] {
+                    #slice_owner::as_mut_slice(&mut self.#idents)
+                }
+            )*
+        }
+
+        impl<S: #storage> ::core::default::Default for #soa_in<S> {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<S: #storage> ::core::clone::Clone for #soa_in<S>
+        where
+            #( S::Column<#tys>: ::core::clone::Clone, )*
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self {
+                    #( #idents: ::core::clone::Clone::clone(&self.#idents), )*
+                }
+            }
+        }
+
+        impl<S: #storage> ::core::iter::Extend<#name> for #soa_in<S> {
+            #[inline]
+            fn extend<I: ::core::iter::IntoIterator<Item = #name>>(&mut self, iter: I) {
+                for row in iter {
+                    self.push(row);
+                }
+            }
+        }
+
+        impl<S: #storage> ::core::iter::FromIterator<#name> for #soa_in<S> {
+            #[inline]
+            fn from_iter<I: ::core::iter::IntoIterator<Item = #name>>(iter: I) -> Self {
+                let mut soa = Self::new();
+                soa.extend(iter);
+                soa
+            }
+        }
+    })
+}
@@ -0,0 +1,420 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    iter::FusedIterator,
+    mem::{self, ManuallyDrop},
+    ops::{Deref, DerefMut},
+    ptr, slice,
+};
+
+use crate::traits::{
+    compare::Natural,
+    vec::{CapacityError, Vec},
+    Comparator,
+};
+
+/// A priority queue over a [`Vec`] implementor, keeping the greatest item
+/// according to `C` on top.
+///
+/// Use [`Reversed`](crate::traits::compare::Reversed) for a min-heap.
+pub struct BinaryHeap<V, C = Natural> {
+    data: V,
+    cmp: C,
+}
+
+impl<V: Vec + Default> BinaryHeap<V, Natural> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            data: V::default(),
+            cmp: Natural,
+        }
+    }
+}
+
+impl<V: Vec> BinaryHeap<V, Natural>
+where
+    V::Item: Ord,
+{
+    /// Turns `vec` into a heap in O(n).
+    #[inline]
+    pub fn from_vec(vec: V) -> Self {
+        Self::from_vec_by(vec, Natural)
+    }
+}
+
+impl<V: Vec, C: Comparator<V::Item>> BinaryHeap<V, C> {
+    #[inline]
+    pub fn with_comparator(cmp: C) -> Self
+    where
+        V: Default,
+    {
+        Self {
+            data: V::default(),
+            cmp,
+        }
+    }
+
+    /// Turns `vec` into a heap ordered by `cmp` in O(n).
+    pub fn from_vec_by(vec: V, cmp: C) -> Self {
+        let mut heap = Self { data: vec, cmp };
+        heap.rebuild();
+        heap
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    #[inline]
+    pub fn comparator(&self) -> &C {
+        &self.cmp
+    }
+
+    /// The items, in no particular order.
+    #[inline]
+    pub fn as_slice(&self) -> &[V::Item] {
+        self.data.as_slice()
+    }
+
+    /// The items, in no particular order.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, V::Item> {
+        self.data.as_slice().iter()
+    }
+
+    #[inline]
+    pub fn into_vec(self) -> V {
+        self.data
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    #[inline]
+    pub fn peek(&self) -> Option<&V::Item> {
+        self.data.as_slice().first()
+    }
+
+    /// Mutable access to the greatest item. The heap is fixed up when the
+    /// returned guard is dropped.
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, V, C>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(PeekMut { heap: self })
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the backing vector can not grow.
+    #[inline]
+    pub fn push(&mut self, item: V::Item) {
+        let old_len = self.len();
+        self.data.push(item);
+        self.sift_up(0, old_len);
+    }
+
+    /// Like [`push`](BinaryHeap::push), but gives `item` back instead of
+    /// panicking when the backing vector is full.
+    #[inline]
+    pub fn try_push(&mut self, item: V::Item) -> Result<(), CapacityError<V::Item>> {
+        let old_len = self.len();
+        self.data.try_push(item)?;
+        self.sift_up(0, old_len);
+        Ok(())
+    }
+
+    /// Removes the greatest item.
+    pub fn pop(&mut self) -> Option<V::Item> {
+        self.data.pop().map(|mut item| {
+            if !self.is_empty() {
+                mem::swap(&mut item, &mut self.data.as_mut_slice()[0]);
+                self.sift_down(0);
+            }
+            item
+        })
+    }
+
+    /// The items sorted in ascending order.
+    pub fn into_sorted_vec(mut self) -> V {
+        let mut end = self.len();
+        while end > 1 {
+            end -= 1;
+            self.data.as_mut_slice().swap(0, end);
+            self.sift_down_range(0, end);
+        }
+        self.data
+    }
+
+    /// Removes the items greatest first. The ones not yielded are dropped
+    /// along with the iterator.
+    #[inline]
+    pub fn drain_sorted(&mut self) -> DrainSorted<'_, V, C> {
+        DrainSorted { heap: self }
+    }
+
+    /// Inserts every item of `iter`, stopping at the first one that does not
+    /// fit and returning it.
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), CapacityError<V::Item>>
+    where
+        I: IntoIterator<Item = V::Item>,
+    {
+        for item in iter {
+            self.try_push(item)?;
+        }
+        Ok(())
+    }
+
+    fn rebuild(&mut self) {
+        let len = self.len();
+        let mut n = len / 2;
+        while n > 0 {
+            n -= 1;
+            self.sift_down_range(n, len);
+        }
+    }
+
+    /// Moves the item at `pos` up towards `start`, returning where it ends.
+    fn sift_up(&mut self, start: usize, pos: usize) -> usize {
+        let cmp = &self.cmp;
+        let mut hole = Hole::new(self.data.as_mut_slice(), pos);
+        while hole.pos > start {
+            let parent = (hole.pos - 1) / 2;
+            if cmp.compare(hole.element(), hole.get(parent)) != Ordering::Greater {
+                break;
+            }
+            hole.move_to(parent);
+        }
+        hole.pos
+    }
+
+    /// Moves the item at `pos` down, among the items before `end`.
+    fn sift_down_range(&mut self, pos: usize, end: usize) {
+        let cmp = &self.cmp;
+        let mut hole = Hole::new(self.data.as_mut_slice(), pos);
+        let mut child = 2 * hole.pos + 1;
+        while child + 1 < end {
+            if cmp.compare(hole.get(child), hole.get(child + 1)) != Ordering::Greater {
+                child += 1;
+            }
+            if cmp.compare(hole.element(), hole.get(child)) != Ordering::Less {
+                return;
+            }
+            hole.move_to(child);
+            child = 2 * hole.pos + 1;
+        }
+        if child + 1 == end && cmp.compare(hole.element(), hole.get(child)) == Ordering::Less {
+            hole.move_to(child);
+        }
+    }
+
+    #[inline]
+    fn sift_down(&mut self, pos: usize) {
+        let len = self.len();
+        self.sift_down_range(pos, len);
+    }
+}
+
+/// An item taken out of a slice, leaving a hole that is filled back when
+/// dropped, even if a comparison panics.
+struct Hole<'a, T> {
+    data: &'a mut [T],
+    element: ManuallyDrop<T>,
+    pos: usize,
+}
+
+impl<'a, T> Hole<'a, T> {
+    #[inline]
+    fn new(data: &'a mut [T], pos: usize) -> Self {
+        let element = unsafe { ptr::read(&data[pos]) };
+        Self {
+            data,
+            element: ManuallyDrop::new(element),
+            pos,
+        }
+    }
+
+    #[inline]
+    fn element(&self) -> &T {
+        &self.element
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> &T {
+        debug_assert!(index != self.pos);
+        &self.data[index]
+    }
+
+    /// Moves the item at `index` into the hole, which takes its place.
+    #[inline]
+    fn move_to(&mut self, index: usize) {
+        debug_assert!(index != self.pos);
+        let ptr = self.data.as_mut_ptr();
+        unsafe { ptr::copy_nonoverlapping(ptr.add(index), ptr.add(self.pos), 1) };
+        self.pos = index;
+    }
+}
+
+impl<T> Drop for Hole<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { ptr::copy_nonoverlapping(&*self.element, &mut self.data[self.pos], 1) };
+    }
+}
+
+/// Mutable access to the greatest item of a [`BinaryHeap`], returned by
+/// [`BinaryHeap::peek_mut`].
+pub struct PeekMut<'a, V: Vec, C: Comparator<V::Item>> {
+    heap: &'a mut BinaryHeap<V, C>,
+}
+
+impl<V: Vec, C: Comparator<V::Item>> PeekMut<'_, V, C> {
+    /// Removes the peeked item from the heap.
+    #[inline]
+    pub fn pop(this: Self) -> V::Item {
+        let this = ManuallyDrop::new(this);
+        let heap = unsafe { ptr::read(&this.heap) };
+        heap.pop().unwrap()
+    }
+}
+
+impl<V: Vec, C: Comparator<V::Item>> Deref for PeekMut<'_, V, C> {
+    type Target = V::Item;
+
+    #[inline]
+    fn deref(&self) -> &V::Item {
+        &self.heap.data.as_slice()[0]
+    }
+}
+
+impl<V: Vec, C: Comparator<V::Item>> DerefMut for PeekMut<'_, V, C> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut V::Item {
+        &mut self.heap.data.as_mut_slice()[0]
+    }
+}
+
+impl<V: Vec, C: Comparator<V::Item>> Drop for PeekMut<'_, V, C> {
+    #[inline]
+    fn drop(&mut self) {
+        self.heap.sift_down(0);
+    }
+}
+
+impl<V: Vec<Item: fmt::Debug>, C: Comparator<V::Item>> fmt::Debug for PeekMut<'_, V, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PeekMut").field(&**self).finish()
+    }
+}
+
+/// Draining iterator returned by [`BinaryHeap::drain_sorted`].
+pub struct DrainSorted<'a, V: Vec, C: Comparator<V::Item>> {
+    heap: &'a mut BinaryHeap<V, C>,
+}
+
+impl<V: Vec, C: Comparator<V::Item>> Iterator for DrainSorted<'_, V, C> {
+    type Item = V::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<V::Item> {
+        self.heap.pop()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.heap.len();
+        (len, Some(len))
+    }
+}
+
+impl<V: Vec, C: Comparator<V::Item>> ExactSizeIterator for DrainSorted<'_, V, C> {}
+
+impl<V: Vec, C: Comparator<V::Item>> FusedIterator for DrainSorted<'_, V, C> {}
+
+impl<V: Vec, C: Comparator<V::Item>> Drop for DrainSorted<'_, V, C> {
+    #[inline]
+    fn drop(&mut self) {
+        self.heap.clear();
+    }
+}
+
+impl<V: Vec + Default> Default for BinaryHeap<V, Natural> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Clone, C: Clone> Clone for BinaryHeap<V, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            cmp: self.cmp.clone(),
+        }
+    }
+}
+
+impl<V: Vec<Item: fmt::Debug>, C> fmt::Debug for BinaryHeap<V, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.data.as_slice()).finish()
+    }
+}
+
+impl<V: Vec<Item: Ord>> From<V> for BinaryHeap<V, Natural> {
+    /// Same as [`BinaryHeap::from_vec`].
+    #[inline]
+    fn from(vec: V) -> Self {
+        Self::from_vec(vec)
+    }
+}
+
+impl<V: Vec, C: Comparator<V::Item>> Extend<V::Item> for BinaryHeap<V, C> {
+    /// # Panics
+    ///
+    /// Panics if the backing vector can not grow; see
+    /// [`try_extend`](BinaryHeap::try_extend).
+    fn extend<I: IntoIterator<Item = V::Item>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<V, C> FromIterator<V::Item> for BinaryHeap<V, C>
+where
+    V: Vec + FromIterator<V::Item>,
+    C: Comparator<V::Item> + Default,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = V::Item>>(iter: I) -> Self {
+        Self::from_vec_by(iter.into_iter().collect(), C::default())
+    }
+}
+
+impl<'a, V: Vec, C> IntoIterator for &'a BinaryHeap<V, C> {
+    type Item = &'a V::Item;
+
+    type IntoIter = slice::Iter<'a, V::Item>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.as_slice().iter()
+    }
+}
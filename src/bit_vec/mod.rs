@@ -0,0 +1,345 @@
+mod set;
+
+#[cfg(feature = "std")]
+pub use set::StdBitSet;
+pub use set::{BitSet, StackBitSet};
+
+use core::{fmt, iter::FusedIterator, ops::RangeBounds};
+
+use crate::{
+    stack_vec::capacity_overflow,
+    traits::vec::{slice_range, CapacityError, Vec},
+    StackVec,
+};
+
+const BITS: usize = usize::BITS as usize;
+
+/// A [`BitVec`] of up to `W` words, that is `W * usize::BITS` bits, stored
+/// inline.
+pub type StackBitVec<const W: usize> = BitVec<StackVec<usize, W>>;
+
+/// A [`BitVec`] storing its words on the heap.
+#[cfg(feature = "std")]
+pub type StdBitVec = BitVec<std::vec::Vec<usize>>;
+
+/// A vector of bits packed into the `usize` words of a [`Vec`] implementor.
+pub struct BitVec<W> {
+    /// Bits past `len` are always zero.
+    words: W,
+    len: usize,
+}
+
+impl<W: Vec<Item = usize> + Default> BitVec<W> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            words: W::default(),
+            len: 0,
+        }
+    }
+}
+
+impl<W: Vec<Item = usize>> BitVec<W> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.words.capacity().saturating_mul(BITS)
+    }
+
+    /// The backing words, least significant bit first.
+    #[inline]
+    pub fn as_words(&self) -> &[usize] {
+        self.words.as_slice()
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<bool> {
+        (index < self.len).then(|| self.words.as_slice()[index / BITS] >> (index % BITS) & 1 == 1)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[track_caller]
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(
+            index < self.len,
+            "index {index} out of bounds for length {}",
+            self.len
+        );
+        let word = &mut self.words.as_mut_slice()[index / BITS];
+        let mask = 1 << (index % BITS);
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `W` can not grow to hold another bit.
+    #[inline]
+    pub fn push(&mut self, value: bool) {
+        if self.try_push(value).is_err() {
+            capacity_overflow()
+        }
+    }
+
+    pub fn try_push(&mut self, value: bool) -> Result<(), CapacityError<bool>> {
+        if self.len.is_multiple_of(BITS) {
+            self.words
+                .try_push(0)
+                .map_err(|_| CapacityError::new(value))?;
+        }
+        self.len += 1;
+        if value {
+            self.set(self.len - 1, true);
+        }
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<bool> {
+        let last = self.get(self.len.checked_sub(1)?)?;
+        self.truncate(self.len - 1);
+        Some(last)
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        self.len = len;
+        self.words.truncate(len.div_ceil(BITS));
+        if !len.is_multiple_of(BITS) {
+            if let Some(last) = self.words.as_mut_slice().last_mut() {
+                *last &= (1 << (len % BITS)) - 1;
+            }
+        }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.words.clear();
+        self.len = 0;
+    }
+
+    /// Sets every bit within `range` to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    #[track_caller]
+    pub fn fill<R: RangeBounds<usize>>(&mut self, range: R, value: bool) {
+        let range = slice_range(range, self.len);
+        fill_words(self.words.as_mut_slice(), range.start, range.end, value);
+    }
+
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        count_ones(self.words.as_slice())
+    }
+
+    #[inline]
+    pub fn count_zeros(&self) -> usize {
+        self.len - self.count_ones()
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            words: self.words.as_slice(),
+            front: 0,
+            back: self.len,
+        }
+    }
+
+    /// The indices of the set bits, in ascending order.
+    #[inline]
+    pub fn iter_ones(&self) -> Ones<'_> {
+        Ones::new(self.words.as_slice())
+    }
+}
+
+fn count_ones(words: &[usize]) -> usize {
+    words.iter().map(|word| word.count_ones() as usize).sum()
+}
+
+/// Sets the bits `start..end` of `words` to `value`.
+fn fill_words(words: &mut [usize], start: usize, end: usize, value: bool) {
+    if start >= end {
+        return;
+    }
+    let (first, last) = (start / BITS, (end - 1) / BITS);
+    for (i, word) in words[first..=last].iter_mut().enumerate() {
+        let index = first + i;
+        let low = if index == first { start % BITS } else { 0 };
+        let high = if index == last {
+            (end - 1) % BITS + 1
+        } else {
+            BITS
+        };
+        let mask = (usize::MAX >> (BITS - (high - low))) << low;
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+}
+
+impl<W: Vec<Item = usize> + Default> Default for BitVec<W> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: Vec<Item = usize> + Clone> Clone for BitVec<W> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            words: self.words.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<W: Vec<Item = usize>, W2: Vec<Item = usize>> PartialEq<BitVec<W2>> for BitVec<W> {
+    #[inline]
+    fn eq(&self, other: &BitVec<W2>) -> bool {
+        self.len == other.len && self.as_words() == other.as_words()
+    }
+}
+
+impl<W: Vec<Item = usize>> Eq for BitVec<W> {}
+
+impl<W: Vec<Item = usize>> fmt::Debug for BitVec<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for bit in self.iter() {
+            f.write_str(if bit { "1" } else { "0" })?;
+        }
+        f.write_str("]")
+    }
+}
+
+impl<W: Vec<Item = usize>> Extend<bool> for BitVec<W> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|bit| self.push(bit));
+    }
+}
+
+impl<W: Vec<Item = usize> + Default> FromIterator<bool> for BitVec<W> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bits = Self::new();
+        bits.extend(iter);
+        bits
+    }
+}
+
+impl<'a, W: Vec<Item = usize>> IntoIterator for &'a BitVec<W> {
+    type Item = bool;
+
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// The bits of a [`BitVec`].
+#[derive(Clone)]
+pub struct Iter<'a> {
+    words: &'a [usize],
+    front: usize,
+    back: usize,
+}
+
+impl Iter<'_> {
+    #[inline]
+    fn bit(&self, index: usize) -> bool {
+        self.words[index / BITS] >> (index % BITS) & 1 == 1
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<bool> {
+        (self.front < self.back).then(|| {
+            self.front += 1;
+            self.bit(self.front - 1)
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<bool> {
+        (self.front < self.back).then(|| {
+            self.back -= 1;
+            self.bit(self.back)
+        })
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl FusedIterator for Iter<'_> {}
+
+/// The indices of the set bits of a [`BitVec`] or [`BitSet`], in ascending
+/// order.
+#[derive(Clone)]
+pub struct Ones<'a> {
+    words: core::slice::Iter<'a, usize>,
+    /// Bits of the current word not yielded yet.
+    current: usize,
+    base: usize,
+}
+
+impl<'a> Ones<'a> {
+    #[inline]
+    pub(crate) fn new(words: &'a [usize]) -> Self {
+        Self {
+            words: words.iter(),
+            current: 0,
+            base: 0usize.wrapping_sub(BITS),
+        }
+    }
+}
+
+impl Iterator for Ones<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            self.current = *self.words.next()?;
+            self.base = self.base.wrapping_add(BITS);
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+        Some(self.base + bit)
+    }
+}
+
+impl FusedIterator for Ones<'_> {}
@@ -0,0 +1,253 @@
+use core::{fmt, iter};
+
+use super::{count_ones, Ones, BITS};
+use crate::{
+    stack_vec::capacity_overflow,
+    traits::vec::{CapacityError, Vec},
+    StackVec,
+};
+
+/// A [`BitSet`] of values below `W * usize::BITS`, stored inline.
+pub type StackBitSet<const W: usize> = BitSet<StackVec<usize, W>>;
+
+/// A [`BitSet`] storing its words on the heap.
+#[cfg(feature = "std")]
+pub type StdBitSet = BitSet<std::vec::Vec<usize>>;
+
+/// A set of small integers, one bit each, packed into the `usize` words of a
+/// [`Vec`] implementor.
+pub struct BitSet<W> {
+    words: W,
+}
+
+impl<W: Vec<Item = usize> + Default> BitSet<W> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            words: W::default(),
+        }
+    }
+}
+
+impl<W: Vec<Item = usize>> BitSet<W> {
+    /// Values below this fit without growing `W`.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.words.capacity().saturating_mul(BITS)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        count_ones(self.words.as_slice())
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.words.as_slice().iter().all(|&word| word == 0)
+    }
+
+    /// The backing words, least significant bit first.
+    #[inline]
+    pub fn as_words(&self) -> &[usize] {
+        self.words.as_slice()
+    }
+
+    #[inline]
+    pub fn contains(&self, value: usize) -> bool {
+        self.words
+            .as_slice()
+            .get(value / BITS)
+            .is_some_and(|word| word >> (value % BITS) & 1 == 1)
+    }
+
+    /// Adds `value`, returning whether it was absent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `W` can not grow to hold `value`.
+    #[inline]
+    pub fn insert(&mut self, value: usize) -> bool {
+        self.try_insert(value)
+            .unwrap_or_else(|_| capacity_overflow())
+    }
+
+    pub fn try_insert(&mut self, value: usize) -> Result<bool, CapacityError<usize>> {
+        self.try_grow(value / BITS + 1)
+            .map_err(|_| CapacityError::new(value))?;
+        let word = &mut self.words.as_mut_slice()[value / BITS];
+        let mask = 1 << (value % BITS);
+        let absent = *word & mask == 0;
+        *word |= mask;
+        Ok(absent)
+    }
+
+    /// Removes `value`, returning whether it was present.
+    pub fn remove(&mut self, value: usize) -> bool {
+        match self.words.as_mut_slice().get_mut(value / BITS) {
+            Some(word) => {
+                let mask = 1 << (value % BITS);
+                let present = *word & mask != 0;
+                *word &= !mask;
+                present
+            }
+            None => false,
+        }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    /// The values, in ascending order.
+    #[inline]
+    pub fn iter(&self) -> Ones<'_> {
+        Ones::new(self.words.as_slice())
+    }
+
+    /// Adds every value of `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `W` can not grow to hold the values of `other`.
+    pub fn union_with<W2: Vec<Item = usize>>(&mut self, other: &BitSet<W2>) {
+        let other = other.trimmed();
+        if self.try_grow(other.len()).is_err() {
+            capacity_overflow()
+        }
+        for (word, other) in self.words.as_mut_slice().iter_mut().zip(other) {
+            *word |= other;
+        }
+    }
+
+    /// Keeps the values also in `other`.
+    pub fn intersect_with<W2: Vec<Item = usize>>(&mut self, other: &BitSet<W2>) {
+        let other = other.words.as_slice();
+        let words = self.words.as_mut_slice();
+        for (word, other) in words.iter_mut().zip(other.iter().chain(iter::repeat(&0))) {
+            *word &= other;
+        }
+    }
+
+    /// Removes the values in `other`.
+    pub fn difference_with<W2: Vec<Item = usize>>(&mut self, other: &BitSet<W2>) {
+        for (word, other) in self.words.as_mut_slice().iter_mut().zip(other.as_words()) {
+            *word &= !other;
+        }
+    }
+
+    /// Keeps the values in exactly one of `self` and `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `W` can not grow to hold the values of `other`.
+    pub fn symmetric_difference_with<W2: Vec<Item = usize>>(&mut self, other: &BitSet<W2>) {
+        let other = other.trimmed();
+        if self.try_grow(other.len()).is_err() {
+            capacity_overflow()
+        }
+        for (word, other) in self.words.as_mut_slice().iter_mut().zip(other) {
+            *word ^= other;
+        }
+    }
+
+    pub fn is_subset<W2: Vec<Item = usize>>(&self, other: &BitSet<W2>) -> bool {
+        let other = other.words.as_slice();
+        self.words
+            .as_slice()
+            .iter()
+            .zip(other.iter().chain(iter::repeat(&0)))
+            .all(|(word, other)| word & !other == 0)
+    }
+
+    #[inline]
+    pub fn is_superset<W2: Vec<Item = usize>>(&self, other: &BitSet<W2>) -> bool {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint<W2: Vec<Item = usize>>(&self, other: &BitSet<W2>) -> bool {
+        self.as_words()
+            .iter()
+            .zip(other.as_words())
+            .all(|(word, other)| word & other == 0)
+    }
+
+    /// The words up to the last non-zero one.
+    fn trimmed(&self) -> &[usize] {
+        let words = self.words.as_slice();
+        let len = words
+            .iter()
+            .rposition(|&word| word != 0)
+            .map_or(0, |i| i + 1);
+        &words[..len]
+    }
+
+    fn try_grow(&mut self, words: usize) -> Result<(), CapacityError> {
+        let len = self.words.len();
+        if words > len {
+            self.words.try_reserve(words - len)?;
+            self.words.resize(words, 0);
+        }
+        Ok(())
+    }
+}
+
+impl<W: Vec<Item = usize> + Default> Default for BitSet<W> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: Vec<Item = usize> + Clone> Clone for BitSet<W> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            words: self.words.clone(),
+        }
+    }
+}
+
+impl<W: Vec<Item = usize>, W2: Vec<Item = usize>> PartialEq<BitSet<W2>> for BitSet<W> {
+    #[inline]
+    fn eq(&self, other: &BitSet<W2>) -> bool {
+        self.trimmed() == other.trimmed()
+    }
+}
+
+impl<W: Vec<Item = usize>> Eq for BitSet<W> {}
+
+impl<W: Vec<Item = usize>> fmt::Debug for BitSet<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<W: Vec<Item = usize>> Extend<usize> for BitSet<W> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| {
+            self.insert(value);
+        });
+    }
+}
+
+impl<W: Vec<Item = usize> + Default> FromIterator<usize> for BitSet<W> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a, W: Vec<Item = usize>> IntoIterator for &'a BitSet<W> {
+    type Item = usize;
+
+    type IntoIter = Ones<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
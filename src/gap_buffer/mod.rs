@@ -0,0 +1,405 @@
+mod string;
+
+pub use string::GapString;
+
+use core::{
+    fmt,
+    iter::Chain,
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Index, IndexMut},
+    ptr, slice,
+};
+
+use crate::{stack_vec::capacity_overflow, traits::vec::CapacityError, StackVec};
+
+/// Up to `N` items stored inline on both sides of a gap at the cursor.
+///
+/// Inserting and removing at the cursor is O(1); moving the cursor shifts
+/// the items it passes over across the gap.
+pub struct GapBuffer<T, const N: usize> {
+    buff: [MaybeUninit<T>; N],
+    /// Items before the gap, at the start of `buff`.
+    front: usize,
+    /// Items after the gap, at the end of `buff`.
+    back: usize,
+}
+
+impl<T, const N: usize> GapBuffer<T, N> {
+    const UNINIT_ITEM: MaybeUninit<T> = MaybeUninit::uninit();
+    const UNINIT_ARRAY: [MaybeUninit<T>; N] = [Self::UNINIT_ITEM; N];
+
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            buff: Self::UNINIT_ARRAY,
+            front: 0,
+            back: 0,
+        }
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        StackVec::<T, N>::capacity()
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.front + self.back
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Position of the cursor, which sits before the item at that index.
+    #[inline]
+    pub const fn cursor(&self) -> usize {
+        self.front
+    }
+
+    /// Moves the cursor to `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    #[track_caller]
+    pub fn set_cursor(&mut self, index: usize) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "cursor index (is {index}) should be <= len (is {len})"
+        );
+        let back_start = self.back_start();
+        let base = self.as_mut_ptr();
+        unsafe {
+            if index < self.front {
+                let count = self.front - index;
+                ptr::copy(base.add(index), base.add(back_start - count), count);
+            } else {
+                let count = index - self.front;
+                ptr::copy(base.add(back_start), base.add(self.front), count);
+            }
+        }
+        self.front = index;
+        self.back = len - index;
+    }
+
+    /// Moves the cursor one item back, returning whether it moved.
+    #[inline]
+    pub fn move_left(&mut self) -> bool {
+        let moved = self.front > 0;
+        if moved {
+            self.set_cursor(self.front - 1);
+        }
+        moved
+    }
+
+    /// Moves the cursor one item forward, returning whether it moved.
+    #[inline]
+    pub fn move_right(&mut self) -> bool {
+        let moved = self.back > 0;
+        if moved {
+            self.set_cursor(self.front + 1);
+        }
+        moved
+    }
+
+    /// Inserts `item` at the cursor and moves the cursor past it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is full.
+    #[inline]
+    pub fn insert(&mut self, item: T) {
+        if self.try_insert(item).is_err() {
+            capacity_overflow()
+        }
+    }
+
+    pub fn try_insert(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        if self.len() == self.capacity() {
+            return Err(CapacityError::new(item));
+        }
+        unsafe { self.as_mut_ptr().add(self.front).write(item) };
+        self.front += 1;
+        Ok(())
+    }
+
+    /// Clones `items` in at the cursor and moves the cursor past them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `items` do not fit.
+    #[inline]
+    pub fn insert_slice(&mut self, items: &[T])
+    where
+        T: Clone,
+    {
+        if self.try_insert_slice(items).is_err() {
+            capacity_overflow()
+        }
+    }
+
+    /// Inserts nothing if `items` do not fit.
+    pub fn try_insert_slice(&mut self, items: &[T]) -> Result<(), CapacityError>
+    where
+        T: Clone,
+    {
+        if self.capacity() - self.len() < items.len() {
+            return Err(CapacityError::new(()));
+        }
+        for item in items {
+            unsafe { self.as_mut_ptr().add(self.front).write(item.clone()) };
+            self.front += 1;
+        }
+        Ok(())
+    }
+
+    /// Removes the item before the cursor, like a backspace.
+    #[inline]
+    pub fn remove_before(&mut self) -> Option<T> {
+        if self.front == 0 {
+            return None;
+        }
+        self.front -= 1;
+        Some(unsafe { self.as_ptr().add(self.front).read() })
+    }
+
+    /// Removes the item after the cursor, like a delete.
+    #[inline]
+    pub fn remove_after(&mut self) -> Option<T> {
+        if self.back == 0 {
+            return None;
+        }
+        let index = self.back_start();
+        self.back -= 1;
+        Some(unsafe { self.as_ptr().add(index).read() })
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let slot = self.slot(index)?;
+        Some(unsafe { &*self.as_ptr().add(slot) })
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let slot = self.slot(index)?;
+        Some(unsafe { &mut *self.as_mut_ptr().add(slot) })
+    }
+
+    /// The items before and after the cursor.
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let base = self.as_ptr();
+        unsafe {
+            (
+                slice::from_raw_parts(base, self.front),
+                slice::from_raw_parts(base.add(self.back_start()), self.back),
+            )
+        }
+    }
+
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let back_start = self.back_start();
+        let base = self.as_mut_ptr();
+        unsafe {
+            (
+                slice::from_raw_parts_mut(base, self.front),
+                slice::from_raw_parts_mut(base.add(back_start), self.back),
+            )
+        }
+    }
+
+    /// Moves the cursor to the end, closing up the items into one slice.
+    #[inline]
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        self.set_cursor(self.len());
+        self.as_mut_slices().0
+    }
+
+    pub fn clear(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        let (front, back) = (front as *mut [T], back as *mut [T]);
+        self.front = 0;
+        self.back = 0;
+        unsafe {
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
+        }
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Chain<slice::Iter<'_, T>, slice::Iter<'_, T>> {
+        let (front, back) = self.as_slices();
+        front.iter().chain(back)
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> Chain<slice::IterMut<'_, T>, slice::IterMut<'_, T>> {
+        let (front, back) = self.as_mut_slices();
+        front.iter_mut().chain(back)
+    }
+
+    /// Closes the gap and hands the items over to a [`StackVec`].
+    pub fn into_stack_vec(mut self) -> StackVec<T, N> {
+        self.make_contiguous();
+        let this = ManuallyDrop::new(self);
+        unsafe { StackVec::from_raw_parts(ptr::read(&this.buff), this.front) }
+    }
+
+    #[inline]
+    fn back_start(&self) -> usize {
+        self.capacity() - self.back
+    }
+
+    /// Position in `buff` of the item at `index`.
+    #[inline]
+    fn slot(&self, index: usize) -> Option<usize> {
+        if index < self.front {
+            Some(index)
+        } else if index < self.len() {
+            Some(index - self.front + self.back_start())
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        self.buff.as_ptr() as *const T
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.buff.as_mut_ptr() as *mut T
+    }
+}
+
+impl<T, const N: usize> Drop for GapBuffer<T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for GapBuffer<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for GapBuffer<T, N> {
+    fn clone(&self) -> Self {
+        let (front, back) = self.as_slices();
+        let mut clone = Self::new();
+        clone.insert_slice(back);
+        clone.set_cursor(0);
+        clone.insert_slice(front);
+        clone
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for GapBuffer<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq<U>, U, const N1: usize, const N2: usize> PartialEq<GapBuffer<U, N2>>
+    for GapBuffer<T, N1>
+{
+    /// Compares the items, wherever the cursors are.
+    #[inline]
+    fn eq(&self, other: &GapBuffer<U, N2>) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for GapBuffer<T, N> {}
+
+impl<T, const N: usize> Index<usize> for GapBuffer<T, N> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: usize) -> &T {
+        let len = self.len();
+        self.get(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for GapBuffer<T, N> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len();
+        self.get_mut(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
+impl<T, const N: usize> Extend<T> for GapBuffer<T, N> {
+    /// Inserts the items at the cursor, in order.
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|item| self.insert(item));
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for GapBuffer<T, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut buffer = Self::new();
+        buffer.extend(iter);
+        buffer
+    }
+}
+
+impl<T, const N: usize> From<StackVec<T, N>> for GapBuffer<T, N> {
+    /// Takes over the items, with the cursor at the end.
+    #[inline]
+    fn from(value: StackVec<T, N>) -> Self {
+        let (buff, front) = value.into_raw_parts();
+        Self {
+            buff,
+            front,
+            back: 0,
+        }
+    }
+}
+
+impl<T, const N: usize> From<GapBuffer<T, N>> for StackVec<T, N> {
+    #[inline]
+    fn from(value: GapBuffer<T, N>) -> Self {
+        value.into_stack_vec()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a GapBuffer<T, N> {
+    type Item = &'a T;
+
+    type IntoIter = Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut GapBuffer<T, N> {
+    type Item = &'a mut T;
+
+    type IntoIter = Chain<slice::IterMut<'a, T>, slice::IterMut<'a, T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
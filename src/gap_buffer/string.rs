@@ -0,0 +1,290 @@
+use core::{
+    fmt,
+    iter::Chain,
+    str::{self, Chars, Utf8Error},
+};
+
+use super::GapBuffer;
+use crate::{stack_vec::capacity_overflow, traits::vec::CapacityError, StackVec};
+
+/// A [`GapBuffer`] of up to `N` bytes of UTF-8 text, with the cursor always
+/// on a char boundary.
+pub struct GapString<const N: usize> {
+    bytes: GapBuffer<u8, N>,
+}
+
+impl<const N: usize> GapString<N> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            bytes: GapBuffer::new(),
+        }
+    }
+
+    /// Takes over UTF-8 `bytes`, with the cursor at the end.
+    #[inline]
+    pub fn from_utf8(bytes: StackVec<u8, N>) -> Result<Self, Utf8Error> {
+        str::from_utf8(&bytes)?;
+        Ok(Self {
+            bytes: bytes.into(),
+        })
+    }
+
+    /// Capacity in bytes.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.bytes.capacity()
+    }
+
+    /// Length in bytes.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Byte position of the cursor.
+    #[inline]
+    pub const fn cursor(&self) -> usize {
+        self.bytes.cursor()
+    }
+
+    #[inline]
+    pub fn is_char_boundary(&self, index: usize) -> bool {
+        // Continuation bytes look like `0b10xx_xxxx`.
+        index == self.len()
+            || self
+                .bytes
+                .get(index)
+                .is_some_and(|&byte| byte as i8 >= -0x40)
+    }
+
+    /// Moves the cursor to byte `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not a char boundary.
+    #[track_caller]
+    pub fn set_cursor(&mut self, index: usize) {
+        assert!(
+            self.is_char_boundary(index),
+            "cursor index (is {index}) should be a char boundary"
+        );
+        self.bytes.set_cursor(index);
+    }
+
+    /// Moves the cursor one char back, returning whether it moved.
+    pub fn move_left(&mut self) -> bool {
+        match self.as_strs().0.chars().next_back() {
+            Some(ch) => {
+                self.bytes.set_cursor(self.cursor() - ch.len_utf8());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the cursor one char forward, returning whether it moved.
+    pub fn move_right(&mut self) -> bool {
+        match self.as_strs().1.chars().next() {
+            Some(ch) => {
+                self.bytes.set_cursor(self.cursor() + ch.len_utf8());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Inserts `ch` at the cursor and moves the cursor past it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ch` does not fit.
+    #[inline]
+    pub fn insert(&mut self, ch: char) {
+        if self.try_insert(ch).is_err() {
+            capacity_overflow()
+        }
+    }
+
+    #[inline]
+    pub fn try_insert(&mut self, ch: char) -> Result<(), CapacityError<char>> {
+        self.try_insert_str(ch.encode_utf8(&mut [0; 4]))
+            .map_err(|_| CapacityError::new(ch))
+    }
+
+    /// Inserts `s` at the cursor and moves the cursor past it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` does not fit.
+    #[inline]
+    pub fn insert_str(&mut self, s: &str) {
+        if self.try_insert_str(s).is_err() {
+            capacity_overflow()
+        }
+    }
+
+    /// Inserts nothing if `s` does not fit.
+    #[inline]
+    pub fn try_insert_str(&mut self, s: &str) -> Result<(), CapacityError> {
+        self.bytes.try_insert_slice(s.as_bytes())
+    }
+
+    /// Removes the char before the cursor, like a backspace.
+    pub fn remove_before(&mut self) -> Option<char> {
+        let ch = self.as_strs().0.chars().next_back()?;
+        for _ in 0..ch.len_utf8() {
+            self.bytes.remove_before();
+        }
+        Some(ch)
+    }
+
+    /// Removes the char after the cursor, like a delete.
+    pub fn remove_after(&mut self) -> Option<char> {
+        let ch = self.as_strs().1.chars().next()?;
+        for _ in 0..ch.len_utf8() {
+            self.bytes.remove_after();
+        }
+        Some(ch)
+    }
+
+    /// The text before and after the cursor.
+    #[inline]
+    pub fn as_strs(&self) -> (&str, &str) {
+        let (front, back) = self.bytes.as_slices();
+        // Both halves split the text on a char boundary.
+        unsafe {
+            (
+                str::from_utf8_unchecked(front),
+                str::from_utf8_unchecked(back),
+            )
+        }
+    }
+
+    /// Moves the cursor to the end, closing up the text into one `str`.
+    #[inline]
+    pub fn make_contiguous(&mut self) -> &mut str {
+        unsafe { str::from_utf8_unchecked_mut(self.bytes.make_contiguous()) }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+    }
+
+    #[inline]
+    pub fn chars(&self) -> Chain<Chars<'_>, Chars<'_>> {
+        let (front, back) = self.as_strs();
+        front.chars().chain(back.chars())
+    }
+
+    /// Closes the gap and hands the bytes over to a [`StackVec`].
+    #[inline]
+    pub fn into_bytes(self) -> StackVec<u8, N> {
+        self.bytes.into_stack_vec()
+    }
+}
+
+impl<const N: usize> Default for GapString<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Clone for GapString<N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+        }
+    }
+}
+
+impl<const N: usize> fmt::Display for GapString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (front, back) = self.as_strs();
+        f.write_str(front)?;
+        f.write_str(back)
+    }
+}
+
+impl<const N: usize> fmt::Debug for GapString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (front, back) = self.as_strs();
+        write!(f, "\"{}{}\"", front.escape_debug(), back.escape_debug())
+    }
+}
+
+impl<const N: usize> fmt::Write for GapString<N> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_insert_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N1: usize, const N2: usize> PartialEq<GapString<N2>> for GapString<N1> {
+    #[inline]
+    fn eq(&self, other: &GapString<N2>) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl<const N: usize> Eq for GapString<N> {}
+
+impl<const N: usize> PartialEq<str> for GapString<N> {
+    fn eq(&self, other: &str) -> bool {
+        let (front, back) = self.as_strs();
+        other.len() == self.len()
+            && other.as_bytes()[..front.len()] == *front.as_bytes()
+            && other.as_bytes()[front.len()..] == *back.as_bytes()
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for GapString<N> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl<const N: usize> Extend<char> for GapString<N> {
+    /// Inserts the chars at the cursor, in order.
+    #[inline]
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|ch| self.insert(ch));
+    }
+}
+
+impl<const N: usize> FromIterator<char> for GapString<N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut s = Self::new();
+        s.extend(iter);
+        s
+    }
+}
+
+impl<const N: usize> From<&str> for GapString<N> {
+    /// # Panics
+    ///
+    /// Panics if `value` does not fit.
+    #[inline]
+    fn from(value: &str) -> Self {
+        let mut s = Self::new();
+        s.insert_str(value);
+        s
+    }
+}
+
+impl<const N: usize> From<GapString<N>> for StackVec<u8, N> {
+    #[inline]
+    fn from(value: GapString<N>) -> Self {
+        value.into_bytes()
+    }
+}
@@ -0,0 +1,480 @@
+use core::{fmt, iter::FusedIterator, marker::PhantomData, mem, ops, slice};
+
+use crate::{
+    stack_vec::capacity_overflow,
+    traits::vec::{CapacityError, Vec},
+    StackVec,
+};
+
+/// A [`GenArena`] storing its values inline.
+pub type StackGenArena<T, const N: usize> = GenArena<T, StackVec<Slot<T>, N>>;
+
+/// A [`GenArena`] storing its values on the heap.
+#[cfg(feature = "std")]
+pub type StdGenArena<T> = GenArena<T, std::vec::Vec<Slot<T>>>;
+
+/// A handle to a value of a [`GenArena`].
+///
+/// It stays tied to the value it was returned for: once that value is
+/// removed, the handle no longer matches anything, even after its slot is
+/// reused.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Index {
+    slot: usize,
+    generation: u32,
+}
+
+impl Index {
+    #[inline]
+    pub const fn from_raw_parts(slot: usize, generation: u32) -> Self {
+        Self { slot, generation }
+    }
+
+    #[inline]
+    pub const fn slot(self) -> usize {
+        self.slot
+    }
+
+    #[inline]
+    pub const fn generation(self) -> u32 {
+        self.generation
+    }
+}
+
+/// A slot of a [`GenArena`], either holding a value or linking to the next
+/// vacant slot.
+pub struct Slot<T> {
+    generation: u32,
+    state: State<T>,
+}
+
+enum State<T> {
+    Occupied(T),
+    Vacant(usize),
+}
+
+impl<T: Clone> Clone for Slot<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            generation: self.generation,
+            state: match &self.state {
+                State::Occupied(value) => State::Occupied(value.clone()),
+                State::Vacant(next) => State::Vacant(*next),
+            },
+        }
+    }
+}
+
+/// Values stored in the slots of a [`Vec`] implementor `V`, addressed by
+/// generational [`Index`]es.
+///
+/// Each removal bumps the generation of the slot, so handles to removed
+/// values return `None` instead of aliasing the value that reused the slot.
+/// Generations are 32 bits wide and wrap around.
+pub struct GenArena<T, V> {
+    slots: V,
+    len: usize,
+    /// Head of the vacant slots list, `slots.len()` when there is none.
+    next_free: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T, V: Vec<Item = Slot<T>> + Default> GenArena<T, V> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            slots: V::default(),
+            len: 0,
+            next_free: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, V: Vec<Item = Slot<T>>> GenArena<T, V> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    #[inline]
+    pub fn get(&self, index: Index) -> Option<&T> {
+        match self.slots.as_slice().get(index.slot) {
+            Some(Slot {
+                generation,
+                state: State::Occupied(value),
+            }) if *generation == index.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, index: Index) -> Option<&mut T> {
+        match self.slots.as_mut_slice().get_mut(index.slot) {
+            Some(Slot {
+                generation,
+                state: State::Occupied(value),
+            }) if *generation == index.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Borrows the values of two handles at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if both handles point to the same slot.
+    #[track_caller]
+    pub fn get2_mut(&mut self, a: Index, b: Index) -> (Option<&mut T>, Option<&mut T>) {
+        assert!(
+            a.slot != b.slot,
+            "`get2_mut` called with the same slot twice"
+        );
+        let slots = self.slots.as_mut_slice();
+        let (a_slot, b_slot) = if a.slot < b.slot {
+            let (low, high) = slots.split_at_mut(b.slot.min(slots.len()));
+            (low.get_mut(a.slot), high.first_mut())
+        } else {
+            let (low, high) = slots.split_at_mut(a.slot.min(slots.len()));
+            (high.first_mut(), low.get_mut(b.slot))
+        };
+        (live(a_slot, a), live(b_slot, b))
+    }
+
+    #[inline]
+    pub fn contains(&self, index: Index) -> bool {
+        self.get(index).is_some()
+    }
+
+    /// Stores `value` and returns its handle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if every slot is occupied and `V` can not grow.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> Index {
+        self.try_insert(value)
+            .unwrap_or_else(|_| capacity_overflow())
+    }
+
+    pub fn try_insert(&mut self, value: T) -> Result<Index, CapacityError<T>> {
+        let slot = self.next_free;
+        let generation = if slot == self.slots.len() {
+            let new = Slot {
+                generation: 0,
+                state: State::Occupied(value),
+            };
+            self.slots
+                .try_push(new)
+                .map_err(|error| match error.element().state {
+                    State::Occupied(value) => CapacityError::new(value),
+                    State::Vacant(_) => unreachable!(),
+                })?;
+            self.next_free = self.slots.len();
+            0
+        } else {
+            let entry = &mut self.slots.as_mut_slice()[slot];
+            match mem::replace(&mut entry.state, State::Occupied(value)) {
+                State::Vacant(next) => self.next_free = next,
+                State::Occupied(_) => unreachable!(),
+            }
+            entry.generation
+        };
+        self.len += 1;
+        Ok(Index { slot, generation })
+    }
+
+    /// Removes and returns the value of `index`, invalidating every handle
+    /// to it.
+    pub fn remove(&mut self, index: Index) -> Option<T> {
+        let entry = self.slots.as_mut_slice().get_mut(index.slot)?;
+        if entry.generation != index.generation || matches!(entry.state, State::Vacant(_)) {
+            return None;
+        }
+        Some(self.vacate(index.slot))
+    }
+
+    /// Keeps the values for which `f` returns `true`.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Index, &mut T) -> bool,
+    {
+        for slot in 0..self.slots.len() {
+            let entry = &mut self.slots.as_mut_slice()[slot];
+            let keep = match &mut entry.state {
+                State::Occupied(value) => f(
+                    Index {
+                        slot,
+                        generation: entry.generation,
+                    },
+                    value,
+                ),
+                State::Vacant(_) => true,
+            };
+            if !keep {
+                self.vacate(slot);
+            }
+        }
+    }
+
+    /// Removes every value. Their slots are kept so that old handles stay
+    /// invalid.
+    pub fn clear(&mut self) {
+        self.drain();
+    }
+
+    /// Removes every value, yielding them with their handles in slot order.
+    /// Values not yielded are dropped with the iterator.
+    #[inline]
+    pub fn drain(&mut self) -> Drain<'_, T, V> {
+        Drain {
+            arena: self,
+            slot: 0,
+        }
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.slots.as_slice().iter().enumerate(),
+            len: self.len,
+        }
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            inner: self.slots.as_mut_slice().iter_mut().enumerate(),
+            len: self.len,
+        }
+    }
+
+    /// Frees the occupied `slot` and bumps its generation.
+    fn vacate(&mut self, slot: usize) -> T {
+        let entry = &mut self.slots.as_mut_slice()[slot];
+        let State::Occupied(value) = mem::replace(&mut entry.state, State::Vacant(self.next_free))
+        else {
+            unreachable!()
+        };
+        entry.generation = entry.generation.wrapping_add(1);
+        self.next_free = slot;
+        self.len -= 1;
+        value
+    }
+}
+
+fn live<T>(slot: Option<&mut Slot<T>>, index: Index) -> Option<&mut T> {
+    match slot {
+        Some(Slot {
+            generation,
+            state: State::Occupied(value),
+        }) if *generation == index.generation => Some(value),
+        _ => None,
+    }
+}
+
+impl<T, V: Vec<Item = Slot<T>> + Default> Default for GenArena<T, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, V: Vec<Item = Slot<T>> + Clone> Clone for GenArena<T, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            slots: self.slots.clone(),
+            len: self.len,
+            next_free: self.next_free,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: fmt::Debug, V: Vec<Item = Slot<T>>> fmt::Debug for GenArena<T, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T, V: Vec<Item = Slot<T>>> ops::Index<Index> for GenArena<T, V> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: Index) -> &T {
+        self.get(index).expect("stale or invalid arena index")
+    }
+}
+
+impl<T, V: Vec<Item = Slot<T>>> ops::IndexMut<Index> for GenArena<T, V> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, index: Index) -> &mut T {
+        self.get_mut(index).expect("stale or invalid arena index")
+    }
+}
+
+impl<'a, T, V: Vec<Item = Slot<T>>> IntoIterator for &'a GenArena<T, V> {
+    type Item = (Index, &'a T);
+
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, V: Vec<Item = Slot<T>>> IntoIterator for &'a mut GenArena<T, V> {
+    type Item = (Index, &'a mut T);
+
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// The values of a [`GenArena`] with their handles.
+pub struct Iter<'a, T> {
+    inner: core::iter::Enumerate<slice::Iter<'a, Slot<T>>>,
+    len: usize,
+}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (Index, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (slot, entry) in self.inner.by_ref() {
+            if let State::Occupied(value) = &entry.state {
+                self.len -= 1;
+                return Some((Index::from_raw_parts(slot, entry.generation), value));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some((slot, entry)) = self.inner.next_back() {
+            if let State::Occupied(value) = &entry.state {
+                self.len -= 1;
+                return Some((Index::from_raw_parts(slot, entry.generation), value));
+            }
+        }
+        None
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// The values of a [`GenArena`] with their handles, mutably.
+pub struct IterMut<'a, T> {
+    inner: core::iter::Enumerate<slice::IterMut<'a, Slot<T>>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = (Index, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (slot, entry) in self.inner.by_ref() {
+            if let State::Occupied(value) = &mut entry.state {
+                self.len -= 1;
+                return Some((Index::from_raw_parts(slot, entry.generation), value));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some((slot, entry)) = self.inner.next_back() {
+            if let State::Occupied(value) = &mut entry.state {
+                self.len -= 1;
+                return Some((Index::from_raw_parts(slot, entry.generation), value));
+            }
+        }
+        None
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// The values removed by [`GenArena::drain`].
+pub struct Drain<'a, T, V: Vec<Item = Slot<T>>> {
+    arena: &'a mut GenArena<T, V>,
+    slot: usize,
+}
+
+impl<T, V: Vec<Item = Slot<T>>> Iterator for Drain<'_, T, V> {
+    type Item = (Index, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.slot < self.arena.slots.len() {
+            let slot = self.slot;
+            self.slot += 1;
+            let entry = &self.arena.slots.as_slice()[slot];
+            if let State::Occupied(_) = entry.state {
+                let index = Index::from_raw_parts(slot, entry.generation);
+                return Some((index, self.arena.vacate(slot)));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.arena.len, Some(self.arena.len))
+    }
+}
+
+impl<T, V: Vec<Item = Slot<T>>> ExactSizeIterator for Drain<'_, T, V> {}
+
+impl<T, V: Vec<Item = Slot<T>>> FusedIterator for Drain<'_, T, V> {}
+
+impl<T, V: Vec<Item = Slot<T>>> Drop for Drain<'_, T, V> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
@@ -0,0 +1,763 @@
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{BuildHasher, Hash},
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem::{self, ManuallyDrop},
+    ops::{Index, IndexMut, RangeBounds},
+    ptr::NonNull,
+};
+
+use crate::{
+    stack_hash_map::DefaultHashBuilder,
+    stack_vec::capacity_overflow,
+    traits::vec::{self, CapacityError, Vec},
+    vec_map::{Iter, IterMut, Keys, Values, ValuesMut},
+    StackVec,
+};
+
+/// Maps with at most this many entries are searched linearly.
+const LINEAR_MAX: usize = 8;
+/// Index slot holding no entry.
+const EMPTY: u64 = u64::MAX;
+
+/// An [`IndexMap`] storing everything inline.
+///
+/// `M` is the size of the hash index. Lookups stay linear while it is too
+/// small to hold twice as many slots as there are entries, rounded up to a
+/// power of two.
+pub type StackIndexMap<K, V, const N: usize, const M: usize, S = DefaultHashBuilder> =
+    IndexMap<K, V, StackVec<(K, V), N>, StackVec<u64, M>, S>;
+
+/// An [`IndexMap`] storing everything on the heap.
+#[cfg(feature = "std")]
+pub type StdIndexMap<K, V, S = DefaultHashBuilder> =
+    IndexMap<K, V, std::vec::Vec<(K, V)>, std::vec::Vec<u64>, S>;
+
+/// A map keeping its entries in insertion order, in a [`Vec`] implementor
+/// `E`.
+///
+/// Small maps are searched linearly. Larger ones use an open-addressing hash
+/// index stored in `I`; if `I` can not grow enough, lookups fall back to a
+/// linear search instead of failing.
+pub struct IndexMap<K, V, E, I, S = DefaultHashBuilder> {
+    entries: E,
+    /// Either empty or a power of two long, each slot holding the hash of a
+    /// key in its high half and the index of its entry in its low half.
+    indices: I,
+    hash_builder: S,
+    _marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<K, V, E, I, S> IndexMap<K, V, E, I, S>
+where
+    E: Vec<Item = (K, V)> + Default,
+    I: Vec<Item = u64> + Default,
+    S: Default,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K, V, E, I, S> IndexMap<K, V, E, I, S>
+where
+    E: Vec<Item = (K, V)>,
+    I: Vec<Item = u64>,
+{
+    #[inline]
+    pub fn with_hasher(hash_builder: S) -> Self
+    where
+        E: Default,
+        I: Default,
+    {
+        Self {
+            entries: E::default(),
+            indices: I::default(),
+            hash_builder,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    #[inline]
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[(K, V)] {
+        self.entries.as_slice()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.indices.clear();
+        self.entries.clear();
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter(self.entries.as_slice().iter())
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut(self.entries.as_mut_slice().iter_mut())
+    }
+
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys(self.entries.as_slice().iter())
+    }
+
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values(self.entries.as_slice().iter())
+    }
+
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut(self.entries.as_mut_slice().iter_mut())
+    }
+
+    #[inline]
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        let (k, v) = self.entries.as_slice().get(index)?;
+        Some((k, v))
+    }
+
+    #[inline]
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        let (k, v) = self.entries.as_mut_slice().get_mut(index)?;
+        Some((&*k, v))
+    }
+
+    #[inline]
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.get_index(0)
+    }
+
+    #[inline]
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.get_index(self.len().wrapping_sub(1))
+    }
+}
+
+impl<K, V, E, I, S> IndexMap<K, V, E, I, S>
+where
+    K: Hash + Eq,
+    E: Vec<Item = (K, V)>,
+    I: Vec<Item = u64>,
+    S: BuildHasher,
+{
+    #[inline]
+    fn hash<Q: Hash + ?Sized>(&self, key: &Q) -> u32 {
+        (self.hash_builder.hash_one(key) >> 32) as u32
+    }
+
+    /// Index of the entry for `key`.
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entries = self.entries.as_slice();
+        let table = self.indices.as_slice();
+        if table.is_empty() {
+            return entries.iter().position(|(k, _)| k.borrow() == key);
+        }
+        let hash = self.hash(key);
+        let slot = find_slot(table, hash, |index| entries[index].0.borrow() == key)?;
+        Some(unpack(table[slot]).1)
+    }
+
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_full(key).map(|(_, _, v)| v)
+    }
+
+    #[inline]
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_full(key).map(|(_, k, v)| (k, v))
+    }
+
+    #[inline]
+    pub fn get_full<Q>(&self, key: &Q) -> Option<(usize, &K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+        let (k, v) = &self.entries.as_slice()[index];
+        Some((index, k, v))
+    }
+
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+        Some(&mut self.entries.as_mut_slice()[index].1)
+    }
+
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_index_of(key).is_some()
+    }
+
+    /// Inserts `value` under `key`, returning the value it replaced. New keys
+    /// go last.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is new and the entries can not grow.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.insert_full(key, value).1
+    }
+
+    /// Like [`insert`](IndexMap::insert), also returning the index of the
+    /// entry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is new and the entries can not grow.
+    #[inline]
+    pub fn insert_full(&mut self, key: K, value: V) -> (usize, Option<V>) {
+        self.try_insert_full(key, value)
+            .unwrap_or_else(|_| capacity_overflow())
+    }
+
+    /// Like [`insert`](IndexMap::insert), but gives the entry back instead
+    /// of panicking when the entries can not grow.
+    #[inline]
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, CapacityError<(K, V)>> {
+        self.try_insert_full(key, value).map(|(_, old)| old)
+    }
+
+    /// Like [`insert_full`](IndexMap::insert_full), but gives the entry back
+    /// instead of panicking when the entries can not grow.
+    #[allow(clippy::type_complexity)]
+    pub fn try_insert_full(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<(usize, Option<V>), CapacityError<(K, V)>> {
+        if let Some(index) = self.get_index_of(&key) {
+            let old = mem::replace(&mut self.entries.as_mut_slice()[index].1, value);
+            return Ok((index, Some(old)));
+        }
+        let hash = self.hash(&key);
+        self.entries.try_push((key, value))?;
+        let index = self.entries.len() - 1;
+        if self.needs_rebuild() {
+            self.rebuild_indices();
+        } else if !self.indices.is_empty() {
+            insert_slot(self.indices.as_mut_slice(), hash, index);
+        }
+        Ok((index, None))
+    }
+
+    /// Removes the entry for `key` and moves the last entry into its place.
+    #[inline]
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+        Some(self.swap_remove_index(index).1)
+    }
+
+    /// Removes the entry for `key`, shifting back the entries after it.
+    #[inline]
+    pub fn shift_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+        Some(self.shift_remove_index(index).1)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove_index(&mut self, index: usize) -> (K, V) {
+        let last = self.entries.len() - 1;
+        if !self.indices.is_empty() {
+            let entries = self.entries.as_slice();
+            let hash = self.hash(&entries[index].0);
+            let last_hash = self.hash(&entries[last].0);
+            let table = self.indices.as_mut_slice();
+            remove_slot(
+                table,
+                find_slot(table, hash, |found| found == index).unwrap(),
+            );
+            if index != last {
+                let slot = find_slot(table, last_hash, |found| found == last).unwrap();
+                table[slot] = pack(last_hash, index);
+            }
+        }
+        self.entries.swap_remove(index)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn shift_remove_index(&mut self, index: usize) -> (K, V) {
+        if !self.indices.is_empty() {
+            let hash = self.hash(&self.entries.as_slice()[index].0);
+            let table = self.indices.as_mut_slice();
+            remove_slot(
+                table,
+                find_slot(table, hash, |found| found == index).unwrap(),
+            );
+            for slot in self.indices.as_mut_slice() {
+                if *slot != EMPTY && unpack(*slot).1 > index {
+                    *slot -= 1;
+                }
+            }
+        }
+        self.entries.remove(index)
+    }
+
+    /// Removes the last entry.
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        let last = self.entries.len().checked_sub(1)?;
+        Some(self.swap_remove_index(last))
+    }
+
+    /// Keeps only the entries for which `f` returns `true`, in order.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        // Lookups stay correct, if linear, should `f` panic.
+        self.indices.clear();
+        self.entries.retain_mut(|(k, v)| f(k, v));
+        self.rebuild_indices();
+    }
+
+    /// Sorts the entries by key.
+    #[inline]
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.sort_unstable_by(|k1, _, k2, _| k1.cmp(k2));
+    }
+
+    /// Sorts the entries with `cmp`. Equal entries may be reordered.
+    pub fn sort_unstable_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        self.indices.clear();
+        self.entries
+            .as_mut_slice()
+            .sort_unstable_by(|(k1, v1), (k2, v2)| cmp(k1, v1, k2, v2));
+        self.rebuild_indices();
+    }
+
+    /// Removes the entries in `range`, in order. The hash index is rebuilt
+    /// once the returned iterator is dropped.
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, K, V, E, I, S>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.indices.clear();
+        // The inner drain borrows `entries` only; `map` is used again once
+        // it is gone.
+        let mut map = NonNull::from(&mut *self);
+        let inner = unsafe { map.as_mut() }.entries.drain(range);
+        Drain {
+            inner: ManuallyDrop::new(inner),
+            map,
+        }
+    }
+
+    /// Inserts every pair of `iter`, stopping at the first one that does not
+    /// fit and returning it.
+    pub fn try_extend<T>(&mut self, iter: T) -> Result<(), CapacityError<(K, V)>>
+    where
+        T: IntoIterator<Item = (K, V)>,
+    {
+        for (key, value) in iter {
+            self.try_insert(key, value)?;
+        }
+        Ok(())
+    }
+
+    fn needs_rebuild(&self) -> bool {
+        let len = self.entries.len();
+        len > LINEAR_MAX && self.indices.len() * 3 < len * 4
+    }
+
+    /// Rebuilds the hash index from scratch, leaving it empty if it is not
+    /// needed or can not grow enough.
+    fn rebuild_indices(&mut self) {
+        self.indices.clear();
+        let len = self.entries.len();
+        if len <= LINEAR_MAX {
+            return;
+        }
+        let size = (len * 2).next_power_of_two();
+        if self.indices.try_reserve(size).is_err() {
+            return;
+        }
+        self.indices.resize(size, EMPTY);
+
+        /// Leaves the index empty if hashing a key panics.
+        struct ClearOnDrop<'a, I: Vec<Item = u64>>(&'a mut I);
+
+        impl<I: Vec<Item = u64>> Drop for ClearOnDrop<'_, I> {
+            fn drop(&mut self) {
+                self.0.clear();
+            }
+        }
+
+        let guard = ClearOnDrop(&mut self.indices);
+        for (index, (key, _)) in self.entries.as_slice().iter().enumerate() {
+            let hash = (self.hash_builder.hash_one(key) >> 32) as u32;
+            insert_slot(guard.0.as_mut_slice(), hash, index);
+        }
+        mem::forget(guard);
+    }
+}
+
+#[inline]
+fn pack(hash: u32, index: usize) -> u64 {
+    (hash as u64) << 32 | index as u64
+}
+
+#[inline]
+fn unpack(slot: u64) -> (u32, usize) {
+    ((slot >> 32) as u32, slot as u32 as usize)
+}
+
+/// Slot whose hash is `hash` and whose entry satisfies `eq`.
+fn find_slot(table: &[u64], hash: u32, mut eq: impl FnMut(usize) -> bool) -> Option<usize> {
+    let mask = table.len() - 1;
+    let mut slot = hash as usize & mask;
+    loop {
+        match table[slot] {
+            EMPTY => return None,
+            found if unpack(found).0 == hash && eq(unpack(found).1) => return Some(slot),
+            _ => slot = (slot + 1) & mask,
+        }
+    }
+}
+
+fn insert_slot(table: &mut [u64], hash: u32, index: usize) {
+    let mask = table.len() - 1;
+    let mut slot = hash as usize & mask;
+    while table[slot] != EMPTY {
+        slot = (slot + 1) & mask;
+    }
+    table[slot] = pack(hash, index);
+}
+
+/// Empties `hole`, moving back the following slots of its probe sequence
+/// that can get closer to their home.
+fn remove_slot(table: &mut [u64], mut hole: usize) {
+    let mask = table.len() - 1;
+    table[hole] = EMPTY;
+    let mut slot = hole;
+    loop {
+        slot = (slot + 1) & mask;
+        let found = table[slot];
+        if found == EMPTY {
+            return;
+        }
+        let home = unpack(found).0 as usize & mask;
+        if (hole.wrapping_sub(home) & mask) < (slot.wrapping_sub(home) & mask) {
+            table[hole] = found;
+            table[slot] = EMPTY;
+            hole = slot;
+        }
+    }
+}
+
+/// A draining iterator over the entries of an [`IndexMap`].
+pub struct Drain<'a, K: 'a, V: 'a, E, I, S>
+where
+    K: Hash + Eq,
+    E: Vec<Item = (K, V)>,
+    I: Vec<Item = u64>,
+    S: BuildHasher,
+{
+    inner: ManuallyDrop<vec::Drain<'a, E>>,
+    map: NonNull<IndexMap<K, V, E, I, S>>,
+}
+
+impl<K, V, E, I, S> Iterator for Drain<'_, K, V, E, I, S>
+where
+    K: Hash + Eq,
+    E: Vec<Item = (K, V)>,
+    I: Vec<Item = u64>,
+    S: BuildHasher,
+{
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(K, V)> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V, E, I, S> DoubleEndedIterator for Drain<'_, K, V, E, I, S>
+where
+    K: Hash + Eq,
+    E: Vec<Item = (K, V)>,
+    I: Vec<Item = u64>,
+    S: BuildHasher,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<(K, V)> {
+        self.inner.next_back()
+    }
+}
+
+impl<K, V, E, I, S> ExactSizeIterator for Drain<'_, K, V, E, I, S>
+where
+    K: Hash + Eq,
+    E: Vec<Item = (K, V)>,
+    I: Vec<Item = u64>,
+    S: BuildHasher,
+{
+}
+
+impl<K, V, E, I, S> FusedIterator for Drain<'_, K, V, E, I, S>
+where
+    K: Hash + Eq,
+    E: Vec<Item = (K, V)>,
+    I: Vec<Item = u64>,
+    S: BuildHasher,
+{
+}
+
+impl<K, V, E, I, S> Drop for Drain<'_, K, V, E, I, S>
+where
+    K: Hash + Eq,
+    E: Vec<Item = (K, V)>,
+    I: Vec<Item = u64>,
+    S: BuildHasher,
+{
+    fn drop(&mut self) {
+        unsafe {
+            ManuallyDrop::drop(&mut self.inner);
+            self.map.as_mut().rebuild_indices();
+        }
+    }
+}
+
+impl<K, V, E, I, S> Default for IndexMap<K, V, E, I, S>
+where
+    E: Vec<Item = (K, V)> + Default,
+    I: Vec<Item = u64> + Default,
+    S: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, E: Clone, I: Clone, S: Clone> Clone for IndexMap<K, V, E, I, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            indices: self.indices.clone(),
+            hash_builder: self.hash_builder.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K, V, E, I, S> fmt::Debug for IndexMap<K, V, E, I, S>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+    E: Vec<Item = (K, V)>,
+    I: Vec<Item = u64>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, E1, I1, S1, E2, I2, S2> PartialEq<IndexMap<K, V, E2, I2, S2>>
+    for IndexMap<K, V, E1, I1, S1>
+where
+    K: Hash + Eq,
+    V: PartialEq,
+    E1: Vec<Item = (K, V)>,
+    I1: Vec<Item = u64>,
+    E2: Vec<Item = (K, V)>,
+    I2: Vec<Item = u64>,
+    S2: BuildHasher,
+{
+    /// Maps are equal when they hold the same entries, in any order.
+    fn eq(&self, other: &IndexMap<K, V, E2, I2, S2>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(k, v)| other.get(k).is_some_and(|other| v == other))
+    }
+}
+
+impl<K, V, E, I, S> Eq for IndexMap<K, V, E, I, S>
+where
+    K: Hash + Eq,
+    V: Eq,
+    E: Vec<Item = (K, V)>,
+    I: Vec<Item = u64>,
+    S: BuildHasher,
+{
+}
+
+impl<K, V, E, I, S, Q> Index<&Q> for IndexMap<K, V, E, I, S>
+where
+    K: Hash + Eq + Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+    E: Vec<Item = (K, V)>,
+    I: Vec<Item = u64>,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+impl<K, V, E, I, S, Q> IndexMut<&Q> for IndexMap<K, V, E, I, S>
+where
+    K: Hash + Eq + Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+    E: Vec<Item = (K, V)>,
+    I: Vec<Item = u64>,
+    S: BuildHasher,
+{
+    #[inline]
+    fn index_mut(&mut self, key: &Q) -> &mut V {
+        self.get_mut(key).expect("no entry found for key")
+    }
+}
+
+impl<K, V, E, I, S> Extend<(K, V)> for IndexMap<K, V, E, I, S>
+where
+    K: Hash + Eq,
+    E: Vec<Item = (K, V)>,
+    I: Vec<Item = u64>,
+    S: BuildHasher,
+{
+    /// # Panics
+    ///
+    /// Panics if the entries can not grow; see
+    /// [`try_extend`](IndexMap::try_extend).
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V, E, I, S> FromIterator<(K, V)> for IndexMap<K, V, E, I, S>
+where
+    K: Hash + Eq,
+    E: Vec<Item = (K, V)> + Default,
+    I: Vec<Item = u64> + Default,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V, E, I, S> IntoIterator for IndexMap<K, V, E, I, S>
+where
+    E: Vec<Item = (K, V)> + IntoIterator<Item = (K, V)>,
+{
+    type Item = (K, V);
+
+    type IntoIter = E::IntoIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, K, V, E, I, S> IntoIterator for &'a IndexMap<K, V, E, I, S>
+where
+    E: Vec<Item = (K, V)>,
+    I: Vec<Item = u64>,
+{
+    type Item = (&'a K, &'a V);
+
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, E, I, S> IntoIterator for &'a mut IndexMap<K, V, E, I, S>
+where
+    E: Vec<Item = (K, V)>,
+    I: Vec<Item = u64>,
+{
+    type Item = (&'a K, &'a mut V);
+
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
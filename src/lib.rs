@@ -1,6 +1,45 @@
 #![cfg_attr(feature = "no_std", no_std)]
 
+pub mod binary_heap;
+pub mod bit_vec;
+pub mod gap_buffer;
+pub mod gen_arena;
+pub mod index_map;
+pub mod lru_cache;
+#[cfg(target_has_atomic = "8")]
+pub mod pool;
+pub mod rle_vec;
+pub mod slab;
+pub mod soa;
+pub mod sorted_vec;
+pub mod sparse_set;
+pub mod stack_btree_map;
+pub mod stack_hash_map;
+pub mod stack_list;
 pub mod stack_vec;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod top_k;
 pub mod traits;
+pub mod vec_map;
 
+pub use binary_heap::BinaryHeap;
+pub use bit_vec::{BitSet, BitVec, StackBitSet, StackBitVec};
+pub use gap_buffer::{GapBuffer, GapString};
+pub use gen_arena::{GenArena, StackGenArena};
+pub use index_map::IndexMap;
+pub use lru_cache::LruCache;
+#[cfg(feature = "derive")]
+pub use no_std_collections_derive::Soa;
+#[cfg(target_has_atomic = "8")]
+pub use pool::StackPool;
+pub use rle_vec::{RleVec, StackRleVec};
+pub use slab::{Slab, StackSlab};
+pub use sorted_vec::{SortedVec, SortedVecBy};
+pub use sparse_set::{SparseMap, SparseSet, StackSparseMap, StackSparseSet};
+pub use stack_btree_map::{StackBTreeMap, StackBTreeSet};
+pub use stack_hash_map::{StackHashMap, StackHashSet};
+pub use stack_list::StackList;
 pub use stack_vec::StackVec;
+pub use top_k::TopK;
+pub use vec_map::VecMap;
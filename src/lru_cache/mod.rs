@@ -0,0 +1,374 @@
+use core::{borrow::Borrow, fmt, iter::FusedIterator, mem};
+
+use crate::StackVec;
+
+/// Link standing for "no entry".
+const NIL: usize = usize::MAX;
+
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    /// More recently used neighbour.
+    prev: usize,
+    /// Less recently used neighbour.
+    next: usize,
+}
+
+/// A cache of up to `N` entries stored inline, evicting the least recently
+/// used one when full.
+///
+/// Entries are linked by index in recency order. Lookups scan the keys,
+/// which suits the few dozen entries such a cache usually holds. The
+/// capacity is fixed by `N`.
+pub struct LruCache<K, V, const N: usize> {
+    entries: StackVec<Entry<K, V>, N>,
+    /// Most recently used entry.
+    head: usize,
+    /// Least recently used entry.
+    tail: usize,
+}
+
+impl<K, V, const N: usize> LruCache<K, V, N> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            entries: StackVec::new(),
+            head: NIL,
+            tail: NIL,
+        }
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[inline]
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.find(key).is_some()
+    }
+
+    /// Looks up `key`, marking it as the most recently used.
+    #[inline]
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.get_mut(key).map(|value| &*value)
+    }
+
+    /// Looks up `key`, marking it as the most recently used.
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        self.promote(index);
+        Some(&mut self.entries[index].value)
+    }
+
+    /// Looks up `key` without touching the recency order.
+    #[inline]
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.find(key).map(|index| &self.entries[index].value)
+    }
+
+    /// Looks up `key` without touching the recency order.
+    #[inline]
+    pub fn peek_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        Some(&mut self.entries[index].value)
+    }
+
+    /// Stores `value` for `key` as the most recently used entry, returning
+    /// the least recently used entry if it had to be evicted to make room.
+    ///
+    /// A value already stored for `key` is dropped; use
+    /// [`replace`](LruCache::replace) to get it back.
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)>
+    where
+        K: Eq,
+    {
+        let value = match self.replace(&key, value) {
+            Ok(_) => return None,
+            Err(value) => value,
+        };
+        if N == 0 {
+            return Some((key, value));
+        }
+        let evicted = if self.len() == N {
+            self.pop_lru()
+        } else {
+            None
+        };
+        self.entries.push(Entry {
+            key,
+            value,
+            prev: NIL,
+            next: NIL,
+        });
+        self.push_front(self.len() - 1);
+        evicted
+    }
+
+    /// Swaps `value` in for the value of `key`, marking it as the most
+    /// recently used, and returns the old one. Gives `value` back if `key` is
+    /// absent.
+    #[inline]
+    pub fn replace<Q>(&mut self, key: &Q, value: V) -> Result<V, V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        match self.get_mut(key) {
+            Some(slot) => Ok(mem::replace(slot, value)),
+            None => Err(value),
+        }
+    }
+
+    /// Removes `key`, returning its value.
+    #[inline]
+    pub fn pop<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        Some(self.remove(index).1)
+    }
+
+    /// The least recently used entry.
+    #[inline]
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        let entry = self.entries.get(self.tail)?;
+        Some((&entry.key, &entry.value))
+    }
+
+    /// Removes the least recently used entry.
+    #[inline]
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        if self.tail == NIL {
+            return None;
+        }
+        Some(self.remove(self.tail))
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.head = NIL;
+        self.tail = NIL;
+    }
+
+    /// The entries, from the most to the least recently used.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            entries: &self.entries,
+            front: self.head,
+            back: self.tail,
+            len: self.len(),
+        }
+    }
+
+    #[inline]
+    fn find<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.entries
+            .iter()
+            .position(|entry| entry.key.borrow() == key)
+    }
+
+    fn promote(&mut self, index: usize) {
+        if self.head != index {
+            self.unlink(index);
+            self.push_front(index);
+        }
+    }
+
+    fn push_front(&mut self, index: usize) {
+        let head = self.head;
+        let entry = &mut self.entries[index];
+        entry.prev = NIL;
+        entry.next = head;
+        match head {
+            NIL => self.tail = index,
+            head => self.entries[head].prev = index,
+        }
+        self.head = index;
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let Entry { prev, next, .. } = self.entries[index];
+        match prev {
+            NIL => self.head = next,
+            prev => self.entries[prev].next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            next => self.entries[next].prev = prev,
+        }
+    }
+
+    /// Unlinks and removes the entry at `index`, moving the last entry into
+    /// its place.
+    fn remove(&mut self, index: usize) -> (K, V) {
+        self.unlink(index);
+        let entry = self.entries.swap_remove(index);
+        if let Some(&Entry { prev, next, .. }) = self.entries.get(index) {
+            match prev {
+                NIL => self.head = index,
+                prev => self.entries[prev].next = index,
+            }
+            match next {
+                NIL => self.tail = index,
+                next => self.entries[next].prev = index,
+            }
+        }
+        (entry.key, entry.value)
+    }
+}
+
+impl<K, V, const N: usize> Default for LruCache<K, V, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone, V: Clone, const N: usize> Clone for LruCache<K, V, N> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self
+                .entries
+                .iter()
+                .map(|entry| Entry {
+                    key: entry.key.clone(),
+                    value: entry.value.clone(),
+                    prev: entry.prev,
+                    next: entry.next,
+                })
+                .collect(),
+            head: self.head,
+            tail: self.tail,
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, const N: usize> fmt::Debug for LruCache<K, V, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Eq, V, const N: usize> Extend<(K, V)> for LruCache<K, V, N> {
+    /// Puts the entries in order, so the last ones end up the most recently
+    /// used.
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|(key, value)| {
+            self.put(key, value);
+        });
+    }
+}
+
+impl<K: Eq, V, const N: usize> FromIterator<(K, V)> for LruCache<K, V, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut cache = Self::new();
+        cache.extend(iter);
+        cache
+    }
+}
+
+impl<'a, K, V, const N: usize> IntoIterator for &'a LruCache<K, V, N> {
+    type Item = (&'a K, &'a V);
+
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the entries of an [`LruCache`], from the most to the least
+/// recently used.
+pub struct Iter<'a, K, V> {
+    entries: &'a [Entry<K, V>],
+    front: usize,
+    back: usize,
+    len: usize,
+}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let entry = &self.entries[self.front];
+        self.front = entry.next;
+        Some((&entry.key, &entry.value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let entry = &self.entries[self.back];
+        self.back = entry.prev;
+        Some((&entry.key, &entry.value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
@@ -0,0 +1,137 @@
+use core::{
+    cell::UnsafeCell,
+    fmt,
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// A fixed set of `N` objects, checked out one at a time through
+/// [`PoolGuard`]s that give them back when dropped.
+///
+/// Checking out only needs `&self`, so a pool can live in a `static`. This
+/// takes compare-and-swap on bytes, so the pool only exists on targets with
+/// `target_has_atomic = "8"`.
+///
+/// Every slot holds an object for the whole life of the pool, which only
+/// lends them out. With no length to track and nothing uninitialized, the
+/// objects sit in a plain array rather than a `StackVec`.
+pub struct StackPool<T, const N: usize> {
+    objects: UnsafeCell<[T; N]>,
+    free: [AtomicBool; N],
+    reset: fn(&mut T),
+}
+
+unsafe impl<T: Send, const N: usize> Sync for StackPool<T, N> {}
+
+impl<T, const N: usize> StackPool<T, N> {
+    #[inline]
+    pub const fn new(objects: [T; N]) -> Self {
+        Self::with_reset(objects, |_| {})
+    }
+
+    /// A pool calling `reset` on every object given back to it.
+    pub const fn with_reset(objects: [T; N], reset: fn(&mut T)) -> Self {
+        Self {
+            objects: UnsafeCell::new(objects),
+            free: [const { AtomicBool::new(true) }; N],
+            reset,
+        }
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// How many objects are not checked out. Only a snapshot if other threads
+    /// use the pool.
+    pub fn available(&self) -> usize {
+        self.free
+            .iter()
+            .filter(|free| free.load(Ordering::Relaxed))
+            .count()
+    }
+
+    /// Checks out a free object, if any.
+    pub fn acquire(&self) -> Option<PoolGuard<'_, T>> {
+        let index = self.free.iter().position(|free| {
+            free.compare_exchange(true, false, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+        })?;
+        // The flag of `index` was just cleared, so nothing else borrows it.
+        Some(PoolGuard {
+            object: unsafe { &mut *self.objects.get().cast::<T>().add(index) },
+            free: &self.free[index],
+            reset: self.reset,
+        })
+    }
+
+    /// The objects, none of which can be checked out while borrowed.
+    #[inline]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.objects.get_mut().iter_mut()
+    }
+}
+
+impl<T: Default, const N: usize> Default for StackPool<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(core::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for StackPool<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StackPool")
+            .field("capacity", &N)
+            .field("available", &self.available())
+            .finish()
+    }
+}
+
+/// An object checked out of a [`StackPool`]. Dropping it resets the object
+/// and makes it available again.
+pub struct PoolGuard<'a, T> {
+    object: &'a mut T,
+    free: &'a AtomicBool,
+    reset: fn(&mut T),
+}
+
+impl<T> Deref for PoolGuard<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.object
+    }
+}
+
+impl<T> DerefMut for PoolGuard<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        self.object
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for PoolGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T> Drop for PoolGuard<'_, T> {
+    fn drop(&mut self) {
+        /// Gives the slot back even if `reset` panics.
+        struct Release<'a>(&'a AtomicBool);
+
+        impl Drop for Release<'_> {
+            #[inline]
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::Release);
+            }
+        }
+
+        let _release = Release(self.free);
+        (self.reset)(self.object);
+    }
+}
@@ -0,0 +1,416 @@
+use core::{fmt, iter::FusedIterator, marker::PhantomData, ops::Index, slice};
+
+use crate::{
+    stack_vec::capacity_overflow,
+    traits::vec::{CapacityError, Vec},
+    StackVec,
+};
+
+/// An [`RleVec`] of up to `R` runs, stored inline.
+pub type StackRleVec<T, const R: usize> = RleVec<T, StackVec<(T, usize), R>>;
+
+/// An [`RleVec`] storing its runs on the heap.
+#[cfg(feature = "std")]
+pub type StdRleVec<T> = RleVec<T, std::vec::Vec<(T, usize)>>;
+
+/// A sequence stored as runs of equal items in any [`Vec`] implementor.
+///
+/// Each run is kept as its value and the index one past its last item, so
+/// lookups binary search the runs. Adjacent runs never hold equal values.
+pub struct RleVec<T, V> {
+    runs: V,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T, V: Vec<Item = (T, usize)> + Default> RleVec<T, V> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            runs: V::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, V: Vec<Item = (T, usize)>> RleVec<T, V> {
+    /// Number of items, counting every repetition.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.runs.as_slice().last().map_or(0, |run| run.1)
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.runs.is_empty()
+    }
+
+    #[inline]
+    pub fn run_count(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// Runs that fit without growing `V`.
+    #[inline]
+    pub fn run_capacity(&self) -> usize {
+        self.runs.capacity()
+    }
+
+    /// Looks up the item at `index` in O(log runs).
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let runs = self.runs.as_slice();
+        runs.get(runs.partition_point(|run| run.1 <= index))
+            .map(|run| &run.0)
+    }
+
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.runs.as_slice().first().map(|run| &run.0)
+    }
+
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.runs.as_slice().last().map(|run| &run.0)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `value` starts a new run and `V` is full, or if the length
+    /// would overflow a `usize`.
+    #[inline]
+    pub fn push(&mut self, value: T)
+    where
+        T: PartialEq,
+    {
+        self.push_run(value, 1)
+    }
+
+    #[inline]
+    pub fn try_push(&mut self, value: T) -> Result<(), CapacityError<T>>
+    where
+        T: PartialEq,
+    {
+        self.try_push_run(value, 1)
+    }
+
+    /// Appends `count` copies of `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` starts a new run and `V` is full, or if the length
+    /// would overflow a `usize`.
+    #[inline]
+    pub fn push_run(&mut self, value: T, count: usize)
+    where
+        T: PartialEq,
+    {
+        if self.try_push_run(value, count).is_err() {
+            capacity_overflow()
+        }
+    }
+
+    /// Merges into the last run when it holds `value`, so only a new run
+    /// or a length overflowing a `usize` can fail.
+    pub fn try_push_run(&mut self, value: T, count: usize) -> Result<(), CapacityError<T>>
+    where
+        T: PartialEq,
+    {
+        if count == 0 {
+            return Ok(());
+        }
+        let Some(end) = self.len().checked_add(count) else {
+            return Err(CapacityError::new(value));
+        };
+        if let Some(last) = self.runs.as_mut_slice().last_mut() {
+            if last.0 == value {
+                last.1 = end;
+                return Ok(());
+            }
+        }
+        self.runs
+            .try_push((value, end))
+            .map_err(|error| CapacityError::new(error.element().0))
+    }
+
+    pub fn pop(&mut self) -> Option<T>
+    where
+        T: Clone,
+    {
+        let start = self.run_start(self.runs.len().checked_sub(1)?);
+        let last = self.runs.as_mut_slice().last_mut()?;
+        if last.1 - start > 1 {
+            last.1 -= 1;
+            Some(last.0.clone())
+        } else {
+            self.runs.pop().map(|run| run.0)
+        }
+    }
+
+    /// Keeps the first `len` items.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len() {
+            return;
+        }
+        let runs = self.runs.as_slice().partition_point(|run| run.1 < len);
+        if len == 0 {
+            self.runs.clear();
+            return;
+        }
+        self.runs.truncate(runs + 1);
+        self.runs.as_mut_slice()[runs].1 = len;
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.runs.clear();
+    }
+
+    /// The items, repeating each run's value.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let runs = self.runs.as_slice();
+        Iter {
+            runs,
+            front: 0,
+            back: self.len(),
+            front_run: 0,
+            back_run: runs.len().saturating_sub(1),
+        }
+    }
+
+    /// Each run's value and number of items.
+    #[inline]
+    pub fn runs(&self) -> Runs<'_, T> {
+        Runs {
+            runs: self.runs.as_slice().iter(),
+            start: 0,
+        }
+    }
+
+    /// Index of the first item of run `run`.
+    #[inline]
+    fn run_start(&self, run: usize) -> usize {
+        run.checked_sub(1)
+            .map_or(0, |prev| self.runs.as_slice()[prev].1)
+    }
+}
+
+impl<T, V: Vec<Item = (T, usize)> + Default> Default for RleVec<T, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, V: Clone> Clone for RleVec<T, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            runs: self.runs.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: fmt::Debug, V: Vec<Item = (T, usize)>> fmt::Debug for RleVec<T, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, V, V2> PartialEq<RleVec<T, V2>> for RleVec<T, V>
+where
+    T: PartialEq,
+    V: Vec<Item = (T, usize)>,
+    V2: Vec<Item = (T, usize)>,
+{
+    #[inline]
+    fn eq(&self, other: &RleVec<T, V2>) -> bool {
+        self.runs.as_slice() == other.runs.as_slice()
+    }
+}
+
+impl<T: Eq, V: Vec<Item = (T, usize)>> Eq for RleVec<T, V> {}
+
+impl<T, V: Vec<Item = (T, usize)>> Index<usize> for RleVec<T, V> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: usize) -> &T {
+        let len = self.len();
+        self.get(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
+impl<T: PartialEq, V: Vec<Item = (T, usize)>> Extend<T> for RleVec<T, V> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push(value));
+    }
+}
+
+impl<T: PartialEq, V: Vec<Item = (T, usize)> + Default> FromIterator<T> for RleVec<T, V> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut rle = Self::new();
+        rle.extend(iter);
+        rle
+    }
+}
+
+impl<T, V, const N: usize> From<StackVec<T, N>> for RleVec<T, V>
+where
+    T: PartialEq,
+    V: Vec<Item = (T, usize)> + Default,
+{
+    /// # Panics
+    ///
+    /// Panics if the runs do not fit in `V`.
+    #[inline]
+    fn from(value: StackVec<T, N>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, V> From<std::vec::Vec<T>> for RleVec<T, V>
+where
+    T: PartialEq,
+    V: Vec<Item = (T, usize)> + Default,
+{
+    /// # Panics
+    ///
+    /// Panics if the runs do not fit in `V`.
+    #[inline]
+    fn from(value: std::vec::Vec<T>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<T: Clone, V: Vec<Item = (T, usize)>, const N: usize> From<&RleVec<T, V>> for StackVec<T, N> {
+    /// # Panics
+    ///
+    /// Panics if the items do not fit in `N`.
+    #[inline]
+    fn from(value: &RleVec<T, V>) -> Self {
+        value.iter().cloned().collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Clone, V: Vec<Item = (T, usize)>> From<&RleVec<T, V>> for std::vec::Vec<T> {
+    #[inline]
+    fn from(value: &RleVec<T, V>) -> Self {
+        value.iter().cloned().collect()
+    }
+}
+
+impl<'a, T, V: Vec<Item = (T, usize)>> IntoIterator for &'a RleVec<T, V> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the items of an [`RleVec`].
+pub struct Iter<'a, T> {
+    runs: &'a [(T, usize)],
+    front: usize,
+    back: usize,
+    /// Run holding the item at `front`.
+    front_run: usize,
+    /// Run holding the item before `back`.
+    back_run: usize,
+}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        while self.runs[self.front_run].1 <= self.front {
+            self.front_run += 1;
+        }
+        self.front += 1;
+        Some(&self.runs[self.front_run].0)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        while self.back_run > 0 && self.runs[self.back_run - 1].1 > self.back {
+            self.back_run -= 1;
+        }
+        Some(&self.runs[self.back_run].0)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// Iterator over the runs of an [`RleVec`], as each value and its number of
+/// items.
+pub struct Runs<'a, T> {
+    runs: slice::Iter<'a, (T, usize)>,
+    start: usize,
+}
+
+impl<T> Clone for Runs<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            runs: self.runs.clone(),
+            start: self.start,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Runs<'a, T> {
+    type Item = (&'a T, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (value, end) = self.runs.next()?;
+        let count = end - self.start;
+        self.start = *end;
+        Some((value, count))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.runs.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Runs<'_, T> {}
+
+impl<T> FusedIterator for Runs<'_, T> {}
@@ -0,0 +1,404 @@
+use core::{
+    fmt,
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem,
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use crate::{
+    stack_vec::capacity_overflow,
+    traits::vec::{self, CapacityError, Vec},
+    StackVec,
+};
+
+/// A [`Slab`] storing its values inline.
+pub type StackSlab<T, const N: usize> = Slab<T, StackVec<Slot<T>, N>>;
+
+/// A [`Slab`] storing its values on the heap.
+#[cfg(feature = "std")]
+pub type StdSlab<T> = Slab<T, std::vec::Vec<Slot<T>>>;
+
+/// A slot of a [`Slab`], either holding a value or linking to the next
+/// vacant slot.
+pub struct Slot<T>(State<T>);
+
+enum State<T> {
+    Occupied(T),
+    Vacant(usize),
+}
+
+impl<T: Clone> Clone for Slot<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Slot(match &self.0 {
+            State::Occupied(value) => State::Occupied(value.clone()),
+            State::Vacant(next) => State::Vacant(*next),
+        })
+    }
+}
+
+/// Values stored in the slots of a [`Vec`] implementor `V`, addressed by
+/// their index.
+///
+/// Removing a value never moves the others; its slot is reused by a later
+/// insertion, so keys must not outlive the values they were handed out for.
+pub struct Slab<T, V> {
+    slots: V,
+    len: usize,
+    /// Head of the vacant slots list, `slots.len()` when there is none.
+    next_free: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T, V: Vec<Item = Slot<T>> + Default> Slab<T, V> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            slots: V::default(),
+            len: 0,
+            next_free: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, V: Vec<Item = Slot<T>>> Slab<T, V> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    /// The key the next inserted value will get.
+    #[inline]
+    pub fn vacant_key(&self) -> usize {
+        self.next_free
+    }
+
+    #[inline]
+    pub fn get(&self, key: usize) -> Option<&T> {
+        match self.slots.as_slice().get(key) {
+            Some(Slot(State::Occupied(value))) => Some(value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+        match self.slots.as_mut_slice().get_mut(key) {
+            Some(Slot(State::Occupied(value))) => Some(value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn contains(&self, key: usize) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Stores `value` and returns its key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if every slot is occupied and `V` can not grow.
+    pub fn insert(&mut self, value: T) -> usize {
+        self.try_insert(value)
+            .unwrap_or_else(|_| capacity_overflow())
+    }
+
+    pub fn try_insert(&mut self, value: T) -> Result<usize, CapacityError<T>> {
+        let key = self.next_free;
+        if key == self.slots.len() {
+            self.slots
+                .try_push(Slot(State::Occupied(value)))
+                .map_err(|error| match error.element().0 {
+                    State::Occupied(value) => CapacityError::new(value),
+                    State::Vacant(_) => unreachable!(),
+                })?;
+            self.next_free = self.slots.len();
+        } else {
+            let slot = &mut self.slots.as_mut_slice()[key];
+            match mem::replace(&mut slot.0, State::Occupied(value)) {
+                State::Vacant(next) => self.next_free = next,
+                State::Occupied(_) => unreachable!(),
+            }
+        }
+        self.len += 1;
+        Ok(key)
+    }
+
+    /// Removes and returns the value at `key`, freeing its slot.
+    pub fn remove(&mut self, key: usize) -> Option<T> {
+        let slot = self.slots.as_mut_slice().get_mut(key)?;
+        match mem::replace(&mut slot.0, State::Vacant(self.next_free)) {
+            State::Occupied(value) => {
+                self.next_free = key;
+                self.len -= 1;
+                Some(value)
+            }
+            vacant => {
+                slot.0 = vacant;
+                None
+            }
+        }
+    }
+
+    /// Keeps the values for which `f` returns `true`.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut T) -> bool,
+    {
+        for key in 0..self.slots.len() {
+            let keep = match &mut self.slots.as_mut_slice()[key].0 {
+                State::Occupied(value) => f(key, value),
+                State::Vacant(_) => true,
+            };
+            if !keep {
+                self.remove(key);
+            }
+        }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.len = 0;
+        self.next_free = 0;
+    }
+
+    /// Removes every value, yielding them in key order.
+    pub fn drain(&mut self) -> Drain<'_, T, V> {
+        let len = mem::replace(&mut self.len, 0);
+        self.next_free = 0;
+        Drain {
+            inner: self.slots.drain(..),
+            len,
+        }
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.slots.as_slice().iter().enumerate(),
+            len: self.len,
+        }
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            inner: self.slots.as_mut_slice().iter_mut().enumerate(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T, V: Vec<Item = Slot<T>> + Default> Default for Slab<T, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, V: Vec<Item = Slot<T>> + Clone> Clone for Slab<T, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            slots: self.slots.clone(),
+            len: self.len,
+            next_free: self.next_free,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: fmt::Debug, V: Vec<Item = Slot<T>>> fmt::Debug for Slab<T, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T, V: Vec<Item = Slot<T>>> Index<usize> for Slab<T, V> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, key: usize) -> &T {
+        self.get(key).expect("invalid slab key")
+    }
+}
+
+impl<T, V: Vec<Item = Slot<T>>> IndexMut<usize> for Slab<T, V> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, key: usize) -> &mut T {
+        self.get_mut(key).expect("invalid slab key")
+    }
+}
+
+impl<'a, T, V: Vec<Item = Slot<T>>> IntoIterator for &'a Slab<T, V> {
+    type Item = (usize, &'a T);
+
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, V: Vec<Item = Slot<T>>> IntoIterator for &'a mut Slab<T, V> {
+    type Item = (usize, &'a mut T);
+
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// The occupied slots of a [`Slab`] with their keys.
+pub struct Iter<'a, T> {
+    inner: core::iter::Enumerate<slice::Iter<'a, Slot<T>>>,
+    len: usize,
+}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (key, slot) in self.inner.by_ref() {
+            if let State::Occupied(value) = &slot.0 {
+                self.len -= 1;
+                return Some((key, value));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some((key, slot)) = self.inner.next_back() {
+            if let State::Occupied(value) = &slot.0 {
+                self.len -= 1;
+                return Some((key, value));
+            }
+        }
+        None
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// The occupied slots of a [`Slab`] with their keys, mutably.
+pub struct IterMut<'a, T> {
+    inner: core::iter::Enumerate<slice::IterMut<'a, Slot<T>>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = (usize, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (key, slot) in self.inner.by_ref() {
+            if let State::Occupied(value) = &mut slot.0 {
+                self.len -= 1;
+                return Some((key, value));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some((key, slot)) = self.inner.next_back() {
+            if let State::Occupied(value) = &mut slot.0 {
+                self.len -= 1;
+                return Some((key, value));
+            }
+        }
+        None
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// The values removed by [`Slab::drain`]. Values not yielded are dropped
+/// with it.
+pub struct Drain<'a, T: 'a, V: Vec<Item = Slot<T>>> {
+    inner: vec::Drain<'a, V>,
+    len: usize,
+}
+
+impl<T, V: Vec<Item = Slot<T>>> Iterator for Drain<'_, T, V> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        for slot in self.inner.by_ref() {
+            if let State::Occupied(value) = slot.0 {
+                self.len -= 1;
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T, V: Vec<Item = Slot<T>>> DoubleEndedIterator for Drain<'_, T, V> {
+    fn next_back(&mut self) -> Option<T> {
+        while let Some(slot) = self.inner.next_back() {
+            if let State::Occupied(value) = slot.0 {
+                self.len -= 1;
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+impl<T, V: Vec<Item = Slot<T>>> ExactSizeIterator for Drain<'_, T, V> {}
+
+impl<T, V: Vec<Item = Slot<T>>> FusedIterator for Drain<'_, T, V> {}
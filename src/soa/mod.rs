@@ -0,0 +1,33 @@
+//! Support for the struct-of-arrays containers generated by
+//! `#[derive(Soa)]`.
+//!
+//! For a struct `Particle`, the derive generates `ParticleSoaIn<S>`, keeping
+//! one column per field in the [`Storage`] `S`, along with the
+//! `ParticleSoa<N>` alias over [`Inline`] storage and the `ParticleRef` and
+//! `ParticleRefMut` row references.
+
+use crate::{traits::vec::Vec, StackVec};
+
+/// Picks the [`Vec`] implementor backing each column of a struct-of-arrays
+/// container.
+pub trait Storage {
+    type Column<T>: Vec<Item = T> + Default;
+}
+
+/// Columns stored inline, as `StackVec<T, N>`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Inline<const N: usize>;
+
+impl<const N: usize> Storage for Inline<N> {
+    type Column<T> = StackVec<T, N>;
+}
+
+/// Columns stored on the heap, as `std::vec::Vec<T>`.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Heap;
+
+#[cfg(feature = "std")]
+impl Storage for Heap {
+    type Column<T> = std::vec::Vec<T>;
+}
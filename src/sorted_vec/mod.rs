@@ -0,0 +1,385 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Bound, Deref, RangeBounds},
+    ptr,
+};
+
+use crate::traits::{
+    compare::Natural,
+    vec::{CapacityError, Vec},
+    Comparator, SliceOwner,
+};
+
+/// A [`Vec`] implementor kept sorted by `C`, without duplicates.
+///
+/// It only hands out shared access to its items, so the order can not be
+/// broken from the outside.
+pub struct SortedVecBy<V, C> {
+    inner: V,
+    cmp: C,
+}
+
+/// A [`SortedVecBy`] ordered by `Ord`.
+pub type SortedVec<V> = SortedVecBy<V, Natural>;
+
+impl<V: Vec + Default> SortedVecBy<V, Natural> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: V::default(),
+            cmp: Natural,
+        }
+    }
+}
+
+impl<V: Vec> SortedVecBy<V, Natural>
+where
+    V::Item: Ord,
+{
+    /// Sorts `vec` and drops its duplicates.
+    #[inline]
+    pub fn from_unsorted(vec: V) -> Self {
+        Self::from_unsorted_by(vec, Natural)
+    }
+}
+
+impl<V: Vec, C: Comparator<V::Item>> SortedVecBy<V, C> {
+    #[inline]
+    pub fn with_comparator(cmp: C) -> Self
+    where
+        V: Default,
+    {
+        Self {
+            inner: V::default(),
+            cmp,
+        }
+    }
+
+    /// Sorts `vec` by `cmp` and drops its duplicates.
+    pub fn from_unsorted_by(mut vec: V, cmp: C) -> Self {
+        vec.as_mut_slice()
+            .sort_unstable_by(|a, b| cmp.compare(a, b));
+        vec.dedup_by(|a, b| cmp.compare(a, b) == Ordering::Equal);
+        Self { inner: vec, cmp }
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[V::Item] {
+        self.inner.as_slice()
+    }
+
+    #[inline]
+    pub fn as_inner(&self) -> &V {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.inner
+    }
+
+    #[inline]
+    pub fn comparator(&self) -> &C {
+        &self.cmp
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    #[inline]
+    pub fn binary_search(&self, item: &V::Item) -> Result<usize, usize> {
+        self.as_slice()
+            .binary_search_by(|probe| self.cmp.compare(probe, item))
+    }
+
+    #[inline]
+    pub fn contains(&self, item: &V::Item) -> bool {
+        self.binary_search(item).is_ok()
+    }
+
+    #[inline]
+    pub fn get(&self, item: &V::Item) -> Option<&V::Item> {
+        self.binary_search(item)
+            .ok()
+            .map(|index| &self.as_slice()[index])
+    }
+
+    /// Inserts `item` in order and returns its index. If an equal item is
+    /// already present, returns its index and gives `item` back.
+    pub fn insert(&mut self, item: V::Item) -> Result<usize, (usize, V::Item)> {
+        match self.binary_search(&item) {
+            Ok(index) => Err((index, item)),
+            Err(index) => {
+                self.inner.insert(index, item);
+                Ok(index)
+            }
+        }
+    }
+
+    /// Like [`insert`](SortedVecBy::insert), but reports a full container
+    /// instead of panicking.
+    #[allow(clippy::type_complexity)]
+    pub fn try_insert(
+        &mut self,
+        item: V::Item,
+    ) -> Result<Result<usize, (usize, V::Item)>, CapacityError<V::Item>> {
+        match self.binary_search(&item) {
+            Ok(index) => Ok(Err((index, item))),
+            Err(index) => self.inner.try_insert(index, item).map(|()| Ok(index)),
+        }
+    }
+
+    /// Removes and returns the item equal to `item`, if any.
+    pub fn remove_value(&mut self, item: &V::Item) -> Option<V::Item> {
+        self.binary_search(item)
+            .ok()
+            .map(|index| self.inner.remove(index))
+    }
+
+    #[inline]
+    pub fn remove_index(&mut self, index: usize) -> V::Item {
+        self.inner.remove(index)
+    }
+
+    /// Removes the greatest item.
+    #[inline]
+    pub fn pop(&mut self) -> Option<V::Item> {
+        self.inner.pop()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len);
+    }
+
+    #[inline]
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&V::Item) -> bool,
+    {
+        self.inner.retain(f);
+    }
+
+    /// The items within `range`, as a slice.
+    pub fn range<R>(&self, range: R) -> &[V::Item]
+    where
+        R: RangeBounds<V::Item>,
+    {
+        let items = self.as_slice();
+        let start = match range.start_bound() {
+            Bound::Included(start) => {
+                items.partition_point(|probe| self.cmp.compare(probe, start).is_lt())
+            }
+            Bound::Excluded(start) => {
+                items.partition_point(|probe| self.cmp.compare(probe, start).is_le())
+            }
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => {
+                items.partition_point(|probe| self.cmp.compare(probe, end).is_le())
+            }
+            Bound::Excluded(end) => {
+                items.partition_point(|probe| self.cmp.compare(probe, end).is_lt())
+            }
+            Bound::Unbounded => items.len(),
+        };
+        &items[start..end.max(start)]
+    }
+
+    /// Moves the items of `other`, which must be sorted by the same
+    /// comparator, into `self` in a single backward pass. Items equal to one
+    /// already present are dropped.
+    ///
+    /// If the comparator panics, `self` stays sorted and keeps the items
+    /// merged so far; the rest of `other` is dropped.
+    pub fn merge<S: Vec<Item = V::Item>>(&mut self, mut other: S) {
+        debug_assert!(other
+            .as_slice()
+            .windows(2)
+            .all(|pair| self.cmp.compare(&pair[0], &pair[1]).is_le()));
+        self.inner.reserve(other.len());
+        let other_len = other.len();
+        unsafe {
+            // `other` no longer owns its items; `MergeGuard` does.
+            other.set_len(0);
+            let len = self.inner.len();
+            let mut guard = MergeGuard {
+                vec: &mut self.inner,
+                other: other.as_mut_ptr(),
+                left: len,
+                right: other_len,
+                write: len + other_len,
+                end: len + other_len,
+            };
+            let base = guard.vec.as_mut_ptr();
+            while guard.right > 0 {
+                let candidate = guard.other.add(guard.right - 1);
+                if guard.left > 0 {
+                    let head = base.add(guard.left - 1);
+                    match self.cmp.compare(&*head, &*candidate) {
+                        Ordering::Greater => {
+                            guard.left -= 1;
+                            guard.write -= 1;
+                            ptr::copy(head, base.add(guard.write), 1);
+                            continue;
+                        }
+                        Ordering::Equal => {
+                            guard.right -= 1;
+                            ptr::drop_in_place(candidate);
+                            continue;
+                        }
+                        Ordering::Less => {}
+                    }
+                }
+                let duplicate = guard.write < guard.end
+                    && self.cmp.compare(&*candidate, &*base.add(guard.write)) == Ordering::Equal;
+                guard.right -= 1;
+                if duplicate {
+                    ptr::drop_in_place(candidate);
+                } else {
+                    guard.write -= 1;
+                    ptr::copy_nonoverlapping(candidate, base.add(guard.write), 1);
+                }
+            }
+        }
+    }
+}
+
+/// State of a backward merge: `vec[..left]` and `other[..right]` are still
+/// unmerged, `vec[write..end]` holds the merged items.
+struct MergeGuard<'a, V: Vec + ?Sized> {
+    vec: &'a mut V,
+    other: *mut V::Item,
+    left: usize,
+    right: usize,
+    write: usize,
+    end: usize,
+}
+
+impl<V: Vec + ?Sized> Drop for MergeGuard<'_, V> {
+    fn drop(&mut self) {
+        unsafe {
+            let base = self.vec.as_mut_ptr();
+            let merged = self.end - self.write;
+            ptr::copy(base.add(self.write), base.add(self.left), merged);
+            self.vec.set_len(self.left + merged);
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.other, self.right));
+        }
+    }
+}
+
+impl<V: Vec + Default> Default for SortedVecBy<V, Natural> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Vec + Clone, C: Clone> Clone for SortedVecBy<V, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            cmp: self.cmp.clone(),
+        }
+    }
+}
+
+impl<V: Vec<Item: fmt::Debug>, C> fmt::Debug for SortedVecBy<V, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.inner.as_slice()).finish()
+    }
+}
+
+impl<V: Vec, C> Deref for SortedVecBy<V, C> {
+    type Target = [V::Item];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.inner.as_slice()
+    }
+}
+
+impl<V: Vec, C> AsRef<[V::Item]> for SortedVecBy<V, C> {
+    #[inline]
+    fn as_ref(&self) -> &[V::Item] {
+        self.inner.as_slice()
+    }
+}
+
+impl<V: Vec<Item: PartialEq>, C, V2: Vec<Item = V::Item>, C2> PartialEq<SortedVecBy<V2, C2>>
+    for SortedVecBy<V, C>
+{
+    #[inline]
+    fn eq(&self, other: &SortedVecBy<V2, C2>) -> bool {
+        self.inner.as_slice() == other.inner.as_slice()
+    }
+}
+
+impl<V: Vec<Item: Eq>, C> Eq for SortedVecBy<V, C> {}
+
+impl<V, C> Extend<V::Item> for SortedVecBy<V, C>
+where
+    V: Vec,
+    C: Comparator<V::Item>,
+{
+    /// Inserts every item, dropping the ones already present.
+    fn extend<I: IntoIterator<Item = V::Item>>(&mut self, iter: I) {
+        for item in iter {
+            let _ = self.insert(item);
+        }
+    }
+}
+
+impl<V, C> FromIterator<V::Item> for SortedVecBy<V, C>
+where
+    V: Vec + FromIterator<V::Item>,
+    C: Comparator<V::Item> + Default,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = V::Item>>(iter: I) -> Self {
+        Self::from_unsorted_by(iter.into_iter().collect(), C::default())
+    }
+}
+
+impl<V, C> IntoIterator for SortedVecBy<V, C>
+where
+    V: Vec + IntoIterator<Item = <V as SliceOwner>::Item>,
+{
+    type Item = <V as SliceOwner>::Item;
+
+    type IntoIter = V::IntoIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, V: Vec, C> IntoIterator for &'a SortedVecBy<V, C> {
+    type Item = &'a V::Item;
+
+    type IntoIter = core::slice::Iter<'a, V::Item>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.as_slice().iter()
+    }
+}
+
+impl<V: Vec<Item: Ord>> From<V> for SortedVecBy<V, Natural> {
+    /// Same as [`SortedVec::from_unsorted`].
+    #[inline]
+    fn from(vec: V) -> Self {
+        Self::from_unsorted(vec)
+    }
+}
@@ -0,0 +1,228 @@
+use core::{fmt, marker::PhantomData, mem};
+
+use super::{cover, position};
+use crate::{
+    stack_vec::capacity_overflow,
+    traits::vec::{CapacityError, Vec},
+    vec_map::{Iter, IterMut, Keys, Values, ValuesMut},
+    StackVec,
+};
+
+/// A [`SparseMap`] of up to `N` values with ids below `U`, stored inline.
+pub type StackSparseMap<T, const U: usize, const N: usize> =
+    SparseMap<T, StackVec<usize, U>, StackVec<(usize, T), N>>;
+
+/// A [`SparseMap`] storing everything on the heap.
+#[cfg(feature = "std")]
+pub type StdSparseMap<T> = SparseMap<T, std::vec::Vec<usize>, std::vec::Vec<(usize, T)>>;
+
+/// A [`SparseSet`](super::SparseSet) with a value stored next to each id.
+pub struct SparseMap<T, S, D> {
+    sparse: S,
+    dense: D,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T, S, D> SparseMap<T, S, D>
+where
+    S: Vec<Item = usize> + Default,
+    D: Vec<Item = (usize, T)> + Default,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            sparse: S::default(),
+            dense: D::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, S: Vec<Item = usize>, D: Vec<Item = (usize, T)>> SparseMap<T, S, D> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.dense.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.dense.is_empty()
+    }
+
+    /// The entries, in no particular order.
+    #[inline]
+    pub fn as_slice(&self) -> &[(usize, T)] {
+        self.dense.as_slice()
+    }
+
+    #[inline]
+    pub fn contains_key(&self, id: usize) -> bool {
+        self.index_of(id).is_some()
+    }
+
+    /// Position of `id` in [`as_slice`](SparseMap::as_slice).
+    #[inline]
+    pub fn index_of(&self, id: usize) -> Option<usize> {
+        position(self.sparse.as_slice(), self.dense.as_slice(), id, |entry| {
+            entry.0
+        })
+    }
+
+    #[inline]
+    pub fn get(&self, id: usize) -> Option<&T> {
+        let index = self.index_of(id)?;
+        Some(&self.dense.as_slice()[index].1)
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, id: usize) -> Option<&mut T> {
+        let index = self.index_of(id)?;
+        Some(&mut self.dense.as_mut_slice()[index].1)
+    }
+
+    /// Stores `value` for `id`, returning the value it replaces.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `S` can not grow to cover `id` or `D` can not hold another
+    /// entry.
+    #[inline]
+    pub fn insert(&mut self, id: usize, value: T) -> Option<T> {
+        self.try_insert(id, value)
+            .unwrap_or_else(|_| capacity_overflow())
+    }
+
+    pub fn try_insert(&mut self, id: usize, value: T) -> Result<Option<T>, CapacityError<T>> {
+        if let Some(old) = self.get_mut(id) {
+            return Ok(Some(mem::replace(old, value)));
+        }
+        if cover(&mut self.sparse, id).is_err() {
+            return Err(CapacityError::new(value));
+        }
+        self.sparse.as_mut_slice()[id] = self.dense.len();
+        self.dense
+            .try_push((id, value))
+            .map_err(|error| CapacityError::new(error.element().1))?;
+        Ok(None)
+    }
+
+    /// Removes and returns the value of `id`, moving the last entry into its
+    /// place.
+    pub fn remove(&mut self, id: usize) -> Option<T> {
+        let index = self.index_of(id)?;
+        let (_, value) = self.dense.swap_remove(index);
+        if let Some(&(moved, _)) = self.dense.as_slice().get(index) {
+            self.sparse.as_mut_slice()[moved] = index;
+        }
+        Some(value)
+    }
+
+    /// Removes every entry, in O(1) besides dropping the values.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.dense.clear();
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, usize, T> {
+        Iter(self.dense.as_slice().iter())
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, usize, T> {
+        IterMut(self.dense.as_mut_slice().iter_mut())
+    }
+
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, usize, T> {
+        Keys(self.dense.as_slice().iter())
+    }
+
+    #[inline]
+    pub fn values(&self) -> Values<'_, usize, T> {
+        Values(self.dense.as_slice().iter())
+    }
+
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, usize, T> {
+        ValuesMut(self.dense.as_mut_slice().iter_mut())
+    }
+}
+
+impl<T, S, D> Default for SparseMap<T, S, D>
+where
+    S: Vec<Item = usize> + Default,
+    D: Vec<Item = (usize, T)> + Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, S: Clone, D: Clone> Clone for SparseMap<T, S, D> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            sparse: self.sparse.clone(),
+            dense: self.dense.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: fmt::Debug, S: Vec<Item = usize>, D: Vec<Item = (usize, T)>> fmt::Debug
+    for SparseMap<T, S, D>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T, S: Vec<Item = usize>, D: Vec<Item = (usize, T)>> Extend<(usize, T)> for SparseMap<T, S, D> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (usize, T)>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|(id, value)| {
+            self.insert(id, value);
+        });
+    }
+}
+
+impl<T, S, D> FromIterator<(usize, T)> for SparseMap<T, S, D>
+where
+    S: Vec<Item = usize> + Default,
+    D: Vec<Item = (usize, T)> + Default,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (usize, T)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<'a, T, S: Vec<Item = usize>, D: Vec<Item = (usize, T)>> IntoIterator
+    for &'a SparseMap<T, S, D>
+{
+    type Item = (&'a usize, &'a T);
+
+    type IntoIter = Iter<'a, usize, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, S: Vec<Item = usize>, D: Vec<Item = (usize, T)>> IntoIterator
+    for &'a mut SparseMap<T, S, D>
+{
+    type Item = (&'a usize, &'a mut T);
+
+    type IntoIter = IterMut<'a, usize, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
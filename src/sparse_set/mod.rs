@@ -0,0 +1,201 @@
+mod map;
+
+#[cfg(feature = "std")]
+pub use map::StdSparseMap;
+pub use map::{SparseMap, StackSparseMap};
+
+use core::{fmt, slice};
+
+use crate::{
+    stack_vec::capacity_overflow,
+    traits::vec::{CapacityError, Vec},
+    StackVec,
+};
+
+/// A [`SparseSet`] of up to `N` ids below `U`, stored inline.
+pub type StackSparseSet<const U: usize, const N: usize> =
+    SparseSet<StackVec<usize, U>, StackVec<usize, N>>;
+
+/// A [`SparseSet`] storing everything on the heap.
+#[cfg(feature = "std")]
+pub type StdSparseSet = SparseSet<std::vec::Vec<usize>, std::vec::Vec<usize>>;
+
+/// A set of integer ids with O(1) insertion, removal and lookup, iterated
+/// over in a dense array.
+///
+/// `S` maps each id to its position in `D`, which lists the members. Entries
+/// of `S` are only trusted when `D` points back at them, so clearing the set
+/// only has to clear `D`. Removal swaps the last member into the hole, so
+/// the order of the members is not kept.
+pub struct SparseSet<S, D> {
+    sparse: S,
+    dense: D,
+}
+
+impl<S: Vec<Item = usize> + Default, D: Vec<Item = usize> + Default> SparseSet<S, D> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            sparse: S::default(),
+            dense: D::default(),
+        }
+    }
+}
+
+impl<S: Vec<Item = usize>, D: Vec<Item = usize>> SparseSet<S, D> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.dense.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.dense.is_empty()
+    }
+
+    /// The members, in no particular order.
+    #[inline]
+    pub fn as_slice(&self) -> &[usize] {
+        self.dense.as_slice()
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, usize> {
+        self.dense.as_slice().iter()
+    }
+
+    #[inline]
+    pub fn contains(&self, id: usize) -> bool {
+        self.index_of(id).is_some()
+    }
+
+    /// Position of `id` in [`as_slice`](SparseSet::as_slice).
+    #[inline]
+    pub fn index_of(&self, id: usize) -> Option<usize> {
+        position(self.sparse.as_slice(), self.dense.as_slice(), id, |&id| id)
+    }
+
+    /// Adds `id`, returning whether it was absent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `S` can not grow to cover `id` or `D` can not hold another
+    /// member.
+    #[inline]
+    pub fn insert(&mut self, id: usize) -> bool {
+        self.try_insert(id).unwrap_or_else(|_| capacity_overflow())
+    }
+
+    pub fn try_insert(&mut self, id: usize) -> Result<bool, CapacityError<usize>> {
+        if self.contains(id) {
+            return Ok(false);
+        }
+        cover(&mut self.sparse, id).map_err(|_| CapacityError::new(id))?;
+        self.sparse.as_mut_slice()[id] = self.dense.len();
+        self.dense.try_push(id)?;
+        Ok(true)
+    }
+
+    /// Removes `id`, returning whether it was present.
+    pub fn remove(&mut self, id: usize) -> bool {
+        let Some(index) = self.index_of(id) else {
+            return false;
+        };
+        self.dense.swap_remove(index);
+        if let Some(&moved) = self.dense.as_slice().get(index) {
+            self.sparse.as_mut_slice()[moved] = index;
+        }
+        true
+    }
+
+    /// Removes every member in O(1).
+    #[inline]
+    pub fn clear(&mut self) {
+        self.dense.clear();
+    }
+}
+
+/// Position in `dense` of the entry for `id`.
+#[inline]
+fn position<T>(
+    sparse: &[usize],
+    dense: &[T],
+    id: usize,
+    key: impl Fn(&T) -> usize,
+) -> Option<usize> {
+    let index = *sparse.get(id)?;
+    dense
+        .get(index)
+        .filter(|entry| key(entry) == id)
+        .map(|_| index)
+}
+
+/// Grows `sparse` so that `id` is in bounds.
+fn cover<S: Vec<Item = usize>>(sparse: &mut S, id: usize) -> Result<(), CapacityError> {
+    let len = sparse.len();
+    if id >= len {
+        sparse.try_reserve(id + 1 - len)?;
+        sparse.resize(id + 1, usize::MAX);
+    }
+    Ok(())
+}
+
+impl<S, D> Default for SparseSet<S, D>
+where
+    S: Vec<Item = usize> + Default,
+    D: Vec<Item = usize> + Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Vec<Item = usize> + Clone, D: Vec<Item = usize> + Clone> Clone for SparseSet<S, D> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            sparse: self.sparse.clone(),
+            dense: self.dense.clone(),
+        }
+    }
+}
+
+impl<S: Vec<Item = usize>, D: Vec<Item = usize>> fmt::Debug for SparseSet<S, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<S: Vec<Item = usize>, D: Vec<Item = usize>> Extend<usize> for SparseSet<S, D> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|id| {
+            self.insert(id);
+        });
+    }
+}
+
+impl<S, D> FromIterator<usize> for SparseSet<S, D>
+where
+    S: Vec<Item = usize> + Default,
+    D: Vec<Item = usize> + Default,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a, S: Vec<Item = usize>, D: Vec<Item = usize>> IntoIterator for &'a SparseSet<S, D> {
+    type Item = &'a usize;
+
+    type IntoIter = slice::Iter<'a, usize>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
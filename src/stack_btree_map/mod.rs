@@ -0,0 +1,1273 @@
+mod set;
+
+pub use set::StackBTreeSet;
+
+use core::{
+    borrow::Borrow,
+    fmt,
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem,
+    ops::{Bound, Index, RangeBounds},
+    ptr,
+};
+
+use crate::{stack_vec::capacity_overflow, traits::vec::CapacityError, StackVec};
+
+/// Minimum degree: nodes other than the root hold `B - 1` to `2 * B - 1`
+/// entries.
+const B: usize = 6;
+const CAPACITY: usize = 2 * B - 1;
+const EDGES: usize = 2 * B;
+
+/// Node index standing for "no node".
+const NIL: usize = usize::MAX;
+
+#[derive(Clone)]
+struct Node<K, V> {
+    keys: StackVec<K, CAPACITY>,
+    vals: StackVec<V, CAPACITY>,
+    /// Empty for leaves. Holds the next free node while on the free list.
+    children: StackVec<usize, EDGES>,
+    /// `NIL` for the root.
+    parent: usize,
+}
+
+impl<K, V> Node<K, V> {
+    const fn new() -> Self {
+        Self {
+            keys: StackVec::new(),
+            vals: StackVec::new(),
+            children: StackVec::new(),
+            parent: NIL,
+        }
+    }
+
+    #[inline]
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    #[inline]
+    fn is_full(&self) -> bool {
+        self.keys.len() == CAPACITY
+    }
+
+    #[inline]
+    fn take(&mut self, index: usize) -> (K, V) {
+        (self.keys.remove(index), self.vals.remove(index))
+    }
+
+    #[inline]
+    fn replace(&mut self, index: usize, key: K, value: V) -> (K, V) {
+        (
+            mem::replace(&mut self.keys[index], key),
+            mem::replace(&mut self.vals[index], value),
+        )
+    }
+}
+
+/// What a removal looks for.
+enum Target<'a, Q: ?Sized> {
+    Key(&'a Q),
+    First,
+    Last,
+}
+
+/// An ordered map whose B-tree nodes come from a pool of `N` nodes stored
+/// inline, each holding up to 11 entries.
+///
+/// Every node but the root keeps at least 5 entries, so a pool of a fifth of
+/// the entries the map must hold, plus the tree height, is always enough.
+/// Nodes freed by removals go back to the pool. Insertions that would need
+/// more nodes than the pool has left fail up front, leaving the map as it
+/// was.
+pub struct StackBTreeMap<K, V, const N: usize> {
+    nodes: StackVec<Node<K, V>, N>,
+    root: usize,
+    free: usize,
+    free_len: usize,
+    len: usize,
+}
+
+impl<K, V, const N: usize> StackBTreeMap<K, V, N> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            nodes: StackVec::new(),
+            root: NIL,
+            free: NIL,
+            free_len: 0,
+            len: 0,
+        }
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Nodes left in the pool.
+    #[inline]
+    pub fn free_nodes(&self) -> usize {
+        N - self.nodes.len() + self.free_len
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.root = NIL;
+        self.free = NIL;
+        self.free_len = 0;
+        self.len = 0;
+    }
+
+    #[inline]
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        let mut node = self.nodes.get(self.root)?;
+        while let Some(&child) = node.children.first() {
+            node = &self.nodes[child];
+        }
+        Some((node.keys.first()?, node.vals.first()?))
+    }
+
+    #[inline]
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        let mut node = self.nodes.get(self.root)?;
+        while let Some(&child) = node.children.last() {
+            node = &self.nodes[child];
+        }
+        Some((node.keys.last()?, node.vals.last()?))
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            range: Range {
+                walk: Walk::new(self.nodes.as_ptr() as *mut _, self.root, |_| 0, <[K]>::len),
+            },
+            len: self.len,
+        }
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            range: RangeMut {
+                walk: Walk::new(self.nodes.as_mut_ptr(), self.root, |_| 0, <[K]>::len),
+                _marker: PhantomData,
+            },
+            len: self.len,
+        }
+    }
+
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys(self.iter())
+    }
+
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values(self.iter())
+    }
+
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut(self.iter_mut())
+    }
+
+    /// The entries with keys in `range`, in order. Crossed bounds give an
+    /// empty range.
+    #[inline]
+    pub fn range<Q, R>(&self, range: R) -> Range<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let (start, end) = (range.start_bound(), range.end_bound());
+        let root = if inverted(start, end) { NIL } else { self.root };
+        Range {
+            walk: Walk::new(
+                self.nodes.as_ptr() as *mut _,
+                root,
+                lower(start),
+                upper(end),
+            ),
+        }
+    }
+
+    #[inline]
+    pub fn range_mut<Q, R>(&mut self, range: R) -> RangeMut<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let (start, end) = (range.start_bound(), range.end_bound());
+        let root = if inverted(start, end) { NIL } else { self.root };
+        RangeMut {
+            walk: Walk::new(self.nodes.as_mut_ptr(), root, lower(start), upper(end)),
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (node, index) = self.find(key)?;
+        Some(&self.nodes[node].vals[index])
+    }
+
+    #[inline]
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (node, index) = self.find(key)?;
+        let node = &self.nodes[node];
+        Some((&node.keys[index], &node.vals[index]))
+    }
+
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (node, index) = self.find(key)?;
+        Some(&mut self.nodes[node].vals[index])
+    }
+
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.find(key).is_some()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `key` is new and the pool runs out of nodes.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        K: Ord,
+    {
+        self.try_insert(key, value)
+            .unwrap_or_else(|_| capacity_overflow())
+    }
+
+    /// Like [`insert`](StackBTreeMap::insert), but gives the pair back
+    /// instead of panicking when the pool runs out of nodes.
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, CapacityError<(K, V)>>
+    where
+        K: Ord,
+    {
+        if let Some(old) = self.get_mut(&key) {
+            return Ok(Some(mem::replace(old, value)));
+        }
+        self.try_insert_absent(key, value).map(|_| None)
+    }
+
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    #[inline]
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.remove_target(Target::Key(key))
+    }
+
+    #[inline]
+    pub fn pop_first(&mut self) -> Option<(K, V)>
+    where
+        K: Ord,
+    {
+        self.remove_target::<K>(Target::First)
+    }
+
+    #[inline]
+    pub fn pop_last(&mut self) -> Option<(K, V)>
+    where
+        K: Ord,
+    {
+        self.remove_target::<K>(Target::Last)
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, N>
+    where
+        K: Ord,
+    {
+        match self.find(&key) {
+            Some((node, index)) => Entry::Occupied(OccupiedEntry {
+                map: self,
+                key,
+                node,
+                index,
+            }),
+            None => Entry::Vacant(VacantEntry { map: self, key }),
+        }
+    }
+
+    /// Inserts every pair of `iter`, stopping at the first one that does not
+    /// fit and returning it.
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), CapacityError<(K, V)>>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Ord,
+    {
+        for (key, value) in iter {
+            self.try_insert(key, value)?;
+        }
+        Ok(())
+    }
+
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, CapacityError<(K, V)>>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Ord,
+    {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+
+    /// Node and position of `key`.
+    fn find<Q>(&self, key: &Q) -> Option<(usize, usize)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut node = self.root;
+        loop {
+            let current = self.nodes.get(node)?;
+            match current
+                .keys
+                .binary_search_by(|probe| probe.borrow().cmp(key))
+            {
+                Ok(index) => return Some((node, index)),
+                Err(index) => node = *current.children.get(index)?,
+            }
+        }
+    }
+
+    fn try_insert_absent(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<(usize, usize), CapacityError<(K, V)>>
+    where
+        K: Ord,
+    {
+        if self.free_nodes() < self.nodes_needed(&key) {
+            return Err(CapacityError::new((key, value)));
+        }
+        Ok(self.insert_absent(key, value))
+    }
+
+    /// Nodes that inserting the absent `key` takes from the pool: one per
+    /// full node on its path, plus a new root if that one is full.
+    fn nodes_needed(&self, key: &K) -> usize
+    where
+        K: Ord,
+    {
+        let Some(root) = self.nodes.get(self.root) else {
+            return 1;
+        };
+        let mut needed = usize::from(root.is_full());
+        let mut node = root;
+        loop {
+            needed += usize::from(node.is_full());
+            let index = node.keys.partition_point(|probe| probe < key);
+            match node.children.get(index) {
+                Some(&child) => node = &self.nodes[child],
+                None => return needed,
+            }
+        }
+    }
+
+    /// Inserts the absent `key`, splitting full nodes on the way down.
+    fn insert_absent(&mut self, key: K, value: V) -> (usize, usize)
+    where
+        K: Ord,
+    {
+        if self.root == NIL {
+            self.root = self.alloc();
+        }
+        if self.nodes[self.root].is_full() {
+            let root = self.alloc();
+            self.nodes[root].children.push(self.root);
+            self.nodes[self.root].parent = root;
+            self.root = root;
+            self.split_child(root, 0);
+        }
+        self.len += 1;
+        let mut node = self.root;
+        loop {
+            let mut index = self.nodes[node].keys.partition_point(|probe| *probe < key);
+            if self.nodes[node].is_leaf() {
+                self.nodes[node].keys.insert(index, key);
+                self.nodes[node].vals.insert(index, value);
+                return (node, index);
+            }
+            let child = self.nodes[node].children[index];
+            if self.nodes[child].is_full() {
+                self.split_child(node, index);
+                if self.nodes[node].keys[index] < key {
+                    index += 1;
+                }
+            }
+            node = self.nodes[node].children[index];
+        }
+    }
+
+    /// Splits the full child `index` of `node` around its median entry,
+    /// which moves up into `node`.
+    fn split_child(&mut self, node: usize, index: usize) {
+        let left = self.nodes[node].children[index];
+        let right = self.alloc();
+        let full = &mut self.nodes[left];
+        let keys = full.keys.split_off(B);
+        let vals = full.vals.split_off(B);
+        let children = if full.is_leaf() {
+            StackVec::new()
+        } else {
+            full.children.split_off(B)
+        };
+        let key = full.keys.pop().unwrap();
+        let value = full.vals.pop().unwrap();
+        self.nodes[right] = Node {
+            keys,
+            vals,
+            children,
+            parent: node,
+        };
+        self.adopt(right, 0);
+        let parent = &mut self.nodes[node];
+        parent.keys.insert(index, key);
+        parent.vals.insert(index, value);
+        parent.children.insert(index + 1, right);
+    }
+
+    fn remove_target<Q>(&mut self, target: Target<'_, Q>) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        if self.root == NIL {
+            return None;
+        }
+        let removed = self.remove_from(self.root, target)?;
+        self.len -= 1;
+        if self.len == 0 {
+            self.clear();
+        }
+        Some(removed)
+    }
+
+    /// Removes `target` from the subtree of `node`, topping up each node on
+    /// the way down to at least `B` entries so that removing from it can not
+    /// underflow it.
+    fn remove_from<Q>(&mut self, mut node: usize, target: Target<'_, Q>) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        loop {
+            let current = &self.nodes[node];
+            let leaf = current.is_leaf();
+            let position = match target {
+                Target::Key(key) => current
+                    .keys
+                    .binary_search_by(|probe| probe.borrow().cmp(key)),
+                Target::First if leaf => Ok(0),
+                Target::First => Err(0),
+                Target::Last if leaf => Ok(current.keys.len() - 1),
+                Target::Last => Err(current.keys.len()),
+            };
+            match position {
+                Ok(index) if leaf => return Some(self.nodes[node].take(index)),
+                Err(_) if leaf => return None,
+                Ok(index) => {
+                    let left = self.nodes[node].children[index];
+                    let right = self.nodes[node].children[index + 1];
+                    if self.nodes[left].keys.len() >= B {
+                        let (key, value) = self.remove_from::<Q>(left, Target::Last)?;
+                        return Some(self.nodes[node].replace(index, key, value));
+                    }
+                    if self.nodes[right].keys.len() >= B {
+                        let (key, value) = self.remove_from::<Q>(right, Target::First)?;
+                        return Some(self.nodes[node].replace(index, key, value));
+                    }
+                    node = self.merge(node, index);
+                }
+                Err(index) => node = self.fill(node, index),
+            }
+        }
+    }
+
+    /// Makes sure child `index` of `node` holds at least `B` entries and
+    /// returns the node now covering its keys.
+    fn fill(&mut self, node: usize, index: usize) -> usize {
+        let children = &self.nodes[node].children;
+        let child = children[index];
+        let left = index.checked_sub(1).map(|index| children[index]);
+        let right = children.get(index + 1).copied();
+        if self.nodes[child].keys.len() >= B {
+            child
+        } else if left.is_some_and(|left| self.nodes[left].keys.len() >= B) {
+            self.rotate_right(node, index);
+            child
+        } else if right.is_some_and(|right| self.nodes[right].keys.len() >= B) {
+            self.rotate_left(node, index);
+            child
+        } else if right.is_some() {
+            self.merge(node, index)
+        } else {
+            self.merge(node, index - 1)
+        }
+    }
+
+    /// Moves the last entry of child `index - 1` of `node` up, and the entry
+    /// between them down into child `index`.
+    fn rotate_right(&mut self, node: usize, index: usize) {
+        let children = &self.nodes[node].children;
+        let (left, child) = (children[index - 1], children[index]);
+        let left = &mut self.nodes[left];
+        let key = left.keys.pop().unwrap();
+        let value = left.vals.pop().unwrap();
+        let edge = left.children.pop();
+        let (key, value) = self.nodes[node].replace(index - 1, key, value);
+        let moved = &mut self.nodes[child];
+        moved.keys.insert(0, key);
+        moved.vals.insert(0, value);
+        if let Some(edge) = edge {
+            moved.children.insert(0, edge);
+            self.nodes[edge].parent = child;
+        }
+    }
+
+    /// Moves the first entry of child `index + 1` of `node` up, and the
+    /// entry between them down into child `index`.
+    fn rotate_left(&mut self, node: usize, index: usize) {
+        let children = &self.nodes[node].children;
+        let (child, right) = (children[index], children[index + 1]);
+        let right = &mut self.nodes[right];
+        let (key, value) = right.take(0);
+        let edge = (!right.is_leaf()).then(|| right.children.remove(0));
+        let (key, value) = self.nodes[node].replace(index, key, value);
+        let moved = &mut self.nodes[child];
+        moved.keys.push(key);
+        moved.vals.push(value);
+        if let Some(edge) = edge {
+            moved.children.push(edge);
+            self.nodes[edge].parent = child;
+        }
+    }
+
+    /// Merges children `index` and `index + 1` of `node` and the entry
+    /// between them into the first one, which it returns.
+    fn merge(&mut self, node: usize, index: usize) -> usize {
+        let parent = &mut self.nodes[node];
+        let (key, value) = parent.take(index);
+        let left = parent.children[index];
+        let right = parent.children.remove(index + 1);
+        let right_node = mem::replace(&mut self.nodes[right], Node::new());
+        let merged = &mut self.nodes[left];
+        let edges = merged.children.len();
+        merged.keys.push(key);
+        merged.vals.push(value);
+        merged.keys.append(right_node.keys);
+        merged.vals.append(right_node.vals);
+        merged.children.append(right_node.children);
+        self.adopt(left, edges);
+        self.release(right);
+        if node == self.root && self.nodes[node].keys.is_empty() {
+            self.root = left;
+            self.nodes[left].parent = NIL;
+            self.release(node);
+        }
+        left
+    }
+
+    /// Points the children of `node` from edge `from` on back at it.
+    fn adopt(&mut self, node: usize, from: usize) {
+        for edge in from..self.nodes[node].children.len() {
+            let child = self.nodes[node].children[edge];
+            self.nodes[child].parent = node;
+        }
+    }
+
+    fn alloc(&mut self) -> usize {
+        if self.free == NIL {
+            self.nodes.push(Node::new());
+            return self.nodes.len() - 1;
+        }
+        let node = self.free;
+        self.free = self.nodes[node].children[0];
+        self.nodes[node].children.clear();
+        self.nodes[node].parent = NIL;
+        self.free_len -= 1;
+        node
+    }
+
+    /// Puts the empty `node` on the free list.
+    fn release(&mut self, node: usize) {
+        let children = &mut self.nodes[node].children;
+        children.clear();
+        children.push(self.free);
+        self.free = node;
+        self.free_len += 1;
+    }
+}
+
+impl<K, V, const N: usize> Default for StackBTreeMap<K, V, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone, V: Clone, const N: usize> Clone for StackBTreeMap<K, V, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes.clone(),
+            root: self.root,
+            free: self.free,
+            free_len: self.free_len,
+            len: self.len,
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, const N: usize> fmt::Debug for StackBTreeMap<K, V, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, const N1: usize, const N2: usize> PartialEq<StackBTreeMap<K, V, N2>>
+    for StackBTreeMap<K, V, N1>
+where
+    K: PartialEq,
+    V: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &StackBTreeMap<K, V, N2>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Eq, V: Eq, const N: usize> Eq for StackBTreeMap<K, V, N> {}
+
+impl<K, V, const N: usize, Q> Index<&Q> for StackBTreeMap<K, V, N>
+where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    type Output = V;
+
+    /// # Panics
+    ///
+    /// Panics if `key` is not in the map.
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found")
+    }
+}
+
+impl<K: Ord, V, const N: usize> Extend<(K, V)> for StackBTreeMap<K, V, N> {
+    /// # Panics
+    ///
+    /// Panics if the pool runs out of nodes.
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|(key, value)| {
+            self.insert(key, value);
+        });
+    }
+}
+
+impl<K: Ord, V, const N: usize> FromIterator<(K, V)> for StackBTreeMap<K, V, N> {
+    /// # Panics
+    ///
+    /// Panics if the pool runs out of nodes.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Ord, V, const N: usize> IntoIterator for StackBTreeMap<K, V, N> {
+    type Item = (K, V);
+
+    type IntoIter = IntoIter<K, V, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, K, V, const N: usize> IntoIterator for &'a StackBTreeMap<K, V, N> {
+    type Item = (&'a K, &'a V);
+
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, const N: usize> IntoIterator for &'a mut StackBTreeMap<K, V, N> {
+    type Item = (&'a K, &'a mut V);
+
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// A view into a single entry of a [`StackBTreeMap`].
+pub enum Entry<'a, K, V, const N: usize> {
+    Occupied(OccupiedEntry<'a, K, V, N>),
+    Vacant(VacantEntry<'a, K, V, N>),
+}
+
+pub struct OccupiedEntry<'a, K, V, const N: usize> {
+    map: &'a mut StackBTreeMap<K, V, N>,
+    /// The key the entry was looked up with.
+    key: K,
+    node: usize,
+    index: usize,
+}
+
+pub struct VacantEntry<'a, K, V, const N: usize> {
+    map: &'a mut StackBTreeMap<K, V, N>,
+    key: K,
+}
+
+impl<'a, K: Ord, V, const N: usize> Entry<'a, K, V, N> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> OccupiedEntry<'a, K, V, N> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.map.nodes[self.node].keys[self.index]
+    }
+
+    #[inline]
+    pub fn get(&self) -> &V {
+        &self.map.nodes[self.node].vals[self.index]
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map.nodes[self.node].vals[self.index]
+    }
+
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.nodes[self.node].vals[self.index]
+    }
+
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    #[inline]
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    #[inline]
+    pub fn remove_entry(self) -> (K, V) {
+        self.map.remove_entry(&self.key).unwrap()
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> VacantEntry<'a, K, V, N> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    #[inline]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the pool runs out of nodes.
+    #[inline]
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.try_insert(value)
+            .unwrap_or_else(|_| capacity_overflow())
+    }
+
+    pub fn try_insert(self, value: V) -> Result<&'a mut V, CapacityError<(K, V)>> {
+        let (node, index) = self.map.try_insert_absent(self.key, value)?;
+        Ok(&mut self.map.nodes[node].vals[index])
+    }
+}
+
+/// Walks the entries between two leaf edges, from either end.
+///
+/// An edge `(node, i)` sits before entry `i` of `node`. Both ends always rest
+/// on a leaf edge, and every gap between two entries has exactly one, so the
+/// walk is over once both ends reach the same one.
+struct Walk<'a, K, V> {
+    nodes: *mut Node<K, V>,
+    front: (usize, usize),
+    back: (usize, usize),
+    _marker: PhantomData<&'a Node<K, V>>,
+}
+
+impl<'a, K, V> Walk<'a, K, V> {
+    /// Picks the edge to take out of each node from its keys.
+    fn new(
+        nodes: *mut Node<K, V>,
+        root: usize,
+        front: impl FnMut(&[K]) -> usize,
+        back: impl FnMut(&[K]) -> usize,
+    ) -> Self {
+        let mut walk = Self {
+            nodes,
+            front: (NIL, 0),
+            back: (NIL, 0),
+            _marker: PhantomData,
+        };
+        if root != NIL {
+            walk.front = walk.descend(root, front);
+            walk.back = walk.descend(root, back);
+        }
+        walk
+    }
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        if self.front == self.back {
+            return None;
+        }
+        let (mut node, mut edge) = self.front;
+        while edge == self.keys(node).len() {
+            (node, edge) = self.ascend(node);
+        }
+        self.front = match self.children(node).get(edge + 1) {
+            Some(&child) => self.descend(child, |_| 0),
+            None => (node, edge + 1),
+        };
+        Some((node, edge))
+    }
+
+    fn next_back(&mut self) -> Option<(usize, usize)> {
+        if self.front == self.back {
+            return None;
+        }
+        let (mut node, mut edge) = self.back;
+        while edge == 0 {
+            (node, edge) = self.ascend(node);
+        }
+        self.back = match self.children(node).get(edge - 1) {
+            Some(&child) => self.descend(child, <[K]>::len),
+            None => (node, edge - 1),
+        };
+        Some((node, edge - 1))
+    }
+
+    /// The leaf edge reached from `node` by taking `edge` out of each node.
+    fn descend(&self, mut node: usize, mut edge: impl FnMut(&[K]) -> usize) -> (usize, usize) {
+        loop {
+            let index = edge(self.keys(node));
+            match self.children(node).get(index) {
+                Some(&child) => node = child,
+                None => return (node, index),
+            }
+        }
+    }
+
+    /// The edge of its parent leading to `node`, which is not the root.
+    fn ascend(&self, node: usize) -> (usize, usize) {
+        let parent = unsafe { *ptr::addr_of!((*self.nodes.add(node)).parent) };
+        let edge = self
+            .children(parent)
+            .iter()
+            .position(|&child| child == node);
+        (parent, edge.unwrap())
+    }
+
+    // The keys, children and parents are never handed out mutably, so they
+    // can be read while values are.
+
+    #[inline]
+    fn keys(&self, node: usize) -> &'a [K] {
+        unsafe { &*ptr::addr_of!((*self.nodes.add(node)).keys) }
+    }
+
+    #[inline]
+    fn children(&self, node: usize) -> &'a [usize] {
+        unsafe { &*ptr::addr_of!((*self.nodes.add(node)).children) }
+    }
+
+    #[inline]
+    fn key(&self, (node, index): (usize, usize)) -> &'a K {
+        &self.keys(node)[index]
+    }
+
+    /// # Safety
+    ///
+    /// The walk must come from a shared borrow of the map or hand out each
+    /// value once.
+    #[inline]
+    unsafe fn value(&self, (node, index): (usize, usize)) -> *mut V {
+        StackVec::raw_ptr(ptr::addr_of_mut!((*self.nodes.add(node)).vals)).add(index)
+    }
+}
+
+impl<K, V> Clone for Walk<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes,
+            front: self.front,
+            back: self.back,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Edge picker for the start of a range.
+fn lower<K: Borrow<Q>, Q: Ord + ?Sized>(bound: Bound<&Q>) -> impl Fn(&[K]) -> usize + '_ {
+    move |keys| match bound {
+        Bound::Included(bound) => keys.partition_point(|key| key.borrow() < bound),
+        Bound::Excluded(bound) => keys.partition_point(|key| key.borrow() <= bound),
+        Bound::Unbounded => 0,
+    }
+}
+
+/// Edge picker for the end of a range.
+fn upper<K: Borrow<Q>, Q: Ord + ?Sized>(bound: Bound<&Q>) -> impl Fn(&[K]) -> usize + '_ {
+    move |keys| match bound {
+        Bound::Included(bound) => keys.partition_point(|key| key.borrow() <= bound),
+        Bound::Excluded(bound) => keys.partition_point(|key| key.borrow() < bound),
+        Bound::Unbounded => keys.len(),
+    }
+}
+
+/// Whether the bounds cross, leaving the walk's ends out of order.
+fn inverted<Q: Ord + ?Sized>(start: Bound<&Q>, end: Bound<&Q>) -> bool {
+    match (start, end) {
+        (Bound::Excluded(start), Bound::Excluded(end)) => start >= end,
+        (
+            Bound::Included(start) | Bound::Excluded(start),
+            Bound::Included(end) | Bound::Excluded(end),
+        ) => start > end,
+        _ => false,
+    }
+}
+
+/// Iterator over the entries of a [`StackBTreeMap`] with keys in a range.
+pub struct Range<'a, K, V> {
+    walk: Walk<'a, K, V>,
+}
+
+impl<K, V> Clone for Range<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            walk: self.walk.clone(),
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.walk.next()?;
+        Some((self.walk.key(position), unsafe {
+            &*self.walk.value(position)
+        }))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Range<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let position = self.walk.next_back()?;
+        Some((self.walk.key(position), unsafe {
+            &*self.walk.value(position)
+        }))
+    }
+}
+
+impl<K, V> FusedIterator for Range<'_, K, V> {}
+
+// The walk only reads through its pointer, like a `&'a StackBTreeMap`.
+unsafe impl<K: Sync, V: Sync> Send for Range<'_, K, V> {}
+
+unsafe impl<K: Sync, V: Sync> Sync for Range<'_, K, V> {}
+
+/// Mutable iterator over the entries of a [`StackBTreeMap`] with keys in a
+/// range.
+pub struct RangeMut<'a, K, V> {
+    walk: Walk<'a, K, V>,
+    _marker: PhantomData<&'a mut V>,
+}
+
+impl<'a, K, V> Iterator for RangeMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.walk.next()?;
+        Some((self.walk.key(position), unsafe {
+            &mut *self.walk.value(position)
+        }))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for RangeMut<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let position = self.walk.next_back()?;
+        Some((self.walk.key(position), unsafe {
+            &mut *self.walk.value(position)
+        }))
+    }
+}
+
+impl<K, V> FusedIterator for RangeMut<'_, K, V> {}
+
+// Only values are handed out mutably, each once.
+unsafe impl<K: Sync, V: Send> Send for RangeMut<'_, K, V> {}
+
+unsafe impl<K: Sync, V: Sync> Sync for RangeMut<'_, K, V> {}
+
+macro_rules! sized_iter {
+    ($(#[$attr:meta])* $name:ident, $range:ident, $item:ty) => {
+        $(#[$attr])*
+        pub struct $name<'a, K, V> {
+            range: $range<'a, K, V>,
+            len: usize,
+        }
+
+        impl<'a, K, V> Iterator for $name<'a, K, V> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let item = self.range.next()?;
+                self.len -= 1;
+                Some(item)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+
+        impl<K, V> DoubleEndedIterator for $name<'_, K, V> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let item = self.range.next_back()?;
+                self.len -= 1;
+                Some(item)
+            }
+        }
+
+        impl<K, V> ExactSizeIterator for $name<'_, K, V> {}
+
+        impl<K, V> FusedIterator for $name<'_, K, V> {}
+    };
+}
+
+sized_iter!(
+    /// Iterator over the entries of a [`StackBTreeMap`], in key order.
+    Iter,
+    Range,
+    (&'a K, &'a V)
+);
+
+sized_iter!(
+    /// Mutable iterator over the entries of a [`StackBTreeMap`], in key
+    /// order.
+    IterMut,
+    RangeMut,
+    (&'a K, &'a mut V)
+);
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            range: self.range.clone(),
+            len: self.len,
+        }
+    }
+}
+
+macro_rules! projection {
+    ($(#[$attr:meta])* $name:ident, $inner:ident, $item:ty, $project:expr) => {
+        $(#[$attr])*
+        pub struct $name<'a, K, V>($inner<'a, K, V>);
+
+        impl<'a, K, V> Iterator for $name<'a, K, V> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.next().map($project)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.size_hint()
+            }
+        }
+
+        impl<K, V> DoubleEndedIterator for $name<'_, K, V> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.0.next_back().map($project)
+            }
+        }
+
+        impl<K, V> ExactSizeIterator for $name<'_, K, V> {}
+
+        impl<K, V> FusedIterator for $name<'_, K, V> {}
+    };
+}
+
+projection!(
+    /// Iterator over the keys of a [`StackBTreeMap`], in order.
+    Keys,
+    Iter,
+    &'a K,
+    |(key, _)| key
+);
+
+projection!(
+    /// Iterator over the values of a [`StackBTreeMap`], in key order.
+    Values,
+    Iter,
+    &'a V,
+    |(_, value)| value
+);
+
+projection!(
+    /// Mutable iterator over the values of a [`StackBTreeMap`], in key
+    /// order.
+    ValuesMut,
+    IterMut,
+    &'a mut V,
+    |(_, value)| value
+);
+
+impl<K, V> Clone for Keys<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<K, V> Clone for Values<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Owning iterator over the entries of a [`StackBTreeMap`], in key order.
+pub struct IntoIter<K, V, const N: usize>(StackBTreeMap<K, V, N>);
+
+impl<K: Ord, V, const N: usize> Iterator for IntoIter<K, V, N> {
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_first()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<K: Ord, V, const N: usize> DoubleEndedIterator for IntoIter<K, V, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_last()
+    }
+}
+
+impl<K: Ord, V, const N: usize> ExactSizeIterator for IntoIter<K, V, N> {}
+
+impl<K: Ord, V, const N: usize> FusedIterator for IntoIter<K, V, N> {}
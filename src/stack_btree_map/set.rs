@@ -0,0 +1,303 @@
+use core::{borrow::Borrow, fmt, iter::FusedIterator, ops::RangeBounds};
+
+use super::{Keys, StackBTreeMap};
+use crate::{stack_vec::capacity_overflow, traits::vec::CapacityError};
+
+/// An ordered set built on [`StackBTreeMap`], whose pool of `N` nodes holds
+/// up to 11 items each.
+pub struct StackBTreeSet<T, const N: usize> {
+    map: StackBTreeMap<T, (), N>,
+}
+
+impl<T, const N: usize> StackBTreeSet<T, N> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            map: StackBTreeMap::new(),
+        }
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Nodes left in the pool.
+    #[inline]
+    pub fn free_nodes(&self) -> usize {
+        self.map.free_nodes()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.map.first_key_value().map(|(item, _)| item)
+    }
+
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.map.last_key_value().map(|(item, _)| item)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter(self.map.keys())
+    }
+}
+
+impl<T: Ord, const N: usize> StackBTreeSet<T, N> {
+    /// The items in `range`, in order.
+    #[inline]
+    pub fn range<Q, R>(&self, range: R) -> Range<'_, T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        Range(self.map.range(range))
+    }
+
+    #[inline]
+    pub fn contains<Q>(&self, item: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.contains_key(item)
+    }
+
+    #[inline]
+    pub fn get<Q>(&self, item: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.get_key_value(item).map(|(item, _)| item)
+    }
+
+    /// Adds `item`, returning whether it was not present yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `item` is new and the pool runs out of nodes.
+    #[inline]
+    pub fn insert(&mut self, item: T) -> bool {
+        self.try_insert(item)
+            .unwrap_or_else(|_| capacity_overflow())
+    }
+
+    /// Like [`insert`](StackBTreeSet::insert), but gives `item` back instead
+    /// of panicking when the pool runs out of nodes.
+    #[inline]
+    pub fn try_insert(&mut self, item: T) -> Result<bool, CapacityError<T>> {
+        match self.map.try_insert(item, ()) {
+            Ok(replaced) => Ok(replaced.is_none()),
+            Err(err) => Err(CapacityError::new(err.element().0)),
+        }
+    }
+
+    #[inline]
+    pub fn remove<Q>(&mut self, item: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.remove(item).is_some()
+    }
+
+    #[inline]
+    pub fn take<Q>(&mut self, item: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.remove_entry(item).map(|(item, ())| item)
+    }
+
+    #[inline]
+    pub fn pop_first(&mut self) -> Option<T> {
+        self.map.pop_first().map(|(item, ())| item)
+    }
+
+    #[inline]
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.map.pop_last().map(|(item, ())| item)
+    }
+}
+
+impl<T, const N: usize> Default for StackBTreeSet<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for StackBTreeSet<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for StackBTreeSet<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const N1: usize, const N2: usize> PartialEq<StackBTreeSet<T, N2>>
+    for StackBTreeSet<T, N1>
+{
+    #[inline]
+    fn eq(&self, other: &StackBTreeSet<T, N2>) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for StackBTreeSet<T, N> {}
+
+impl<T: Ord, const N: usize> Extend<T> for StackBTreeSet<T, N> {
+    /// # Panics
+    ///
+    /// Panics if the pool runs out of nodes.
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.map.extend(iter.into_iter().map(|item| (item, ())));
+    }
+}
+
+impl<T: Ord, const N: usize> FromIterator<T> for StackBTreeSet<T, N> {
+    /// # Panics
+    ///
+    /// Panics if the pool runs out of nodes.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Ord, const N: usize> IntoIterator for StackBTreeSet<T, N> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.map.into_iter())
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a StackBTreeSet<T, N> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the items of a [`StackBTreeSet`], in order.
+pub struct Iter<'a, T>(Keys<'a, T, ()>);
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// Iterator over the items of a [`StackBTreeSet`] in a range.
+pub struct Range<'a, T>(super::Range<'a, T, ()>);
+
+impl<T> Clone for Range<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<'a, T> Iterator for Range<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.0.next().map(|(item, _)| item)
+    }
+}
+
+impl<T> DoubleEndedIterator for Range<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(item, _)| item)
+    }
+}
+
+impl<T> FusedIterator for Range<'_, T> {}
+
+/// Owning iterator over the items of a [`StackBTreeSet`], in order.
+pub struct IntoIter<T, const N: usize>(super::IntoIter<T, (), N>);
+
+impl<T: Ord, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.0.next().map(|(item, ())| item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T: Ord, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.0.next_back().map(|(item, ())| item)
+    }
+}
+
+impl<T: Ord, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T: Ord, const N: usize> FusedIterator for IntoIter<T, N> {}
@@ -0,0 +1,875 @@
+use core::{
+    borrow::Borrow,
+    fmt,
+    hash::{BuildHasher, Hash},
+    iter::FusedIterator,
+    mem::{self, MaybeUninit},
+    ops::Index,
+    ptr, slice,
+};
+
+use crate::{stack_vec::capacity_overflow, traits::vec::CapacityError};
+
+mod hasher;
+pub mod set;
+
+pub use hasher::{DefaultHashBuilder, FxBuildHasher, FxHasher};
+pub use set::StackHashSet;
+
+/// Bucket tag of an empty slot.
+const EMPTY: u32 = 0;
+/// Set on the tag of every occupied slot.
+const OCCUPIED: u32 = 1 << 31;
+/// Set by `retain` on slots whose entry was dropped, until they are purged.
+const DELETED: u32 = 1 << 30;
+/// Bits of the tag holding the key's hash.
+const HASH_MASK: u32 = DELETED - 1;
+
+/// A fixed-capacity hash map storing up to `N` entries inline.
+///
+/// Collisions are resolved by linear probing. Removal shifts the following
+/// entries of the probe sequence back instead of leaving tombstones, so
+/// lookups never slow down as entries come and go.
+pub struct StackHashMap<K, V, const N: usize, S = DefaultHashBuilder> {
+    tags: [u32; N],
+    slots: [MaybeUninit<(K, V)>; N],
+    len: usize,
+    hash_builder: S,
+}
+
+impl<K, V, const N: usize> StackHashMap<K, V, N> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_hasher(FxBuildHasher)
+    }
+}
+
+impl<K, V, const N: usize, S> StackHashMap<K, V, N, S> {
+    const UNINIT_SLOT: MaybeUninit<(K, V)> = MaybeUninit::uninit();
+    const UNINIT_SLOTS: [MaybeUninit<(K, V)>; N] = [Self::UNINIT_SLOT; N];
+
+    #[inline]
+    pub const fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            tags: [EMPTY; N],
+            slots: Self::UNINIT_SLOTS,
+            len: 0,
+            hash_builder,
+        }
+    }
+
+    #[inline]
+    pub const fn capacity() -> usize {
+        N
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    pub fn clear(&mut self) {
+        let tags = mem::replace(&mut self.tags, [EMPTY; N]);
+        self.len = 0;
+        for (tag, slot) in tags.iter().zip(&mut self.slots) {
+            if *tag != EMPTY {
+                unsafe { slot.assume_init_drop() };
+            }
+        }
+    }
+
+    /// Keeps only the entries for which `f` returns `true`.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        /// Purges the slots marked as deleted, even if `f` panics.
+        struct PurgeOnDrop<'a, K, V, const N: usize, S> {
+            map: &'a mut StackHashMap<K, V, N, S>,
+            deleted: usize,
+        }
+
+        impl<K, V, const N: usize, S> Drop for PurgeOnDrop<'_, K, V, N, S> {
+            fn drop(&mut self) {
+                let mut index = 0;
+                while self.deleted > 0 {
+                    if self.map.tags[index] & DELETED != 0 {
+                        self.map.tags[index] = EMPTY;
+                        self.map.shift_back(index);
+                        self.deleted -= 1;
+                    } else {
+                        index = StackHashMap::<K, V, N, S>::next(index);
+                    }
+                }
+            }
+        }
+
+        // Deleted slots stay in the probe sequences until the guard purges
+        // them, so no entry moves while `f` is running.
+        let mut guard = PurgeOnDrop {
+            map: self,
+            deleted: 0,
+        };
+        for index in 0..N {
+            if guard.map.tags[index] & OCCUPIED == 0 {
+                continue;
+            }
+            let (key, value) = unsafe { guard.map.slots[index].assume_init_mut() };
+            if !f(key, value) {
+                guard.map.tags[index] |= DELETED;
+                guard.map.len -= 1;
+                guard.deleted += 1;
+                unsafe { guard.map.slots[index].assume_init_drop() };
+            }
+        }
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            tags: self.tags.iter(),
+            slots: self.slots.iter(),
+            len: self.len,
+        }
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            tags: self.tags.iter(),
+            slots: self.slots.iter_mut(),
+            len: self.len,
+        }
+    }
+
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys(self.iter())
+    }
+
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values(self.iter())
+    }
+
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut(self.iter_mut())
+    }
+
+    #[inline]
+    fn home(tag: u32) -> usize {
+        (tag & HASH_MASK) as usize % N
+    }
+
+    #[inline]
+    fn next(index: usize) -> usize {
+        if index + 1 == N {
+            0
+        } else {
+            index + 1
+        }
+    }
+
+    /// # Safety
+    ///
+    /// The slot at `index` must be occupied.
+    #[inline]
+    unsafe fn entry_at(&self, index: usize) -> &(K, V) {
+        unsafe { self.slots.get_unchecked(index).assume_init_ref() }
+    }
+
+    /// # Safety
+    ///
+    /// The slot at `index` must be occupied.
+    #[inline]
+    unsafe fn entry_at_mut(&mut self, index: usize) -> &mut (K, V) {
+        unsafe { self.slots.get_unchecked_mut(index).assume_init_mut() }
+    }
+
+    /// # Safety
+    ///
+    /// The slot at `index` must be empty and the first empty one of the
+    /// probe sequence of `tag`.
+    #[inline]
+    unsafe fn write_at(&mut self, index: usize, tag: u32, entry: (K, V)) -> &mut (K, V) {
+        self.tags[index] = tag;
+        self.len += 1;
+        self.slots[index].write(entry)
+    }
+
+    /// # Safety
+    ///
+    /// The slot at `index` must be occupied.
+    unsafe fn remove_at(&mut self, index: usize) -> (K, V) {
+        let entry = unsafe { self.slots[index].assume_init_read() };
+        self.tags[index] = EMPTY;
+        self.len -= 1;
+        self.shift_back(index);
+        entry
+    }
+
+    /// Moves back the entries following the newly emptied slot `hole` that
+    /// can get closer to their home slot, keeping every probe sequence free
+    /// of empty slots.
+    fn shift_back(&mut self, mut hole: usize) {
+        let slots = self.slots.as_mut_ptr();
+        let mut index = hole;
+        loop {
+            index = Self::next(index);
+            let tag = self.tags[index];
+            if tag == EMPTY {
+                return;
+            }
+            let home = Self::home(tag);
+            if (hole + N - home) % N < (index + N - home) % N {
+                self.tags[hole] = tag;
+                self.tags[index] = EMPTY;
+                unsafe { ptr::copy_nonoverlapping(slots.add(index), slots.add(hole), 1) };
+                hole = index;
+            }
+        }
+    }
+}
+
+impl<K, V, const N: usize, S: BuildHasher> StackHashMap<K, V, N, S> {
+    #[inline]
+    fn tag<Q: Hash + ?Sized>(&self, key: &Q) -> u32 {
+        ((self.hash_builder.hash_one(key) >> 32) as u32 & HASH_MASK) | OCCUPIED
+    }
+
+    /// Index of the entry for `key`, or else of the empty slot it would be
+    /// stored at, if any.
+    fn find<Q>(&self, tag: u32, key: &Q) -> Result<usize, Option<usize>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        if N == 0 {
+            return Err(None);
+        }
+        let mut index = Self::home(tag);
+        for _ in 0..N {
+            match self.tags[index] {
+                EMPTY => return Err(Some(index)),
+                found if found == tag && unsafe { self.entry_at(index) }.0.borrow() == key => {
+                    return Ok(index)
+                }
+                _ => index = Self::next(index),
+            }
+        }
+        Err(None)
+    }
+
+    #[inline]
+    fn find_key<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.is_empty() {
+            return None;
+        }
+        self.find(self.tag(key), key).ok()
+    }
+
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_key_value(key).map(|(_, v)| v)
+    }
+
+    #[inline]
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (k, v) = unsafe { self.entry_at(self.find_key(key)?) };
+        Some((k, v))
+    }
+
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find_key(key)?;
+        Some(unsafe { &mut self.entry_at_mut(index).1 })
+    }
+
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find_key(key).is_some()
+    }
+
+    /// Inserts `value` under `key`, returning the value it replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is new and the map is full.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        K: Hash + Eq,
+    {
+        self.try_insert(key, value)
+            .unwrap_or_else(|_| capacity_overflow())
+    }
+
+    /// Like [`insert`](StackHashMap::insert), but gives the entry back
+    /// instead of panicking when the map is full.
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, CapacityError<(K, V)>>
+    where
+        K: Hash + Eq,
+    {
+        let tag = self.tag(&key);
+        match self.find(tag, &key) {
+            Ok(index) => Ok(Some(mem::replace(
+                unsafe { &mut self.entry_at_mut(index).1 },
+                value,
+            ))),
+            Err(Some(index)) => {
+                unsafe { self.write_at(index, tag, (key, value)) };
+                Ok(None)
+            }
+            Err(None) => Err(CapacityError::new((key, value))),
+        }
+    }
+
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    #[inline]
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find_key(key)?;
+        Some(unsafe { self.remove_at(index) })
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, N, S>
+    where
+        K: Hash + Eq,
+    {
+        let tag = self.tag(&key);
+        match self.find(tag, &key) {
+            Ok(index) => Entry::Occupied(OccupiedEntry { map: self, index }),
+            Err(index) => Entry::Vacant(VacantEntry {
+                map: self,
+                index,
+                tag,
+                key,
+            }),
+        }
+    }
+
+    /// Inserts every pair of `iter`, stopping at the first one that does not
+    /// fit and returning it.
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), CapacityError<(K, V)>>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Hash + Eq,
+    {
+        for (key, value) in iter {
+            self.try_insert(key, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// A view into a single entry of a [`StackHashMap`].
+pub enum Entry<'a, K, V, const N: usize, S> {
+    Occupied(OccupiedEntry<'a, K, V, N, S>),
+    Vacant(VacantEntry<'a, K, V, N, S>),
+}
+
+pub struct OccupiedEntry<'a, K, V, const N: usize, S> {
+    map: &'a mut StackHashMap<K, V, N, S>,
+    index: usize,
+}
+
+pub struct VacantEntry<'a, K, V, const N: usize, S> {
+    map: &'a mut StackHashMap<K, V, N, S>,
+    /// Where the entry goes, or `None` if the map is full.
+    index: Option<usize>,
+    tag: u32,
+    key: K,
+}
+
+impl<'a, K, V, const N: usize, S> Entry<'a, K, V, N, S> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the entry is vacant and the map is full.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the entry is vacant and the map is full.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the entry is vacant and the map is full.
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K, V, const N: usize, S> OccupiedEntry<'a, K, V, N, S> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        unsafe { &self.map.entry_at(self.index).0 }
+    }
+
+    #[inline]
+    pub fn get(&self) -> &V {
+        unsafe { &self.map.entry_at(self.index).1 }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V {
+        unsafe { &mut self.map.entry_at_mut(self.index).1 }
+    }
+
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        let Self { map, index } = self;
+        unsafe { &mut map.entry_at_mut(index).1 }
+    }
+
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    #[inline]
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    #[inline]
+    pub fn remove_entry(self) -> (K, V) {
+        unsafe { self.map.remove_at(self.index) }
+    }
+}
+
+impl<'a, K, V, const N: usize, S> VacantEntry<'a, K, V, N, S> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    #[inline]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the map is full.
+    #[inline]
+    pub fn insert(self, value: V) -> &'a mut V {
+        match self.try_insert(value) {
+            Ok(value) => value,
+            Err(_) => capacity_overflow(),
+        }
+    }
+
+    pub fn try_insert(self, value: V) -> Result<&'a mut V, CapacityError<(K, V)>> {
+        let Self {
+            map,
+            index,
+            tag,
+            key,
+        } = self;
+        match index {
+            Some(index) => Ok(&mut unsafe { map.write_at(index, tag, (key, value)) }.1),
+            None => Err(CapacityError::new((key, value))),
+        }
+    }
+}
+
+impl<K, V, const N: usize, S> Drop for StackHashMap<K, V, N, S> {
+    fn drop(&mut self) {
+        if mem::needs_drop::<(K, V)>() {
+            self.clear();
+        }
+    }
+}
+
+impl<K, V, const N: usize, S: Default> Default for StackHashMap<K, V, N, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K: Clone, V: Clone, const N: usize, S: Clone> Clone for StackHashMap<K, V, N, S> {
+    fn clone(&self) -> Self {
+        let mut map = Self::with_hasher(self.hash_builder.clone());
+        for index in 0..N {
+            let tag = self.tags[index];
+            if tag != EMPTY {
+                let (key, value) = unsafe { self.entry_at(index) };
+                // Owned by this frame until written, so a panicking
+                // `value.clone()` still drops the cloned key.
+                let (key, value) = (key.clone(), value.clone());
+                map.slots[index].write((key, value));
+                map.tags[index] = tag;
+                map.len += 1;
+            }
+        }
+        map
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, const N: usize, S> fmt::Debug for StackHashMap<K, V, N, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, const N1: usize, const N2: usize, S1, S2> PartialEq<StackHashMap<K, V, N2, S2>>
+    for StackHashMap<K, V, N1, S1>
+where
+    K: Hash + Eq,
+    V: PartialEq,
+    S2: BuildHasher,
+{
+    fn eq(&self, other: &StackHashMap<K, V, N2, S2>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(k, v)| other.get(k).is_some_and(|other| v == other))
+    }
+}
+
+impl<K: Hash + Eq, V: Eq, const N: usize, S: BuildHasher> Eq for StackHashMap<K, V, N, S> {}
+
+impl<K, V, const N: usize, S, Q> Index<&Q> for StackHashMap<K, V, N, S>
+where
+    K: Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+impl<K: Hash + Eq, V, const N: usize, S: BuildHasher> Extend<(K, V)> for StackHashMap<K, V, N, S> {
+    /// # Panics
+    ///
+    /// Panics if the map fills up; see
+    /// [`try_extend`](StackHashMap::try_extend).
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V, const N: usize, S> FromIterator<(K, V)> for StackHashMap<K, V, N, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    /// # Panics
+    ///
+    /// Panics if there are more than `N` distinct keys.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V, const N: usize, S> IntoIterator for StackHashMap<K, V, N, S> {
+    type Item = (K, V);
+
+    type IntoIter = IntoIter<K, V, N, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            map: self,
+            index: 0,
+        }
+    }
+}
+
+impl<'a, K, V, const N: usize, S> IntoIterator for &'a StackHashMap<K, V, N, S> {
+    type Item = (&'a K, &'a V);
+
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, const N: usize, S> IntoIterator for &'a mut StackHashMap<K, V, N, S> {
+    type Item = (&'a K, &'a mut V);
+
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Iterator over the entries of a [`StackHashMap`], in slot order.
+pub struct Iter<'a, K, V> {
+    tags: slice::Iter<'a, u32>,
+    slots: slice::Iter<'a, MaybeUninit<(K, V)>>,
+    len: usize,
+}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            tags: self.tags.clone(),
+            slots: self.slots.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (tag, slot) = (self.tags.next()?, self.slots.next()?);
+            if *tag != EMPTY {
+                self.len -= 1;
+                let (k, v) = unsafe { slot.assume_init_ref() };
+                return Some((k, v));
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            let (tag, slot) = (self.tags.next_back()?, self.slots.next_back()?);
+            if *tag != EMPTY {
+                self.len -= 1;
+                let (k, v) = unsafe { slot.assume_init_ref() };
+                return Some((k, v));
+            }
+        }
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+/// Iterator over the entries of a [`StackHashMap`], with mutable values.
+pub struct IterMut<'a, K, V> {
+    tags: slice::Iter<'a, u32>,
+    slots: slice::IterMut<'a, MaybeUninit<(K, V)>>,
+    len: usize,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (tag, slot) = (self.tags.next()?, self.slots.next()?);
+            if *tag != EMPTY {
+                self.len -= 1;
+                let (k, v) = unsafe { slot.assume_init_mut() };
+                return Some((&*k, v));
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IterMut<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            let (tag, slot) = (self.tags.next_back()?, self.slots.next_back()?);
+            if *tag != EMPTY {
+                self.len -= 1;
+                let (k, v) = unsafe { slot.assume_init_mut() };
+                return Some((&*k, v));
+            }
+        }
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+impl<K, V> FusedIterator for IterMut<'_, K, V> {}
+
+/// Iterator over the keys of a [`StackHashMap`].
+pub struct Keys<'a, K, V>(Iter<'a, K, V>);
+
+impl<K, V> Clone for Keys<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Iterator over the values of a [`StackHashMap`].
+pub struct Values<'a, K, V>(Iter<'a, K, V>);
+
+impl<K, V> Clone for Values<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Iterator over the values of a [`StackHashMap`], mutably.
+pub struct ValuesMut<'a, K, V>(IterMut<'a, K, V>);
+
+macro_rules! projection {
+    ($name:ident, $item:ty, |$entry:pat_param| $map:expr) => {
+        impl<'a, K, V> Iterator for $name<'a, K, V> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.next().map(|$entry| $map)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.size_hint()
+            }
+        }
+
+        impl<K, V> DoubleEndedIterator for $name<'_, K, V> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.0.next_back().map(|$entry| $map)
+            }
+        }
+
+        impl<K, V> ExactSizeIterator for $name<'_, K, V> {}
+
+        impl<K, V> FusedIterator for $name<'_, K, V> {}
+    };
+}
+
+projection!(Keys, &'a K, |(k, _)| k);
+projection!(Values, &'a V, |(_, v)| v);
+projection!(ValuesMut, &'a mut V, |(_, v)| v);
+
+/// Owning iterator over the entries of a [`StackHashMap`], in slot order.
+pub struct IntoIter<K, V, const N: usize, S> {
+    map: StackHashMap<K, V, N, S>,
+    index: usize,
+}
+
+impl<K, V, const N: usize, S> Iterator for IntoIter<K, V, N, S> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        while self.index < N {
+            let index = self.index;
+            self.index += 1;
+            if self.map.tags[index] != EMPTY {
+                // Probe sequences no longer matter: the map is only dropped.
+                self.map.tags[index] = EMPTY;
+                self.map.len -= 1;
+                return Some(unsafe { self.map.slots[index].assume_init_read() });
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.map.len, Some(self.map.len))
+    }
+}
+
+impl<K, V, const N: usize, S> ExactSizeIterator for IntoIter<K, V, N, S> {}
+
+impl<K, V, const N: usize, S> FusedIterator for IntoIter<K, V, N, S> {}
+
+impl<K: fmt::Debug, V: fmt::Debug, const N: usize, S> fmt::Debug for IntoIter<K, V, N, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.map).finish()
+    }
+}
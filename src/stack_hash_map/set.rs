@@ -0,0 +1,276 @@
+use core::{
+    borrow::Borrow,
+    fmt,
+    hash::{BuildHasher, Hash},
+    iter::FusedIterator,
+};
+
+use super::{DefaultHashBuilder, FxBuildHasher, Keys, StackHashMap};
+use crate::{stack_vec::capacity_overflow, traits::vec::CapacityError};
+
+/// A fixed-capacity hash set storing up to `N` items inline, built on
+/// [`StackHashMap`].
+pub struct StackHashSet<T, const N: usize, S = DefaultHashBuilder> {
+    map: StackHashMap<T, (), N, S>,
+}
+
+impl<T, const N: usize> StackHashSet<T, N> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_hasher(FxBuildHasher)
+    }
+}
+
+impl<T, const N: usize, S> StackHashSet<T, N, S> {
+    #[inline]
+    pub const fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            map: StackHashMap::with_hasher(hash_builder),
+        }
+    }
+
+    #[inline]
+    pub const fn capacity() -> usize {
+        N
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    #[inline]
+    pub fn hasher(&self) -> &S {
+        self.map.hasher()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.map.retain(|item, _| f(item));
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter(self.map.keys())
+    }
+}
+
+impl<T, const N: usize, S: BuildHasher> StackHashSet<T, N, S> {
+    #[inline]
+    pub fn contains<Q>(&self, item: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(item)
+    }
+
+    #[inline]
+    pub fn get<Q>(&self, item: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get_key_value(item).map(|(item, _)| item)
+    }
+
+    /// Adds `item`, returning whether it was not present yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `item` is new and the set is full.
+    #[inline]
+    pub fn insert(&mut self, item: T) -> bool
+    where
+        T: Hash + Eq,
+    {
+        self.try_insert(item)
+            .unwrap_or_else(|_| capacity_overflow())
+    }
+
+    /// Like [`insert`](StackHashSet::insert), but gives `item` back instead
+    /// of panicking when the set is full.
+    #[inline]
+    pub fn try_insert(&mut self, item: T) -> Result<bool, CapacityError<T>>
+    where
+        T: Hash + Eq,
+    {
+        match self.map.try_insert(item, ()) {
+            Ok(replaced) => Ok(replaced.is_none()),
+            Err(err) => Err(CapacityError::new(err.element().0)),
+        }
+    }
+
+    #[inline]
+    pub fn remove<Q>(&mut self, item: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(item).is_some()
+    }
+
+    #[inline]
+    pub fn take<Q>(&mut self, item: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove_entry(item).map(|(item, ())| item)
+    }
+}
+
+impl<T, const N: usize, S: Default> Default for StackHashSet<T, N, S> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            map: StackHashMap::default(),
+        }
+    }
+}
+
+impl<T: Clone, const N: usize, S: Clone> Clone for StackHashSet<T, N, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize, S> fmt::Debug for StackHashSet<T, N, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N1: usize, const N2: usize, S1, S2> PartialEq<StackHashSet<T, N2, S2>>
+    for StackHashSet<T, N1, S1>
+where
+    T: Hash + Eq,
+    S2: BuildHasher,
+{
+    #[inline]
+    fn eq(&self, other: &StackHashSet<T, N2, S2>) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Hash + Eq, const N: usize, S: BuildHasher> Eq for StackHashSet<T, N, S> {}
+
+impl<T: Hash + Eq, const N: usize, S: BuildHasher> Extend<T> for StackHashSet<T, N, S> {
+    /// # Panics
+    ///
+    /// Panics if the set fills up.
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.map.extend(iter.into_iter().map(|item| (item, ())));
+    }
+}
+
+impl<T, const N: usize, S> FromIterator<T> for StackHashSet<T, N, S>
+where
+    T: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    /// # Panics
+    ///
+    /// Panics if there are more than `N` distinct items.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::default();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T, const N: usize, S> IntoIterator for StackHashSet<T, N, S> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T, N, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.map.into_iter())
+    }
+}
+
+impl<'a, T, const N: usize, S> IntoIterator for &'a StackHashSet<T, N, S> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the items of a [`StackHashSet`].
+pub struct Iter<'a, T>(Keys<'a, T, ()>);
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// Owning iterator over the items of a [`StackHashSet`].
+pub struct IntoIter<T, const N: usize, S>(super::IntoIter<T, (), N, S>);
+
+impl<T, const N: usize, S> Iterator for IntoIter<T, N, S> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.0.next().map(|(item, ())| item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T, const N: usize, S> ExactSizeIterator for IntoIter<T, N, S> {}
+
+impl<T, const N: usize, S> FusedIterator for IntoIter<T, N, S> {}
@@ -0,0 +1,180 @@
+use super::{Link, StackList};
+use crate::{stack_vec::capacity_overflow, traits::vec::CapacityError};
+
+/// A cursor over a [`StackList`] that can edit it around its position.
+///
+/// Past either end the cursor sits on a "ghost" position, from which
+/// [`move_next`](CursorMut::move_next) goes to the front and
+/// [`move_prev`](CursorMut::move_prev) to the back.
+pub struct CursorMut<'a, T, const N: usize, L: Link = usize> {
+    list: &'a mut StackList<T, N, L>,
+    current: usize,
+}
+
+impl<'a, T, const N: usize, L: Link> CursorMut<'a, T, N, L> {
+    #[inline]
+    pub(super) fn new(list: &'a mut StackList<T, N, L>, current: usize) -> Self {
+        Self { list, current }
+    }
+
+    /// Key of the current node, or `None` on the ghost position.
+    #[inline]
+    pub fn key(&self) -> Option<usize> {
+        (self.current != StackList::<T, N, L>::NIL).then_some(self.current)
+    }
+
+    #[inline]
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.list.get_mut(self.current)
+    }
+
+    #[inline]
+    pub fn move_next(&mut self) {
+        self.current = self.next();
+    }
+
+    #[inline]
+    pub fn move_prev(&mut self) {
+        self.current = self.prev();
+    }
+
+    #[inline]
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = self.next();
+        self.list.get_mut(next)
+    }
+
+    #[inline]
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let prev = self.prev();
+        self.list.get_mut(prev)
+    }
+
+    /// Inserts `value` after the current node, or at the front from the
+    /// ghost position, returning the key of its node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is full.
+    #[inline]
+    pub fn insert_after(&mut self, value: T) -> usize {
+        self.try_insert_after(value)
+            .unwrap_or_else(|_| capacity_overflow())
+    }
+
+    #[inline]
+    pub fn try_insert_after(&mut self, value: T) -> Result<usize, CapacityError<T>> {
+        let next = self.next();
+        self.list.link(self.current, next, value)
+    }
+
+    /// Inserts `value` before the current node, or at the back from the
+    /// ghost position, returning the key of its node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is full.
+    #[inline]
+    pub fn insert_before(&mut self, value: T) -> usize {
+        self.try_insert_before(value)
+            .unwrap_or_else(|_| capacity_overflow())
+    }
+
+    #[inline]
+    pub fn try_insert_before(&mut self, value: T) -> Result<usize, CapacityError<T>> {
+        let prev = self.prev();
+        self.list.link(prev, self.current, value)
+    }
+
+    /// Removes the current node and moves to the next one.
+    #[inline]
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.list.get(self.current)?;
+        let next = self.next();
+        let value = self.list.unlink(self.current);
+        self.current = next;
+        Some(value)
+    }
+
+    /// Moves the values of `other` in after the current node, or at the
+    /// front from the ghost position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if they do not fit.
+    #[inline]
+    pub fn splice_after<const M: usize, L2: Link>(&mut self, other: StackList<T, M, L2>) {
+        if self.try_splice_after(other).is_err() {
+            capacity_overflow()
+        }
+    }
+
+    /// Splices nothing if the values of `other` do not fit.
+    #[inline]
+    pub fn try_splice_after<const M: usize, L2: Link>(
+        &mut self,
+        other: StackList<T, M, L2>,
+    ) -> Result<(), CapacityError<StackList<T, M, L2>>> {
+        let next = self.next();
+        self.splice(self.current, next, other)
+    }
+
+    /// Moves the values of `other` in before the current node, or at the
+    /// back from the ghost position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if they do not fit.
+    #[inline]
+    pub fn splice_before<const M: usize, L2: Link>(&mut self, other: StackList<T, M, L2>) {
+        if self.try_splice_before(other).is_err() {
+            capacity_overflow()
+        }
+    }
+
+    /// Splices nothing if the values of `other` do not fit.
+    #[inline]
+    pub fn try_splice_before<const M: usize, L2: Link>(
+        &mut self,
+        other: StackList<T, M, L2>,
+    ) -> Result<(), CapacityError<StackList<T, M, L2>>> {
+        let prev = self.prev();
+        self.splice(prev, self.current, other)
+    }
+
+    /// Links the values of `other` in between the adjacent `prev` and
+    /// `next`.
+    fn splice<const M: usize, L2: Link>(
+        &mut self,
+        mut prev: usize,
+        next: usize,
+        other: StackList<T, M, L2>,
+    ) -> Result<(), CapacityError<StackList<T, M, L2>>> {
+        if N - self.list.len() < other.len() {
+            return Err(CapacityError::new(other));
+        }
+        for value in other {
+            prev = self
+                .list
+                .link(prev, next, value)
+                .unwrap_or_else(|_| unreachable!());
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn next(&self) -> usize {
+        match self.list.nodes.get(self.current) {
+            Some(node) => node.next(),
+            None => self.list.head,
+        }
+    }
+
+    #[inline]
+    fn prev(&self) -> usize {
+        match self.list.nodes.get(self.current) {
+            Some(node) => node.prev(),
+            None => self.list.tail,
+        }
+    }
+}
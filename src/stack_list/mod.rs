@@ -0,0 +1,521 @@
+mod cursor;
+
+pub use cursor::CursorMut;
+
+use core::{fmt, iter::FusedIterator, marker::PhantomData};
+
+use crate::{stack_vec::capacity_overflow, traits::vec::CapacityError, StackVec};
+
+/// An unsigned integer type used for the links of a [`StackList`].
+pub trait Link: Copy + Eq {
+    /// Largest value, standing for "no node".
+    const MAX: usize;
+
+    fn from_index(index: usize) -> Self;
+
+    fn index(self) -> usize;
+}
+
+macro_rules! impl_link {
+    ($($ty:ty),*) => {$(
+        impl Link for $ty {
+            const MAX: usize = <$ty>::MAX as usize;
+
+            #[inline]
+            fn from_index(index: usize) -> Self {
+                index as $ty
+            }
+
+            #[inline]
+            fn index(self) -> usize {
+                self as usize
+            }
+        }
+    )*};
+}
+
+impl_link!(u8, u16, usize);
+
+#[derive(Clone)]
+struct Node<T, L> {
+    /// `None` once the node is on the free list.
+    value: Option<T>,
+    prev: L,
+    /// Next free node while on the free list.
+    next: L,
+}
+
+impl<T, L: Link> Node<T, L> {
+    #[inline]
+    fn prev(&self) -> usize {
+        self.prev.index()
+    }
+
+    #[inline]
+    fn next(&self) -> usize {
+        self.next.index()
+    }
+}
+
+/// A doubly linked list of up to `N` nodes stored inline, linked by index.
+///
+/// Links are `L` values, so `u16` or `u8` links shrink the nodes of short
+/// lists. Removed nodes go to a free list and are reused by later
+/// insertions. Each value keeps the key of its node until it is removed,
+/// giving O(1) access and removal anywhere in the list.
+pub struct StackList<T, const N: usize, L: Link = usize> {
+    nodes: StackVec<Node<T, L>, N>,
+    head: usize,
+    tail: usize,
+    free: usize,
+    len: usize,
+    _marker: PhantomData<L>,
+}
+
+impl<T, const N: usize, L: Link> StackList<T, N, L> {
+    const NIL: usize = {
+        assert!(N <= L::MAX, "`N` nodes do not fit in the link type");
+        L::MAX
+    };
+
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            nodes: StackVec::new(),
+            head: Self::NIL,
+            tail: Self::NIL,
+            free: Self::NIL,
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Value of the node at `key`.
+    #[inline]
+    pub fn get(&self, key: usize) -> Option<&T> {
+        self.nodes.get(key)?.value.as_ref()
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+        self.nodes.get_mut(key)?.value.as_mut()
+    }
+
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.get(self.head)
+    }
+
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.head)
+    }
+
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.tail)
+    }
+
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.tail)
+    }
+
+    /// Returns the key of the new node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is full.
+    #[inline]
+    pub fn push_front(&mut self, value: T) -> usize {
+        self.try_push_front(value)
+            .unwrap_or_else(|_| capacity_overflow())
+    }
+
+    #[inline]
+    pub fn try_push_front(&mut self, value: T) -> Result<usize, CapacityError<T>> {
+        self.link(Self::NIL, self.head, value)
+    }
+
+    /// Returns the key of the new node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is full.
+    #[inline]
+    pub fn push_back(&mut self, value: T) -> usize {
+        self.try_push_back(value)
+            .unwrap_or_else(|_| capacity_overflow())
+    }
+
+    #[inline]
+    pub fn try_push_back(&mut self, value: T) -> Result<usize, CapacityError<T>> {
+        self.link(self.tail, Self::NIL, value)
+    }
+
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.remove(self.head)
+    }
+
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.remove(self.tail)
+    }
+
+    /// Removes the node at `key` in O(1), freeing it for reuse.
+    #[inline]
+    pub fn remove(&mut self, key: usize) -> Option<T> {
+        self.get(key)?;
+        Some(self.unlink(key))
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.head = Self::NIL;
+        self.tail = Self::NIL;
+        self.free = Self::NIL;
+        self.len = 0;
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T, L> {
+        Iter {
+            nodes: &self.nodes,
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+        }
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, L> {
+        IterMut {
+            nodes: self.nodes.as_mut_ptr(),
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// A cursor on the first node, or on the "ghost" position of an empty
+    /// list.
+    #[inline]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, N, L> {
+        let current = self.head;
+        CursorMut::new(self, current)
+    }
+
+    #[inline]
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T, N, L> {
+        let current = self.tail;
+        CursorMut::new(self, current)
+    }
+
+    /// A cursor on the node at `key`.
+    #[inline]
+    pub fn cursor_mut(&mut self, key: usize) -> Option<CursorMut<'_, T, N, L>> {
+        self.get(key)?;
+        Some(CursorMut::new(self, key))
+    }
+
+    /// Links a new node between the adjacent `prev` and `next`.
+    fn link(&mut self, prev: usize, next: usize, value: T) -> Result<usize, CapacityError<T>> {
+        let key = if self.free != Self::NIL {
+            let key = self.free;
+            let node = &mut self.nodes[key];
+            self.free = node.next();
+            *node = Node {
+                value: Some(value),
+                prev: L::from_index(prev),
+                next: L::from_index(next),
+            };
+            key
+        } else {
+            self.nodes
+                .try_push(Node {
+                    value: Some(value),
+                    prev: L::from_index(prev),
+                    next: L::from_index(next),
+                })
+                .map_err(|error| CapacityError::new(error.element().value.unwrap()))?;
+            self.nodes.len() - 1
+        };
+        if prev == Self::NIL {
+            self.head = key;
+        } else {
+            self.nodes[prev].next = L::from_index(key);
+        }
+        if next == Self::NIL {
+            self.tail = key;
+        } else {
+            self.nodes[next].prev = L::from_index(key);
+        }
+        self.len += 1;
+        Ok(key)
+    }
+
+    /// Unlinks the occupied node at `key` and puts it on the free list.
+    fn unlink(&mut self, key: usize) -> T {
+        let node = &mut self.nodes[key];
+        let (prev, next) = (node.prev(), node.next());
+        let value = node.value.take().unwrap();
+        node.next = L::from_index(self.free);
+        self.free = key;
+        if prev == Self::NIL {
+            self.head = next;
+        } else {
+            self.nodes[prev].next = L::from_index(next);
+        }
+        if next == Self::NIL {
+            self.tail = prev;
+        } else {
+            self.nodes[next].prev = L::from_index(prev);
+        }
+        self.len -= 1;
+        value
+    }
+}
+
+impl<T, const N: usize, L: Link> Default for StackList<T, N, L> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize, L: Link> Clone for StackList<T, N, L> {
+    /// Keeps the key of every node.
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes.clone(),
+            head: self.head,
+            tail: self.tail,
+            free: self.free,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize, L: Link> fmt::Debug for StackList<T, N, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, U, const N1: usize, const N2: usize, L1, L2> PartialEq<StackList<U, N2, L2>>
+    for StackList<T, N1, L1>
+where
+    T: PartialEq<U>,
+    L1: Link,
+    L2: Link,
+{
+    #[inline]
+    fn eq(&self, other: &StackList<U, N2, L2>) -> bool {
+        self.len == other.len && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: Eq, const N: usize, L: Link> Eq for StackList<T, N, L> {}
+
+impl<T, const N: usize, L: Link> Extend<T> for StackList<T, N, L> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| {
+            self.push_back(value);
+        });
+    }
+}
+
+impl<T, const N: usize, L: Link> FromIterator<T> for StackList<T, N, L> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T, const N: usize, L: Link> IntoIterator for StackList<T, N, L> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T, N, L>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T, const N: usize, L: Link> IntoIterator for &'a StackList<T, N, L> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T, L>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize, L: Link> IntoIterator for &'a mut StackList<T, N, L> {
+    type Item = &'a mut T;
+
+    type IntoIter = IterMut<'a, T, L>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Iterator over the values of a [`StackList`], front to back.
+pub struct Iter<'a, T, L: Link = usize> {
+    nodes: &'a [Node<T, L>],
+    front: usize,
+    back: usize,
+    len: usize,
+}
+
+impl<T, L: Link> Clone for Iter<'_, T, L> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, T, L: Link> Iterator for Iter<'a, T, L> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let node = &self.nodes[self.front];
+        self.front = node.next();
+        node.value.as_ref()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T, L: Link> DoubleEndedIterator for Iter<'_, T, L> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let node = &self.nodes[self.back];
+        self.back = node.prev();
+        node.value.as_ref()
+    }
+}
+
+impl<T, L: Link> ExactSizeIterator for Iter<'_, T, L> {}
+
+impl<T, L: Link> FusedIterator for Iter<'_, T, L> {}
+
+/// Mutable iterator over the values of a [`StackList`], front to back.
+pub struct IterMut<'a, T, L: Link = usize> {
+    nodes: *mut Node<T, L>,
+    front: usize,
+    back: usize,
+    len: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T, L: Link> Iterator for IterMut<'a, T, L> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // `len` stops the walk before any node is reached twice.
+        let node = unsafe { &mut *self.nodes.add(self.front) };
+        self.front = node.next();
+        node.value.as_mut()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T, L: Link> DoubleEndedIterator for IterMut<'_, T, L> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let node = unsafe { &mut *self.nodes.add(self.back) };
+        self.back = node.prev();
+        node.value.as_mut()
+    }
+}
+
+impl<T, L: Link> ExactSizeIterator for IterMut<'_, T, L> {}
+
+impl<T, L: Link> FusedIterator for IterMut<'_, T, L> {}
+
+// Behaves like the `&'a mut [Node<T, L>]` it was made from.
+unsafe impl<T: Send, L: Link + Send> Send for IterMut<'_, T, L> {}
+
+unsafe impl<T: Sync, L: Link + Sync> Sync for IterMut<'_, T, L> {}
+
+/// Owning iterator over the values of a [`StackList`], front to back.
+pub struct IntoIter<T, const N: usize, L: Link = usize>(StackList<T, N, L>);
+
+impl<T, const N: usize, L: Link> Iterator for IntoIter<T, N, L> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.0.pop_front()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T, const N: usize, L: Link> DoubleEndedIterator for IntoIter<T, N, L> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.0.pop_back()
+    }
+}
+
+impl<T, const N: usize, L: Link> ExactSizeIterator for IntoIter<T, N, L> {}
+
+impl<T, const N: usize, L: Link> FusedIterator for IntoIter<T, N, L> {}
@@ -1,17 +1,26 @@
-use core::{borrow::*, cmp::Ordering, fmt, hash::*, mem::MaybeUninit, ops::*, slice::SliceIndex};
+use core::{
+    borrow::*,
+    cmp::Ordering,
+    fmt,
+    hash::*,
+    mem::{ManuallyDrop, MaybeUninit},
+    num::NonZeroUsize,
+    ops::*,
+    ptr,
+    slice::SliceIndex,
+};
+
+use crate::traits::{vec::CapacityError, *};
 
-use crate::traits::*;
-
-#[derive(Clone, Debug)]
 pub struct StackVec<T, const N: usize> {
-    buff: [T; N],
+    buff: [MaybeUninit<T>; N],
     len: usize,
 }
 
 impl<T, const N: usize> StackVec<T, N> {
     const IS_ZST: bool = core::mem::size_of::<T>() == 0;
-    const UNINIT_ITEM: T = unsafe { core::mem::MaybeUninit::uninit().assume_init() };
-    const UNINIT_ARRAY: [T; N] = [Self::UNINIT_ITEM; N];
+    const UNINIT_ITEM: MaybeUninit<T> = MaybeUninit::uninit();
+    const UNINIT_ARRAY: [MaybeUninit<T>; N] = [Self::UNINIT_ITEM; N];
 
     #[inline]
     pub const fn new() -> Self {
@@ -21,20 +30,61 @@ impl<T, const N: usize> StackVec<T, N> {
         }
     }
 
+    /// Bitwise moves the items of `slice` into a new `StackVec`.
+    ///
+    /// The caller must make sure the source does not drop them afterwards.
+    #[cfg(feature = "std")]
     unsafe fn from_slice_copy_unchecked(slice: &[T]) -> Self {
         let len = slice.len();
         if Self::capacity() < len {
-            panic!("Capacity overflow")
+            capacity_overflow()
         } else {
             let mut buff = Self::UNINIT_ARRAY;
             unsafe {
                 buff.as_mut_ptr()
-                    .copy_from_nonoverlapping(slice.as_ptr(), len)
+                    .copy_from_nonoverlapping(slice.as_ptr() as *const MaybeUninit<T>, len)
             };
             Self { buff, len }
         }
     }
 
+    fn from_slice_clone(slice: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        if Self::capacity() < slice.len() {
+            capacity_overflow()
+        }
+        let mut this = Self::new();
+        unsafe { this.extend_from_slice_unchecked(slice) };
+        this
+    }
+
+    /// # Safety
+    ///
+    /// The first `len` items of `buff` must be initialized.
+    #[inline]
+    pub(crate) const unsafe fn from_raw_parts(buff: [MaybeUninit<T>; N], len: usize) -> Self {
+        Self { buff, len }
+    }
+
+    /// Hands out the backing array, whose first `len` items are initialized.
+    #[inline]
+    pub(crate) fn into_raw_parts(self) -> ([MaybeUninit<T>; N], usize) {
+        let this = ManuallyDrop::new(self);
+        (unsafe { ptr::read(&this.buff) }, this.len)
+    }
+
+    /// Pointer to the first item of `*this`, without borrowing it.
+    ///
+    /// # Safety
+    ///
+    /// `this` must point to a live `StackVec`.
+    #[inline]
+    pub(crate) unsafe fn raw_ptr(this: *mut Self) -> *mut T {
+        ptr::addr_of_mut!((*this).buff).cast()
+    }
+
     #[inline]
     pub const fn capacity() -> usize {
         if Self::IS_ZST {
@@ -44,11 +94,17 @@ impl<T, const N: usize> StackVec<T, N> {
         }
     }
 
+    /// # Safety
+    ///
+    /// See [`Vec::set_len`].
     #[inline]
     pub unsafe fn set_len(&mut self, new_len: usize) {
         self.len = new_len;
     }
 
+    /// # Safety
+    ///
+    /// See [`Vec::split_off_unchecked`].
     #[inline]
     pub unsafe fn split_off_unchecked(&mut self, at: usize) -> Self {
         Vec::split_off_unchecked(self, at)
@@ -59,6 +115,9 @@ impl<T, const N: usize> StackVec<T, N> {
         Vec::split_off(self, at)
     }
 
+    /// # Safety
+    ///
+    /// See [`Vec::push_unchecked`].
     #[inline]
     pub unsafe fn push_unchecked(&mut self, item: T) {
         Vec::push_unchecked(self, item);
@@ -69,6 +128,14 @@ impl<T, const N: usize> StackVec<T, N> {
         Vec::push(self, item)
     }
 
+    #[inline]
+    pub fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        Vec::try_push(self, item)
+    }
+
+    /// # Safety
+    ///
+    /// See [`Vec::append_unchecked`].
     #[inline]
     pub unsafe fn append_unchecked<S: SliceOwner<Item = T>>(&mut self, other: S) {
         Vec::append_unchecked(self, other);
@@ -79,6 +146,9 @@ impl<T, const N: usize> StackVec<T, N> {
         Vec::append(self, other)
     }
 
+    /// # Safety
+    ///
+    /// See [`Vec::pop_unchecked`].
     #[inline]
     pub unsafe fn pop_unchecked(&mut self) -> T {
         Vec::pop_unchecked(self)
@@ -94,11 +164,17 @@ impl<T, const N: usize> StackVec<T, N> {
         Vec::swap_remove(self, index)
     }
 
+    /// # Safety
+    ///
+    /// See [`Vec::swap_remove_unchecked`].
     #[inline]
     pub unsafe fn swap_remove_unchecked(&mut self, index: usize) -> T {
         Vec::swap_remove_unchecked(self, index)
     }
 
+    /// # Safety
+    ///
+    /// See [`Vec::remove_unchecked`].
     #[inline]
     pub unsafe fn remove_unchecked(&mut self, index: usize) -> T {
         Vec::remove_unchecked(self, index)
@@ -119,11 +195,17 @@ impl<T, const N: usize> StackVec<T, N> {
         Vec::truncate(self, len);
     }
 
+    /// # Safety
+    ///
+    /// See [`Vec::truncate_unchecked`].
     #[inline]
     pub unsafe fn truncate_unchecked(&mut self, len: usize) {
         Vec::truncate_unchecked(self, len);
     }
 
+    /// # Safety
+    ///
+    /// See [`Vec::insert_unchecked`].
     #[inline]
     pub unsafe fn insert_unchecked(&mut self, index: usize, element: T) {
         Vec::insert_unchecked(self, index, element);
@@ -135,13 +217,72 @@ impl<T, const N: usize> StackVec<T, N> {
     }
 
     #[inline]
-    pub fn drain<R>(&mut self, range: R) -> vec::Drain<Self>
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        Vec::try_insert(self, index, element)
+    }
+
+    #[inline]
+    pub fn insert_slice(&mut self, index: usize, slice: &[T])
+    where
+        T: Clone,
+    {
+        Vec::insert_slice(self, index, slice);
+    }
+
+    #[inline]
+    pub fn try_insert_slice(&mut self, index: usize, slice: &[T]) -> Result<(), CapacityError>
+    where
+        T: Clone,
+    {
+        Vec::try_insert_slice(self, index, slice)
+    }
+
+    #[inline]
+    pub fn insert_from_iter<I>(&mut self, index: usize, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Vec::insert_from_iter(self, index, iter);
+    }
+
+    #[inline]
+    pub fn try_insert_from_iter<I>(&mut self, index: usize, iter: I) -> Result<(), CapacityError<T>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Vec::try_insert_from_iter(self, index, iter)
+    }
+
+    #[inline]
+    pub fn remove_range<R>(&mut self, range: R)
+    where
+        R: RangeBounds<usize>,
+    {
+        Vec::remove_range(self, range);
+    }
+
+    #[inline]
+    pub fn remove_indices(&mut self, indices: &[usize]) {
+        Vec::remove_indices(self, indices);
+    }
+
+    #[inline]
+    pub fn drain<R>(&mut self, range: R) -> vec::Drain<'_, Self>
     where
         R: RangeBounds<usize>,
     {
         Vec::drain(self, range)
     }
 
+    #[inline]
+    pub fn drain_into<R, V>(&mut self, range: R, other: &mut V)
+    where
+        R: RangeBounds<usize>,
+        V: Vec<Item = T> + ?Sized,
+    {
+        Vec::drain_into(self, range, other)
+    }
+
     #[inline]
     pub fn retain<F>(&mut self, f: F)
     where
@@ -176,6 +317,55 @@ impl<T, const N: usize> StackVec<T, N> {
         Vec::dedup_by(self, same_bucket);
     }
 
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        Vec::dedup(self);
+    }
+
+    #[inline]
+    pub fn dedup_count(&mut self) -> usize
+    where
+        T: PartialEq,
+    {
+        Vec::dedup_count(self)
+    }
+
+    #[inline]
+    pub fn dedup_by_returning<F, S>(&mut self, same_bucket: F, sink: &mut S) -> usize
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+        S: Vec<Item = T> + ?Sized,
+    {
+        Vec::dedup_by_returning(self, same_bucket, sink)
+    }
+
+    #[inline]
+    pub fn partition_in_place<F>(&mut self, pred: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        Vec::partition_in_place(self, pred)
+    }
+
+    #[inline]
+    pub fn partition_dedup(&mut self) -> (&mut [T], &mut [T])
+    where
+        T: PartialEq,
+    {
+        Vec::partition_dedup(self)
+    }
+
+    #[inline]
+    pub fn partition_dedup_by<F>(&mut self, same_bucket: F) -> (&mut [T], &mut [T])
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        Vec::partition_dedup_by(self, same_bucket)
+    }
+
     #[inline]
     pub fn resize(&mut self, new_len: usize, value: T)
     where
@@ -202,6 +392,9 @@ impl<T, const N: usize> StackVec<T, N> {
         Vec::spare_capacity_mut(self)
     }
 
+    /// # Safety
+    ///
+    /// See [`Vec::extend_from_slice_unchecked`].
     #[inline]
     pub unsafe fn extend_from_slice_unchecked(&mut self, slice: &[T])
     where
@@ -226,6 +419,114 @@ impl<T, const N: usize> StackVec<T, N> {
     {
         Vec::extend_from_within(self, src);
     }
+
+    #[inline]
+    pub fn sort_stable_in_place(&mut self)
+    where
+        T: Ord,
+    {
+        Vec::sort_stable_in_place(self);
+    }
+
+    #[inline]
+    pub fn sort_stable_in_place_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        Vec::sort_stable_in_place_by(self, compare);
+    }
+
+    #[inline]
+    pub fn sort_stable_in_place_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        Vec::sort_stable_in_place_by_key(self, f);
+    }
+
+    #[inline]
+    pub fn radix_sort(&mut self)
+    where
+        T: RadixKey,
+    {
+        Vec::radix_sort(self);
+    }
+
+    #[inline]
+    pub fn radix_sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: RadixKey,
+        F: FnMut(&T) -> K,
+    {
+        Vec::radix_sort_by_key(self, f);
+    }
+
+    #[inline]
+    pub fn radix_sort_with<S>(&mut self, scratch: &mut S)
+    where
+        T: RadixKey,
+        S: Vec<Item = T> + ?Sized,
+    {
+        Vec::radix_sort_with(self, scratch);
+    }
+
+    #[inline]
+    pub fn radix_sort_by_key_with<K, F, S>(&mut self, f: F, scratch: &mut S)
+    where
+        K: RadixKey,
+        F: FnMut(&T) -> K,
+        S: Vec<Item = T> + ?Sized,
+    {
+        Vec::radix_sort_by_key_with(self, f, scratch);
+    }
+
+    /// Sorts by `f`, calling it once per item. The keys are kept in a
+    /// `StackVec` of the same capacity, so nothing is allocated.
+    pub fn sort_by_cached_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        let len = self.len;
+        // Zero-sized items are all alike, and may outnumber the key slots.
+        if len < 2 || Self::IS_ZST {
+            return;
+        }
+        let mut keys = StackVec::<(K, usize), N>::new();
+        for (index, item) in self.iter().enumerate() {
+            unsafe { keys.push_unchecked((f(item), index)) };
+        }
+        // Indices are unique, so an unstable sort keeps equal keys in order.
+        keys.sort_unstable();
+        for i in 0..len {
+            let mut index = keys[i].1;
+            while index < i {
+                index = keys[index].1;
+            }
+            keys[i].1 = index;
+            self.swap(i, index);
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for StackVec<T, N> {
+    fn clone(&self) -> Self {
+        Self::from_slice_clone(self.as_slice())
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for StackVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T, const N: usize> Drop for StackVec<T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { core::ptr::drop_in_place(self.as_mut_slice()) }
+    }
 }
 
 impl<T, const N: usize> AsMut<StackVec<T, N>> for StackVec<T, N> {
@@ -316,8 +617,9 @@ impl<T, const N: usize> Extend<T> for StackVec<T, N> {
 impl<T, const N: usize> From<[T; N]> for StackVec<T, N> {
     #[inline]
     fn from(value: [T; N]) -> Self {
+        let value = ManuallyDrop::new(value);
         Self {
-            buff: value,
+            buff: unsafe { (&*value as *const [T; N] as *const [MaybeUninit<T>; N]).read() },
             len: N,
         }
     }
@@ -326,16 +628,13 @@ impl<T, const N: usize> From<[T; N]> for StackVec<T, N> {
 impl<T: Clone, const N: usize> From<&[T; N]> for StackVec<T, N> {
     #[inline]
     fn from(value: &[T; N]) -> Self {
-        Self {
-            buff: value.clone(),
-            len: N,
-        }
+        Self::from_slice_clone(value)
     }
 }
 
 impl<T: Clone, const N: usize> From<&[T]> for StackVec<T, N> {
     fn from(value: &[T]) -> Self {
-        unsafe { Self::from_slice_copy_unchecked(value) }
+        Self::from_slice_clone(value)
     }
 }
 
@@ -349,42 +648,37 @@ impl<T: Clone, const N: usize> From<&mut [T; N]> for StackVec<T, N> {
 impl<T: Clone, const N: usize> From<&mut [T]> for StackVec<T, N> {
     #[inline]
     fn from(value: &mut [T]) -> Self {
-        unsafe { Self::from_slice_copy_unchecked(value) }
+        Self::from_slice_clone(value)
     }
 }
 
 impl<const N: usize> From<&str> for StackVec<u8, N> {
     #[inline]
     fn from(s: &str) -> StackVec<u8, N> {
-        unsafe { Self::from_slice_copy_unchecked(s.as_bytes()) }
+        Self::from_slice_clone(s.as_bytes())
     }
 }
 
 #[cfg(feature = "std")]
 impl<T, const N: usize> From<Box<[T; N]>> for StackVec<T, N> {
     fn from(value: Box<[T; N]>) -> Self {
-        let mut buff = Self::UNINIT_ARRAY;
-        unsafe { buff.as_mut_ptr()
-            .copy_from_nonoverlapping(value.as_ptr(), N) };
-        core::mem::forget(value);
-        Self { buff, len: N }
+        Self::from(*value)
     }
 }
 
 #[cfg(feature = "std")]
 impl<T, const N: usize> From<Box<[T]>> for StackVec<T, N> {
     fn from(value: Box<[T]>) -> Self {
-        let this = unsafe { Self::from_slice_copy_unchecked(&*value) };
-        core::mem::forget(value);
-        this
+        Self::from(value.into_vec())
     }
 }
 
 #[cfg(feature = "std")]
 impl<T, const N: usize> From<std::vec::Vec<T>> for StackVec<T, N> {
-    fn from(value: std::vec::Vec<T>) -> Self {
+    fn from(mut value: std::vec::Vec<T>) -> Self {
         let this = unsafe { Self::from_slice_copy_unchecked(&value) };
-        core::mem::forget(value);
+        // The items now belong to `this`; only the allocation is released.
+        unsafe { value.set_len(0) };
         this
     }
 }
@@ -441,10 +735,11 @@ impl<T, const N: usize> IntoIterator for StackVec<T, N> {
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
+        let this = ManuallyDrop::new(self);
         IntoIter {
-            array: self.buff,
+            array: unsafe { core::ptr::read(&this.buff) },
             start: 0,
-            end: self.len,
+            end: this.len,
         }
     }
 }
@@ -605,12 +900,12 @@ unsafe impl<T, const N: usize> SliceOwner for StackVec<T, N> {
 
     #[inline]
     fn as_ptr(&self) -> *const T {
-        self.buff.as_ptr()
+        self.buff.as_ptr() as *const T
     }
 
     #[inline]
     fn as_mut_ptr(&mut self) -> *mut T {
-        self.buff.as_mut_ptr()
+        self.buff.as_mut_ptr() as *mut T
     }
 }
 
@@ -626,8 +921,10 @@ unsafe impl<T, const N: usize> Vec for StackVec<T, N> {
     }
 
     #[inline]
-    fn reserve(&mut self, _: usize) {
-        panic!("Can not reserve in a StackVec!")
+    fn reserve(&mut self, additional: usize) {
+        if additional > Self::capacity() - self.len {
+            capacity_overflow()
+        }
     }
 
     unsafe fn split_off_unchecked(&mut self, at: usize) -> Self {
@@ -647,8 +944,12 @@ unsafe impl<T, const N: usize> Vec for StackVec<T, N> {
     }
 }
 
+/// Owning iterator over the items of a [`StackVec`].
+///
+/// Only the `start..end` window of `array` is initialized; everything outside
+/// of it has either been yielded or was never written.
 pub struct IntoIter<T, const N: usize> {
-    array: [T; N],
+    array: [MaybeUninit<T>; N],
     start: usize,
     end: usize,
 }
@@ -659,16 +960,66 @@ impl<T, const N: usize> IntoIter<T, N> {
         vec.into_iter()
     }
 
+    #[inline(always)]
+    fn ptr(&self) -> *const T {
+        self.array.as_ptr() as *const T
+    }
+
+    #[inline(always)]
+    fn mut_ptr(&mut self) -> *mut T {
+        self.array.as_mut_ptr() as *mut T
+    }
+
+    /// The items not yielded yet.
     #[inline(always)]
     pub fn as_slice(&self) -> &[T] {
-        unsafe { core::slice::from_raw_parts(self.array.as_ptr().add(self.start), self.len()) }
+        unsafe { core::slice::from_raw_parts(self.ptr().add(self.start), self.len()) }
     }
 
+    /// The items not yielded yet. Changes made through this slice are seen
+    /// by later calls to `next`, `next_back` and [`into_stack_vec`].
+    ///
+    /// [`into_stack_vec`]: IntoIter::into_stack_vec
     #[inline(always)]
     pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let start = self.start;
+        let len = self.len();
+        unsafe { core::slice::from_raw_parts_mut(self.mut_ptr().add(start), len) }
+    }
+
+    /// Stops iterating and returns the items not yielded yet, in order.
+    pub fn into_stack_vec(self) -> StackVec<T, N> {
+        let this = ManuallyDrop::new(self);
+        let len = this.len();
+        let mut vec = StackVec::<T, N>::new();
         unsafe {
-            core::slice::from_raw_parts_mut(self.array.as_mut_ptr().add(self.start), self.len())
+            vec.as_mut_ptr()
+                .copy_from_nonoverlapping(this.ptr().add(this.start), len);
+            vec.set_len(len);
         }
+        vec
+    }
+
+    /// Drops the next `n` items from the front. Returns how many were missing
+    /// if fewer than `n` remained.
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.len());
+        let start = self.start;
+        let to_drop = ptr::slice_from_raw_parts_mut(unsafe { self.mut_ptr().add(start) }, step);
+        self.start += step;
+        unsafe { ptr::drop_in_place(to_drop) };
+        NonZeroUsize::new(n - step).map_or(Ok(()), Err)
+    }
+
+    /// Drops the next `n` items from the back. Returns how many were missing
+    /// if fewer than `n` remained.
+    pub fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.len());
+        self.end -= step;
+        let end = self.end;
+        let to_drop = ptr::slice_from_raw_parts_mut(unsafe { self.mut_ptr().add(end) }, step);
+        unsafe { ptr::drop_in_place(to_drop) };
+        NonZeroUsize::new(n - step).map_or(Ok(()), Err)
     }
 }
 
@@ -690,7 +1041,7 @@ impl<T, const N: usize> Iterator for IntoIter<T, N> {
         if self.end > self.start {
             let curr = self.start;
             self.start = unsafe { curr.unchecked_add(1) };
-            Some(unsafe { self.array.as_ptr().add(curr).read() })
+            Some(unsafe { self.ptr().add(curr).read() })
         } else {
             None
         }
@@ -711,18 +1062,29 @@ impl<T, const N: usize> Iterator for IntoIter<T, N> {
     fn last(mut self) -> Option<T> {
         self.next_back()
     }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.advance_by(n).ok()?;
+        self.next()
+    }
 }
 
 impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
     fn next_back(&mut self) -> Option<T> {
         if self.end > self.start {
-            let curr = self.end;
-            self.end = unsafe { curr.unchecked_sub(1) };
-            Some(unsafe { self.array.as_ptr().add(curr).read() })
+            self.end = unsafe { self.end.unchecked_sub(1) };
+            Some(unsafe { self.ptr().add(self.end).read() })
         } else {
             None
         }
     }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        self.advance_back_by(n).ok()?;
+        self.next_back()
+    }
 }
 
 impl<T, const N: usize> Drop for IntoIter<T, N> {
@@ -743,18 +1105,12 @@ impl<T, const N: usize> core::iter::FusedIterator for IntoIter<T, N> {}
 
 impl<T: Clone, const N: usize> Clone for IntoIter<T, N> {
     fn clone(&self) -> Self {
-        let mut new = StackVec::<T, N>::UNINIT_ARRAY;
-        let ptr = new.as_mut_ptr();
-        for index in self.start..self.end {
-            unsafe {
-                ptr.add(index)
-                    .write(self.array.get_unchecked(index).clone())
-            };
-        }
-        Self {
-            array: new,
-            start: self.start,
-            end: self.end,
-        }
+        StackVec::from_slice_clone(self.as_slice()).into_iter()
     }
 }
+
+#[cold]
+#[track_caller]
+pub(crate) fn capacity_overflow() -> ! {
+    panic!("Capacity overflow")
+}
@@ -0,0 +1,829 @@
+//! Conformance suite for [`Vec`] implementors.
+//!
+//! Every check builds fresh containers through the `new` closure it is given,
+//! mirrors each operation on a [`std::vec::Vec`] reference model and verifies
+//! that contents and live-item counts agree, including after a panic has been
+//! injected into a clone or a user callback. Containers must be able to hold
+//! at least [`MIN_CAPACITY`] items.
+//!
+//! ```
+//! use no_std_collections::{testing, StackVec};
+//!
+//! testing::run(StackVec::<_, 32>::new, StackVec::<_, 32>::new);
+//! ```
+
+use std::{
+    cell::Cell,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+};
+
+use crate::traits::{vec::Vec, SliceOwner};
+
+/// Number of items every container under test must be able to hold.
+pub const MIN_CAPACITY: usize = 32;
+
+/// Counts live [`Tracked`] values and injects panics into their `Clone`.
+#[derive(Clone, Default)]
+pub struct Tracker(Rc<State>);
+
+#[derive(Default)]
+struct State {
+    live: Cell<isize>,
+    clones_left: Cell<Option<usize>>,
+}
+
+impl Tracker {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn track(&self, value: u32) -> Tracked {
+        self.0.live.set(self.0.live.get() + 1);
+        Tracked {
+            value,
+            state: self.0.clone(),
+        }
+    }
+
+    /// Number of values created by this tracker (or cloned from them) that
+    /// have not been dropped yet. Negative means something was dropped twice.
+    #[inline]
+    pub fn live(&self) -> isize {
+        self.0.live.get()
+    }
+
+    /// Lets `n` more clones succeed and makes the next one panic.
+    #[inline]
+    pub fn panic_after_clones(&self, n: usize) {
+        self.0.clones_left.set(Some(n));
+    }
+
+    #[inline]
+    pub fn disarm(&self) {
+        self.0.clones_left.set(None);
+    }
+}
+
+/// Drop-counted element type handed out by a [`Tracker`].
+pub struct Tracked {
+    value: u32,
+    state: Rc<State>,
+}
+
+impl Tracked {
+    #[inline]
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+impl Clone for Tracked {
+    fn clone(&self) -> Self {
+        match self.state.clones_left.get() {
+            Some(0) => {
+                self.state.clones_left.set(None);
+                panic!("injected panic while cloning {}", self.value);
+            }
+            Some(n) => self.state.clones_left.set(Some(n - 1)),
+            None => {}
+        }
+        self.state.live.set(self.state.live.get() + 1);
+        Self {
+            value: self.value,
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl Drop for Tracked {
+    #[inline]
+    fn drop(&mut self) {
+        self.state.live.set(self.state.live.get() - 1);
+    }
+}
+
+impl PartialEq for Tracked {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl core::fmt::Debug for Tracked {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+std::thread_local! {
+    static ZST_LIVE: Cell<isize> = const { Cell::new(0) };
+}
+
+/// Drop-counted zero-sized element type. Counts are kept per thread.
+#[derive(Debug, PartialEq)]
+pub struct Zst(());
+
+impl Zst {
+    #[inline]
+    pub fn new() -> Self {
+        ZST_LIVE.with(|live| live.set(live.get() + 1));
+        Zst(())
+    }
+
+    /// Number of `Zst`s alive on the current thread.
+    #[inline]
+    pub fn live() -> isize {
+        ZST_LIVE.with(Cell::get)
+    }
+}
+
+impl Default for Zst {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for Zst {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Zst {
+    #[inline]
+    fn drop(&mut self) {
+        ZST_LIVE.with(|live| live.set(live.get() - 1));
+    }
+}
+
+/// Small deterministic generator for model tests.
+#[derive(Clone)]
+pub struct XorShift(u64);
+
+impl XorShift {
+    /// Sets the lowest bit of `seed`, as xorshift would stay at zero, so
+    /// seeds `2k` and `2k + 1` give the same sequence.
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        Self(seed | 1)
+    }
+
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A value in `0..bound`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero.
+    #[inline]
+    #[track_caller]
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "empty range");
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+/// Runs every check of the suite.
+pub fn run<V, Z>(mut new: impl FnMut() -> V, new_zst: impl FnMut() -> Z)
+where
+    V: Vec<Item = Tracked>,
+    Z: Vec<Item = Zst>,
+{
+    check_push_pop(&mut new);
+    check_insert_remove(&mut new);
+    check_drain(&mut new);
+    check_retain(&mut new);
+    check_dedup(&mut new);
+    check_partition(&mut new);
+    check_bulk(&mut new);
+    check_split_off(&mut new);
+    check_resize(&mut new);
+    check_model(&mut new, 0x9E37_79B9, 2_000);
+    check_zst(new_zst);
+}
+
+pub fn check_push_pop<V: Vec<Item = Tracked>>(mut new: impl FnMut() -> V) {
+    let tracker = Tracker::new();
+    let mut vec = new();
+    let mut model = std::vec::Vec::new();
+    assert!(vec.is_empty());
+    assert!(vec.pop().is_none());
+
+    for i in 0..MIN_CAPACITY as u32 {
+        vec.push(tracker.track(i));
+        model.push(i);
+        assert_model(&vec, &model, &tracker);
+    }
+    while let Some(item) = vec.pop() {
+        assert_eq!(Some(item.value()), model.pop());
+        drop(item);
+        assert_model(&vec, &model, &tracker);
+    }
+
+    let (vec, _) = filled(&mut new, &tracker, MIN_CAPACITY);
+    drop(vec);
+    assert_eq!(
+        tracker.live(),
+        0,
+        "dropping the container leaked or double-dropped"
+    );
+}
+
+pub fn check_insert_remove<V: Vec<Item = Tracked>>(mut new: impl FnMut() -> V) {
+    let tracker = Tracker::new();
+    let mut vec = new();
+    let mut model = std::vec::Vec::new();
+
+    for i in 0..MIN_CAPACITY as u32 {
+        let index = (i as usize * 7) % (model.len() + 1);
+        vec.insert(index, tracker.track(i));
+        model.insert(index, i);
+        assert_model(&vec, &model, &tracker);
+    }
+    assert_panics(|| vec.insert(vec.len() + 1, tracker.track(0)));
+    assert_panics(|| drop(vec.remove(vec.len())));
+    assert_panics(|| drop(vec.swap_remove(vec.len())));
+    assert_model(&vec, &model, &tracker);
+
+    let mut step = 0;
+    while !model.is_empty() {
+        let index = (step * 5) % model.len();
+        let (item, expected) = if step % 2 == 0 {
+            (vec.remove(index), model.remove(index))
+        } else {
+            (vec.swap_remove(index), model.swap_remove(index))
+        };
+        assert_eq!(item.value(), expected);
+        drop(item);
+        assert_model(&vec, &model, &tracker);
+        step += 1;
+    }
+}
+
+pub fn check_drain<V: Vec<Item = Tracked>>(mut new: impl FnMut() -> V) {
+    const LEN: usize = 8;
+    let tracker = Tracker::new();
+
+    for start in 0..=LEN {
+        for end in start..=LEN {
+            for consumed in 0..=end - start {
+                for from_back in [false, true] {
+                    let (mut vec, mut model) = filled(&mut new, &tracker, LEN);
+                    let expected: std::vec::Vec<u32> = model.drain(start..end).collect();
+                    let mut drain = vec.drain(start..end);
+                    assert_eq!(drain.size_hint(), (end - start, Some(end - start)));
+                    let mut taken = std::vec::Vec::new();
+                    for _ in 0..consumed {
+                        let item = if from_back {
+                            drain.next_back()
+                        } else {
+                            drain.next()
+                        };
+                        taken.push(item.unwrap().value());
+                    }
+                    assert_eq!(drain.len(), end - start - consumed);
+                    assert_eq!(drain.as_mut_slice().len(), drain.len());
+                    drop(drain);
+                    if from_back {
+                        taken.reverse();
+                        assert_eq!(taken, expected[expected.len() - consumed..]);
+                    } else {
+                        assert_eq!(taken, expected[..consumed]);
+                    }
+                    assert_model(&vec, &model, &tracker);
+                }
+            }
+        }
+    }
+
+    for start in 0..=LEN {
+        for end in start..=LEN {
+            for consumed in 0..=end - start {
+                let (mut vec, mut model) = filled(&mut new, &tracker, LEN);
+                let mut drain = vec.drain(start..end);
+                for _ in 0..consumed {
+                    drain.next();
+                }
+                drain.keep_rest();
+                model.drain(start..start + consumed);
+                assert_model(&vec, &model, &tracker);
+                drop(vec);
+
+                let (mut vec, mut model) = filled(&mut new, &tracker, LEN);
+                let mut drain = vec.drain(start..end);
+                let skipped = drain.advance_by(consumed);
+                assert_eq!(skipped, Ok(()));
+                assert_eq!(drain.len(), end - start - consumed);
+                let nth = drain.nth(1).map(|item| item.value());
+                let expected = model.drain(start..end).nth(consumed + 1);
+                assert_eq!(nth, expected);
+                let remaining = drain.len();
+                assert_eq!(
+                    drain.advance_by(LEN),
+                    Err(NonZeroUsize::new(LEN - remaining).unwrap())
+                );
+                assert_eq!(drain.len(), 0);
+                drop(drain);
+                assert_model(&vec, &model, &tracker);
+                drop(vec);
+
+                let (mut vec, mut model) = filled(&mut new, &tracker, LEN);
+                let mut other = new();
+                other.push(tracker.track(100));
+                vec.drain_into(start..end, &mut other);
+                let mut other_model = std::vec::Vec::from([100]);
+                other_model.extend(model.drain(start..end));
+                assert_eq!(values(&other), other_model);
+                drop(other);
+                assert_model(&vec, &model, &tracker);
+            }
+        }
+    }
+
+    let (mut vec, _) = filled(&mut new, &tracker, LEN);
+    assert_panics(|| drop(vec.drain(..LEN + 1)));
+    #[allow(clippy::reversed_empty_ranges)]
+    {
+        assert_panics(|| drop(vec.drain(2..1)));
+    }
+    assert_eq!(vec.len(), LEN);
+}
+
+pub fn check_retain<V: Vec<Item = Tracked>>(mut new: impl FnMut() -> V) {
+    let tracker = Tracker::new();
+
+    let (mut vec, mut model) = filled(&mut new, &tracker, MIN_CAPACITY);
+    vec.retain(|item| item.value() % 3 != 0);
+    model.retain(|value| value % 3 != 0);
+    assert_model(&vec, &model, &tracker);
+    vec.retain_mut(|item| item.value() % 2 == 0);
+    model.retain(|value| value % 2 == 0);
+    assert_model(&vec, &model, &tracker);
+    drop(vec);
+
+    for panic_at in 0..MIN_CAPACITY {
+        let (mut vec, mut model) = filled(&mut new, &tracker, MIN_CAPACITY);
+        let mut calls = 0;
+        assert_panics(|| {
+            vec.retain(|item| {
+                if calls == panic_at {
+                    panic!("injected panic in predicate");
+                }
+                calls += 1;
+                item.value() % 2 == 0
+            })
+        });
+        // Items visited before the panic are filtered, the rest are kept.
+        let mut index = 0;
+        model.retain(|value| {
+            index += 1;
+            index > panic_at || value % 2 == 0
+        });
+        assert_model(&vec, &model, &tracker);
+    }
+}
+
+pub fn check_dedup<V: Vec<Item = Tracked>>(mut new: impl FnMut() -> V) {
+    const VALUES: [u32; 12] = [0, 0, 1, 1, 1, 2, 3, 3, 4, 3, 3, 5];
+    let tracker = Tracker::new();
+    let from_values = |new: &mut dyn FnMut() -> V| {
+        let mut vec = new();
+        for value in VALUES {
+            vec.push(tracker.track(value));
+        }
+        vec
+    };
+
+    let mut vec = from_values(&mut new);
+    let mut model = VALUES.to_vec();
+    vec.dedup_by_key(|item| item.value());
+    model.dedup();
+    assert_model(&vec, &model, &tracker);
+    drop(vec);
+
+    let mut vec = from_values(&mut new);
+    let mut model = VALUES.to_vec();
+    vec.dedup_by(|a, b| a.value() / 2 == b.value() / 2);
+    model.dedup_by(|a, b| *a / 2 == *b / 2);
+    assert_model(&vec, &model, &tracker);
+    drop(vec);
+
+    for panic_at in 0..VALUES.len() - 1 {
+        let mut vec = from_values(&mut new);
+        let mut calls = 0;
+        assert_panics(|| {
+            vec.dedup_by(|a, b| {
+                if calls == panic_at {
+                    panic!("injected panic in same_bucket");
+                }
+                calls += 1;
+                a == b
+            })
+        });
+        assert_eq!(tracker.live(), vec.len() as isize);
+        assert!(vec.len() <= VALUES.len());
+        assert!(is_subsequence(&values(&vec), &VALUES));
+    }
+
+    let mut vec = from_values(&mut new);
+    let mut model = VALUES.to_vec();
+    model.dedup();
+    assert_eq!(vec.dedup_count(), VALUES.len() - model.len());
+    assert_model(&vec, &model, &tracker);
+    drop(vec);
+
+    let (mut kept, mut removed) = (std::vec::Vec::new(), std::vec::Vec::new());
+    for value in VALUES {
+        if kept.last() == Some(&value) {
+            removed.push(value);
+        } else {
+            kept.push(value);
+        }
+    }
+    let mut vec = from_values(&mut new);
+    let mut sink = new();
+    assert_eq!(
+        vec.dedup_by_returning(|a, b| a == b, &mut sink),
+        removed.len()
+    );
+    assert_eq!(values(&sink), removed);
+    drop(sink);
+    assert_model(&vec, &kept, &tracker);
+    drop(vec);
+
+    for panic_at in 0..VALUES.len() - 1 {
+        let mut vec = from_values(&mut new);
+        let mut sink = new();
+        let mut calls = 0;
+        assert_panics(|| {
+            vec.dedup_by_returning(
+                |a, b| {
+                    if calls == panic_at {
+                        panic!("injected panic in same_bucket");
+                    }
+                    calls += 1;
+                    a == b
+                },
+                &mut sink,
+            );
+        });
+        assert_eq!(tracker.live(), (vec.len() + sink.len()) as isize);
+        let mut all = [values(&vec), values(&sink)].concat();
+        all.sort_unstable();
+        assert_eq!(all, sorted(&VALUES));
+    }
+
+    let mut vec = from_values(&mut new);
+    let (unique, duplicates) = vec.partition_dedup();
+    assert_eq!(values_of(unique), kept);
+    assert_eq!(duplicates.len(), removed.len());
+    assert_eq!(sorted(&values(&vec)), sorted(&VALUES));
+    drop(vec);
+    assert_eq!(tracker.live(), 0);
+}
+
+pub fn check_partition<V: Vec<Item = Tracked>>(mut new: impl FnMut() -> V) {
+    let tracker = Tracker::new();
+
+    for len in 0..=MIN_CAPACITY {
+        let (mut vec, model) = filled(&mut new, &tracker, len);
+        let even = vec.partition_in_place(|item| item.value() % 2 == 0);
+        assert_eq!(even, model.iter().filter(|&&value| value % 2 == 0).count());
+        assert!(vec.as_slice()[..even]
+            .iter()
+            .all(|item| item.value() % 2 == 0));
+        assert!(vec.as_slice()[even..]
+            .iter()
+            .all(|item| item.value() % 2 == 1));
+        assert_eq!(sorted(&values(&vec)), model);
+        assert_eq!(tracker.live(), len as isize);
+    }
+
+    for panic_at in 0..MIN_CAPACITY {
+        let (mut vec, model) = filled(&mut new, &tracker, MIN_CAPACITY);
+        let mut calls = 0;
+        assert_panics(|| {
+            vec.partition_in_place(|item| {
+                if calls == panic_at {
+                    panic!("injected panic in predicate");
+                }
+                calls += 1;
+                item.value() % 3 == 0
+            });
+        });
+        assert_eq!(sorted(&values(&vec)), model);
+        assert_eq!(tracker.live(), MIN_CAPACITY as isize);
+    }
+}
+
+pub fn check_bulk<V: Vec<Item = Tracked>>(mut new: impl FnMut() -> V) {
+    const LEN: usize = 8;
+    let tracker = Tracker::new();
+    let source: std::vec::Vec<Tracked> = (100..104).map(|i| tracker.track(i)).collect();
+
+    for index in 0..=LEN {
+        let (mut vec, mut model) = filled(&mut new, &tracker, LEN);
+        vec.insert_slice(index, &source);
+        model.splice(index..index, 100..104);
+        assert_eq!(values(&vec), model);
+        drop(vec);
+
+        for hint in [true, false] {
+            let (mut vec, mut model) = filled(&mut new, &tracker, LEN);
+            let items = (200..205).map(|i| tracker.track(i));
+            if hint {
+                vec.insert_from_iter(index, items);
+            } else {
+                vec.insert_from_iter(index, items.filter(|_| true));
+            }
+            model.splice(index..index, 200..205);
+            assert_eq!(values(&vec), model);
+        }
+
+        let (mut vec, mut model) = filled(&mut new, &tracker, LEN);
+        assert!(vec.try_insert(index, tracker.track(50)).is_ok());
+        model.insert(index, 50);
+        assert!(vec.try_insert_slice(index, &source[..2]).is_ok());
+        model.splice(index..index, 100..102);
+        assert!(vec
+            .try_insert_from_iter(index, (0..2).map(|i| tracker.track(i)))
+            .is_ok());
+        model.splice(index..index, 0..2);
+        assert_eq!(values(&vec), model);
+
+        for end in index..=LEN {
+            let (mut vec, mut model) = filled(&mut new, &tracker, LEN);
+            vec.remove_range(index..end);
+            model.drain(index..end);
+            assert_eq!(values(&vec), model);
+        }
+    }
+    assert_eq!(tracker.live(), source.len() as isize);
+
+    for panic_after in 0..source.len() {
+        let (mut vec, mut model) = filled(&mut new, &tracker, LEN);
+        tracker.panic_after_clones(panic_after);
+        assert_panics(|| vec.insert_slice(3, &source));
+        tracker.disarm();
+        model.splice(3..3, 100..100 + panic_after as u32);
+        assert_eq!(values(&vec), model);
+    }
+
+    for indices in [
+        &[][..],
+        &[0],
+        &[7],
+        &[0, 1, 2],
+        &[1, 3, 4, 6],
+        &[0, 2, 4, 5, 6, 7],
+    ] {
+        let (mut vec, mut model) = filled(&mut new, &tracker, LEN);
+        vec.remove_indices(indices);
+        let mut index = 0;
+        model.retain(|_| {
+            index += 1;
+            !indices.contains(&(index - 1))
+        });
+        assert_eq!(values(&vec), model);
+    }
+    let (mut vec, model) = filled(&mut new, &tracker, LEN);
+    assert_panics(|| vec.remove_indices(&[2, 1]));
+    assert_panics(|| vec.remove_indices(&[3, 3]));
+    assert_panics(|| vec.remove_indices(&[LEN]));
+    assert_panics(|| vec.insert_slice(LEN + 1, &[]));
+    assert_eq!(values(&vec), model);
+    drop((vec, source));
+    assert_eq!(tracker.live(), 0);
+}
+
+pub fn check_split_off<V: Vec<Item = Tracked>>(mut new: impl FnMut() -> V) {
+    const LEN: usize = 10;
+    let tracker = Tracker::new();
+
+    for at in 0..=LEN {
+        let (mut vec, mut model) = filled(&mut new, &tracker, LEN);
+        let other = vec.split_off(at);
+        let other_model = model.split_off(at);
+        assert_eq!(values(&other), other_model);
+        assert_eq!(values(&vec), model);
+        drop(other);
+        assert_model(&vec, &model, &tracker);
+    }
+
+    let (mut vec, model) = filled(&mut new, &tracker, LEN);
+    assert_panics(|| drop(vec.split_off(LEN + 1)));
+    assert_model(&vec, &model, &tracker);
+}
+
+pub fn check_resize<V: Vec<Item = Tracked>>(mut new: impl FnMut() -> V) {
+    let tracker = Tracker::new();
+    let (mut vec, mut model) = filled(&mut new, &tracker, 4);
+
+    vec.resize(12, tracker.track(7));
+    model.resize(12, 7);
+    assert_model(&vec, &model, &tracker);
+    vec.resize(6, tracker.track(8));
+    model.resize(6, 8);
+    assert_model(&vec, &model, &tracker);
+    let mut next = 100;
+    vec.resize_with(9, || {
+        next += 1;
+        tracker.track(next)
+    });
+    model.extend([101, 102, 103]);
+    assert_model(&vec, &model, &tracker);
+    vec.resize_with(2, || unreachable!());
+    model.truncate(2);
+    assert_model(&vec, &model, &tracker);
+    vec.truncate(5);
+    assert_model(&vec, &model, &tracker);
+    vec.truncate(1);
+    model.truncate(1);
+    assert_model(&vec, &model, &tracker);
+    vec.clear();
+    model.clear();
+    assert_model(&vec, &model, &tracker);
+    drop(vec);
+
+    for panic_after in 0..8 {
+        let (mut vec, mut model) = filled(&mut new, &tracker, 2);
+        let value = tracker.track(9);
+        tracker.panic_after_clones(panic_after);
+        assert_panics(|| vec.resize(12, value));
+        tracker.disarm();
+        // Every clone that completed must have been committed.
+        model.resize(2 + panic_after, 9);
+        assert_model(&vec, &model, &tracker);
+    }
+
+    for panic_after in 0..4 {
+        let (mut vec, mut model) = filled(&mut new, &tracker, 3);
+        let source: std::vec::Vec<Tracked> = (10..16).map(|i| tracker.track(i)).collect();
+        tracker.panic_after_clones(panic_after);
+        assert_panics(|| vec.extend_from_slice(&source));
+        tracker.disarm();
+        model.extend(10..10 + panic_after as u32);
+        assert_eq!(values(&vec), model);
+        drop(source);
+        assert_model(&vec, &model, &tracker);
+    }
+}
+
+pub fn check_zst<Z: Vec<Item = Zst>>(mut new: impl FnMut() -> Z) {
+    let base = Zst::live();
+    let live = |expected: usize| assert_eq!(Zst::live() - base, expected as isize);
+
+    let mut vec = new();
+    for _ in 0..MIN_CAPACITY {
+        vec.push(Zst::new());
+    }
+    live(MIN_CAPACITY);
+    assert_eq!(vec.len(), MIN_CAPACITY);
+    assert!(vec.pop().is_some());
+    live(MIN_CAPACITY - 1);
+    vec.insert(3, Zst::new());
+    drop(vec.remove(0));
+    drop(vec.swap_remove(0));
+    live(MIN_CAPACITY - 2);
+
+    let drained = vec.drain(2..6).take(1).count();
+    assert_eq!(drained, 1);
+    assert_eq!(vec.len(), MIN_CAPACITY - 6);
+    live(MIN_CAPACITY - 6);
+
+    let mut keep = false;
+    vec.retain(|_| {
+        keep = !keep;
+        keep
+    });
+    assert_eq!(vec.len(), (MIN_CAPACITY - 6) / 2);
+    live(vec.len());
+
+    let other = vec.split_off(4);
+    assert_eq!(vec.len() + other.len(), (MIN_CAPACITY - 6) / 2);
+    drop(other);
+    live(vec.len());
+    vec.resize(10, Zst::new());
+    live(10);
+    vec.truncate(1);
+    live(1);
+    drop(vec);
+    live(0);
+}
+
+/// Applies `steps` pseudo-random operations seeded by `seed` to both the
+/// container and the reference model, comparing them after every step.
+pub fn check_model<V: Vec<Item = Tracked>>(mut new: impl FnMut() -> V, seed: u64, steps: usize) {
+    let tracker = Tracker::new();
+    let mut rng = XorShift::new(seed);
+    let mut vec = new();
+    let mut model = std::vec::Vec::new();
+    let mut next = 0;
+
+    for _ in 0..steps {
+        let len = model.len();
+        match rng.below(10) {
+            0..=2 if len < MIN_CAPACITY => {
+                vec.push(tracker.track(next));
+                model.push(next);
+                next += 1;
+            }
+            3 if len < MIN_CAPACITY => {
+                let index = rng.below(len + 1);
+                vec.insert(index, tracker.track(next));
+                model.insert(index, next);
+                next += 1;
+            }
+            4 => assert_eq!(vec.pop().map(|item| item.value()), model.pop()),
+            5 if len > 0 => {
+                let index = rng.below(len);
+                assert_eq!(vec.remove(index).value(), model.remove(index));
+            }
+            6 if len > 0 => {
+                let index = rng.below(len);
+                assert_eq!(vec.swap_remove(index).value(), model.swap_remove(index));
+            }
+            7 => {
+                let start = rng.below(len + 1);
+                let end = start + rng.below(len - start + 1);
+                let drained: std::vec::Vec<u32> =
+                    vec.drain(start..end).map(|item| item.value()).collect();
+                assert!(drained.iter().eq(model.drain(start..end).as_slice()));
+            }
+            8 => {
+                let mask = rng.next_u64() as u32;
+                vec.retain(|item| (mask >> (item.value() % 32)) & 1 == 1);
+                model.retain(|value| (mask >> (value % 32)) & 1 == 1);
+            }
+            _ => {
+                let len = rng.below(len + 1);
+                vec.truncate(len);
+                model.truncate(len);
+            }
+        }
+        assert_model(&vec, &model, &tracker);
+    }
+}
+
+fn filled<V: Vec<Item = Tracked>>(
+    new: &mut impl FnMut() -> V,
+    tracker: &Tracker,
+    len: usize,
+) -> (V, std::vec::Vec<u32>) {
+    let mut vec = new();
+    for i in 0..len as u32 {
+        vec.push(tracker.track(i));
+    }
+    (vec, (0..len as u32).collect())
+}
+
+fn values<S: SliceOwner<Item = Tracked>>(vec: &S) -> std::vec::Vec<u32> {
+    values_of(vec.as_slice())
+}
+
+fn values_of(items: &[Tracked]) -> std::vec::Vec<u32> {
+    items.iter().map(Tracked::value).collect()
+}
+
+fn sorted(values: &[u32]) -> std::vec::Vec<u32> {
+    let mut values = values.to_vec();
+    values.sort_unstable();
+    values
+}
+
+fn is_subsequence(needle: &[u32], haystack: &[u32]) -> bool {
+    let mut haystack = haystack.iter();
+    needle
+        .iter()
+        .all(|value| haystack.any(|other| other == value))
+}
+
+#[track_caller]
+fn assert_model<V: Vec<Item = Tracked>>(vec: &V, model: &[u32], tracker: &Tracker) {
+    assert_eq!(values(vec), model);
+    assert!(vec.len() <= vec.capacity());
+    assert_eq!(
+        tracker.live(),
+        model.len() as isize,
+        "live items do not match the container length"
+    );
+}
+
+#[track_caller]
+fn assert_panics<F: FnOnce()>(f: F) {
+    assert!(
+        panic::catch_unwind(AssertUnwindSafe(f)).is_err(),
+        "expected a panic"
+    );
+}
@@ -0,0 +1,157 @@
+use core::{cmp::Ordering, fmt, mem, slice};
+
+use crate::{
+    traits::{
+        compare::{Natural, Reversed},
+        Comparator,
+    },
+    BinaryHeap, StackVec,
+};
+
+/// Keeps the `K` greatest items, according to `C`, out of everything pushed
+/// into it.
+///
+/// The items live in a [`StackVec`] arranged as a heap with the smallest kept
+/// item on top, so each push costs O(log K).
+pub struct TopK<T, const K: usize, C = Natural> {
+    heap: BinaryHeap<StackVec<T, K>, Reversed<C>>,
+}
+
+impl<T: Ord, const K: usize> TopK<T, K, Natural> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_comparator(Natural)
+    }
+}
+
+impl<T, const K: usize, C: Comparator<T>> TopK<T, K, C> {
+    #[inline]
+    pub fn with_comparator(cmp: C) -> Self {
+        Self {
+            heap: BinaryHeap::with_comparator(Reversed(cmp)),
+        }
+    }
+
+    #[inline]
+    pub const fn capacity() -> usize {
+        K
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.heap.len() == K
+    }
+
+    #[inline]
+    pub fn comparator(&self) -> &C {
+        &self.heap.comparator().0
+    }
+
+    /// The smallest item kept. Once full, only greater items get in.
+    #[inline]
+    pub fn threshold(&self) -> Option<&T> {
+        self.heap.peek()
+    }
+
+    /// Offers `item`. Returns the item that got evicted to make room for it,
+    /// or `item` itself if it is not among the `K` greatest.
+    pub fn push(&mut self, item: T) -> Option<T> {
+        if !self.is_full() {
+            self.heap.push(item);
+            return None;
+        }
+        let greater = self
+            .heap
+            .peek()
+            .is_some_and(|min| self.comparator().compare(&item, min) == Ordering::Greater);
+        if !greater {
+            return Some(item);
+        }
+        let mut min = self.heap.peek_mut()?;
+        Some(mem::replace(&mut *min, item))
+    }
+
+    /// The items kept, in no particular order.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.heap.iter()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.heap.clear();
+    }
+
+    /// The items kept, greatest first.
+    #[inline]
+    pub fn into_sorted_stack_vec(self) -> StackVec<T, K> {
+        self.heap.into_sorted_vec()
+    }
+
+    /// The items kept, in no particular order.
+    #[inline]
+    pub fn into_stack_vec(self) -> StackVec<T, K> {
+        self.heap.into_vec()
+    }
+}
+
+impl<T: Ord, const K: usize> Default for TopK<T, K, Natural> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const K: usize, C: Clone> Clone for TopK<T, K, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            heap: self.heap.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, const K: usize, C> fmt::Debug for TopK<T, K, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TopK").field(&self.heap).finish()
+    }
+}
+
+impl<T, const K: usize, C: Comparator<T>> Extend<T> for TopK<T, K, C> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T, const K: usize, C: Comparator<T> + Default> FromIterator<T> for TopK<T, K, C> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut top = Self::with_comparator(C::default());
+        top.extend(iter);
+        top
+    }
+}
+
+impl<'a, T, const K: usize, C> IntoIterator for &'a TopK<T, K, C> {
+    type Item = &'a T;
+
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.heap.into_iter()
+    }
+}
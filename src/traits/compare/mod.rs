@@ -0,0 +1,69 @@
+use core::{cmp::Ordering, marker::PhantomData};
+
+/// A total order over `T`, used by the ordered containers of this crate.
+///
+/// Closures of the form `Fn(&T, &T) -> Ordering` are comparators.
+pub trait Comparator<T: ?Sized> {
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+impl<T: ?Sized, F: Fn(&T, &T) -> Ordering> Comparator<T> for F {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
+
+/// The order given by `T: Ord`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Natural;
+
+impl<T: Ord + ?Sized> Comparator<T> for Natural {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+/// The inverse of the order given by `C`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Reversed<C = Natural>(pub C);
+
+impl<T: ?Sized, C: Comparator<T>> Comparator<T> for Reversed<C> {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self.0.compare(b, a)
+    }
+}
+
+/// Orders items by the key extracted by `F`.
+pub struct ByKey<F, K> {
+    key: F,
+    _key: PhantomData<fn() -> K>,
+}
+
+impl<F, K> ByKey<F, K> {
+    #[inline]
+    pub const fn new(key: F) -> Self {
+        Self {
+            key,
+            _key: PhantomData,
+        }
+    }
+}
+
+impl<F: Clone, K> Clone for ByKey<F, K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.key.clone())
+    }
+}
+
+impl<F: Copy, K> Copy for ByKey<F, K> {}
+
+impl<T: ?Sized, K: Ord, F: Fn(&T) -> K> Comparator<T> for ByKey<F, K> {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        (self.key)(a).cmp(&(self.key)(b))
+    }
+}
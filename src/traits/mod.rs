@@ -1,6 +1,11 @@
+pub mod compare;
+pub mod radix;
 pub mod slice_owner;
+pub mod sorted_slice;
 pub mod vec;
 
-
+pub use compare::Comparator;
+pub use radix::RadixKey;
 pub use vec::Vec;
-pub use slice_owner::SliceOwner;
\ No newline at end of file
+pub use slice_owner::SliceOwner;
+pub use sorted_slice::SortedSliceExt;
@@ -0,0 +1,50 @@
+/// A key that can be sorted byte by byte, used by [`Vec::radix_sort`].
+///
+/// [`Vec::radix_sort`]: crate::traits::Vec::radix_sort
+pub trait RadixKey: Copy + Ord {
+    /// Number of bytes in the key.
+    const BYTES: usize;
+
+    /// Byte `index`, 0 being the least significant, of an unsigned integer
+    /// ordered like `self`.
+    fn radix_byte(self, index: usize) -> u8;
+}
+
+macro_rules! unsigned_key {
+    ($($ty:ty),*) => {$(
+        impl RadixKey for $ty {
+            const BYTES: usize = core::mem::size_of::<$ty>();
+
+            #[inline]
+            fn radix_byte(self, index: usize) -> u8 {
+                (self >> (index * 8)) as u8
+            }
+        }
+    )*};
+}
+
+macro_rules! signed_key {
+    ($($ty:ty => $unsigned:ty),*) => {$(
+        impl RadixKey for $ty {
+            const BYTES: usize = core::mem::size_of::<$ty>();
+
+            /// Flipping the sign bit moves negative values below positive ones.
+            #[inline]
+            fn radix_byte(self, index: usize) -> u8 {
+                ((self as $unsigned ^ (1 << (<$unsigned>::BITS - 1))) >> (index * 8)) as u8
+            }
+        }
+    )*};
+}
+
+unsigned_key!(u8, u16, u32, u64, u128, usize);
+signed_key!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+impl RadixKey for char {
+    const BYTES: usize = 4;
+
+    #[inline]
+    fn radix_byte(self, index: usize) -> u8 {
+        (self as u32).radix_byte(index)
+    }
+}
@@ -1,8 +1,19 @@
+/// A type that owns a contiguous run of initialized `Item`s.
+///
+/// # Safety
+///
+/// `as_ptr` (and `as_mut_ptr`) must point to `len()` initialized, properly
+/// aligned items owned by `self`.
 pub unsafe trait SliceOwner {
     type Item;
 
     fn len(&self) -> usize;
 
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     fn as_ptr(&self) -> *const Self::Item;
 
     #[inline]
@@ -21,6 +32,34 @@ pub unsafe trait SliceOwner {
     }
 }
 
+unsafe impl<T> SliceOwner for [T] {
+    type Item = T;
+    #[inline]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    #[inline]
+    fn as_ptr(&self) -> *const Self::Item {
+        <[T]>::as_ptr(self)
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut Self::Item {
+        <[T]>::as_mut_ptr(self)
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[Self::Item] {
+        self
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [Self::Item] {
+        self
+    }
+}
+
 unsafe impl<T, const N: usize> SliceOwner for [T; N] {
     type Item = T;
     #[inline]
@@ -69,29 +108,29 @@ unsafe impl<T> SliceOwner for Vec<T> {
 }
 
 #[cfg(feature = "std")]
-unsafe impl<T> SliceOwner for Box<[T]> {    
+unsafe impl<T> SliceOwner for Box<[T]> {
     type Item = T;
 
     #[inline]
     fn len(&self) -> usize {
-        (&**self).len()
+        <[T]>::len(self)
     }
 
     #[inline]
     fn as_ptr(&self) -> *const Self::Item {
-        (&**self).as_ptr()
+        <[T]>::as_ptr(self)
     }
 
     #[inline]
     fn as_mut_ptr(&mut self) -> *mut Self::Item {
-        (&mut **self).as_mut_ptr()
+        <[T]>::as_mut_ptr(self)
     }
 
     fn as_slice(&self) -> &[Self::Item] {
-        &**self
+        self
     }
 
     fn as_mut_slice(&mut self) -> &mut [Self::Item] {
-        &mut **self
+        self
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,403 @@
+use core::{cmp::Ordering, fmt, iter::FusedIterator};
+
+use super::{
+    vec::{CapacityError, Vec},
+    SliceOwner,
+};
+
+/// Set operations over [`SliceOwner`]s sorted in ascending order.
+///
+/// `union`, `intersection`, `difference` and `symmetric_difference` expect
+/// both sides to be free of duplicates; `merge` keeps every item. Unsorted
+/// inputs give unspecified (but safe) results.
+///
+/// The `*_into` methods append the result to `out`. Their `try_*` variants
+/// leave `out` untouched and return an error if it is too small to hold it.
+pub trait SortedSliceExt: SliceOwner {
+    #[inline]
+    fn union<'a, O>(&'a self, other: &'a O) -> Union<'a, Self::Item>
+    where
+        O: SliceOwner<Item = Self::Item> + ?Sized,
+        Self::Item: Ord,
+    {
+        Union(Pair::new(self.as_slice(), other.as_slice()))
+    }
+
+    #[inline]
+    fn intersection<'a, O>(&'a self, other: &'a O) -> Intersection<'a, Self::Item>
+    where
+        O: SliceOwner<Item = Self::Item> + ?Sized,
+        Self::Item: Ord,
+    {
+        Intersection(Pair::new(self.as_slice(), other.as_slice()))
+    }
+
+    /// Items of `self` that are not in `other`.
+    #[inline]
+    fn difference<'a, O>(&'a self, other: &'a O) -> Difference<'a, Self::Item>
+    where
+        O: SliceOwner<Item = Self::Item> + ?Sized,
+        Self::Item: Ord,
+    {
+        Difference(Pair::new(self.as_slice(), other.as_slice()))
+    }
+
+    #[inline]
+    fn symmetric_difference<'a, O>(&'a self, other: &'a O) -> SymmetricDifference<'a, Self::Item>
+    where
+        O: SliceOwner<Item = Self::Item> + ?Sized,
+        Self::Item: Ord,
+    {
+        SymmetricDifference(Pair::new(self.as_slice(), other.as_slice()))
+    }
+
+    /// Every item of both sides, in order. Equal items of `self` come first.
+    #[inline]
+    fn merge<'a, O>(&'a self, other: &'a O) -> Merge<'a, Self::Item>
+    where
+        O: SliceOwner<Item = Self::Item> + ?Sized,
+        Self::Item: Ord,
+    {
+        Merge(Pair::new(self.as_slice(), other.as_slice()))
+    }
+
+    #[inline]
+    fn union_into<O, V>(&self, other: &O, out: &mut V)
+    where
+        O: SliceOwner<Item = Self::Item> + ?Sized,
+        V: Vec<Item = Self::Item> + ?Sized,
+        Self::Item: Ord + Clone,
+    {
+        extend_cloned(out, self.union(other));
+    }
+
+    #[inline]
+    fn try_union_into<O, V>(&self, other: &O, out: &mut V) -> Result<(), CapacityError>
+    where
+        O: SliceOwner<Item = Self::Item> + ?Sized,
+        V: Vec<Item = Self::Item> + ?Sized,
+        Self::Item: Ord + Clone,
+    {
+        try_extend_cloned(out, self.union(other))
+    }
+
+    #[inline]
+    fn intersection_into<O, V>(&self, other: &O, out: &mut V)
+    where
+        O: SliceOwner<Item = Self::Item> + ?Sized,
+        V: Vec<Item = Self::Item> + ?Sized,
+        Self::Item: Ord + Clone,
+    {
+        extend_cloned(out, self.intersection(other));
+    }
+
+    #[inline]
+    fn try_intersection_into<O, V>(&self, other: &O, out: &mut V) -> Result<(), CapacityError>
+    where
+        O: SliceOwner<Item = Self::Item> + ?Sized,
+        V: Vec<Item = Self::Item> + ?Sized,
+        Self::Item: Ord + Clone,
+    {
+        try_extend_cloned(out, self.intersection(other))
+    }
+
+    #[inline]
+    fn difference_into<O, V>(&self, other: &O, out: &mut V)
+    where
+        O: SliceOwner<Item = Self::Item> + ?Sized,
+        V: Vec<Item = Self::Item> + ?Sized,
+        Self::Item: Ord + Clone,
+    {
+        extend_cloned(out, self.difference(other));
+    }
+
+    #[inline]
+    fn try_difference_into<O, V>(&self, other: &O, out: &mut V) -> Result<(), CapacityError>
+    where
+        O: SliceOwner<Item = Self::Item> + ?Sized,
+        V: Vec<Item = Self::Item> + ?Sized,
+        Self::Item: Ord + Clone,
+    {
+        try_extend_cloned(out, self.difference(other))
+    }
+
+    #[inline]
+    fn symmetric_difference_into<O, V>(&self, other: &O, out: &mut V)
+    where
+        O: SliceOwner<Item = Self::Item> + ?Sized,
+        V: Vec<Item = Self::Item> + ?Sized,
+        Self::Item: Ord + Clone,
+    {
+        extend_cloned(out, self.symmetric_difference(other));
+    }
+
+    #[inline]
+    fn try_symmetric_difference_into<O, V>(
+        &self,
+        other: &O,
+        out: &mut V,
+    ) -> Result<(), CapacityError>
+    where
+        O: SliceOwner<Item = Self::Item> + ?Sized,
+        V: Vec<Item = Self::Item> + ?Sized,
+        Self::Item: Ord + Clone,
+    {
+        try_extend_cloned(out, self.symmetric_difference(other))
+    }
+
+    #[inline]
+    fn merge_into<O, V>(&self, other: &O, out: &mut V)
+    where
+        O: SliceOwner<Item = Self::Item> + ?Sized,
+        V: Vec<Item = Self::Item> + ?Sized,
+        Self::Item: Ord + Clone,
+    {
+        extend_cloned(out, self.merge(other));
+    }
+
+    #[inline]
+    fn try_merge_into<O, V>(&self, other: &O, out: &mut V) -> Result<(), CapacityError>
+    where
+        O: SliceOwner<Item = Self::Item> + ?Sized,
+        V: Vec<Item = Self::Item> + ?Sized,
+        Self::Item: Ord + Clone,
+    {
+        try_extend_cloned(out, self.merge(other))
+    }
+}
+
+impl<S: SliceOwner + ?Sized> SortedSliceExt for S {}
+
+fn extend_cloned<'a, V, I>(out: &mut V, iter: I)
+where
+    V: Vec + ?Sized,
+    V::Item: Clone + 'a,
+    I: Iterator<Item = &'a V::Item>,
+{
+    out.reserve(iter.size_hint().0);
+    for item in iter {
+        out.push(item.clone());
+    }
+}
+
+fn try_extend_cloned<'a, V, I>(out: &mut V, iter: I) -> Result<(), CapacityError>
+where
+    V: Vec + ?Sized,
+    V::Item: Clone + 'a,
+    I: Iterator<Item = &'a V::Item>,
+{
+    let len = out.len();
+    for item in iter {
+        if out.try_push(item.clone()).is_err() {
+            out.truncate(len);
+            return Err(CapacityError::new(()));
+        }
+    }
+    Ok(())
+}
+
+/// The unvisited parts of both sides.
+struct Pair<'a, T> {
+    a: &'a [T],
+    b: &'a [T],
+}
+
+impl<'a, T: Ord> Pair<'a, T> {
+    #[inline]
+    fn new(a: &'a [T], b: &'a [T]) -> Self {
+        Self { a, b }
+    }
+
+    /// Compares the heads of both sides; `None` if either is exhausted.
+    #[inline]
+    fn cmp_heads(&self) -> Option<Ordering> {
+        Some(self.a.first()?.cmp(self.b.first()?))
+    }
+
+    #[inline]
+    fn next_a(&mut self) -> Option<&'a T> {
+        let (head, rest) = self.a.split_first()?;
+        self.a = rest;
+        Some(head)
+    }
+
+    #[inline]
+    fn next_b(&mut self) -> Option<&'a T> {
+        let (head, rest) = self.b.split_first()?;
+        self.b = rest;
+        Some(head)
+    }
+}
+
+impl<T> Clone for Pair<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a,
+            b: self.b,
+        }
+    }
+}
+
+macro_rules! set_iter {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        pub struct $name<'a, T>(Pair<'a, T>);
+
+        impl<T> Clone for $name<'_, T> {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self(self.0.clone())
+            }
+        }
+
+        impl<T: fmt::Debug> fmt::Debug for $name<'_, T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&self.0.a)
+                    .field(&self.0.b)
+                    .finish()
+            }
+        }
+
+        impl<T: Ord> FusedIterator for $name<'_, T> {}
+    };
+}
+
+set_iter!(
+    /// Iterator returned by [`SortedSliceExt::union`].
+    Union
+);
+set_iter!(
+    /// Iterator returned by [`SortedSliceExt::intersection`].
+    Intersection
+);
+set_iter!(
+    /// Iterator returned by [`SortedSliceExt::difference`].
+    Difference
+);
+set_iter!(
+    /// Iterator returned by [`SortedSliceExt::symmetric_difference`].
+    SymmetricDifference
+);
+set_iter!(
+    /// Iterator returned by [`SortedSliceExt::merge`].
+    Merge
+);
+
+impl<'a, T: Ord> Iterator for Union<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        match self.0.cmp_heads() {
+            Some(Ordering::Less) => self.0.next_a(),
+            Some(Ordering::Greater) => self.0.next_b(),
+            Some(Ordering::Equal) => {
+                self.0.next_b();
+                self.0.next_a()
+            }
+            None => self.0.next_a().or_else(|| self.0.next_b()),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a, b) = (self.0.a.len(), self.0.b.len());
+        (a.max(b), a.checked_add(b))
+    }
+}
+
+impl<'a, T: Ord> Iterator for Intersection<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            match self.0.cmp_heads()? {
+                Ordering::Less => {
+                    self.0.next_a();
+                }
+                Ordering::Greater => {
+                    self.0.next_b();
+                }
+                Ordering::Equal => {
+                    self.0.next_b();
+                    return self.0.next_a();
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.0.a.len().min(self.0.b.len())))
+    }
+}
+
+impl<'a, T: Ord> Iterator for Difference<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            match self.0.cmp_heads() {
+                Some(Ordering::Less) | None => return self.0.next_a(),
+                Some(Ordering::Greater) => {
+                    self.0.next_b();
+                }
+                Some(Ordering::Equal) => {
+                    self.0.next_a();
+                    self.0.next_b();
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a, b) = (self.0.a.len(), self.0.b.len());
+        (a.saturating_sub(b), Some(a))
+    }
+}
+
+impl<'a, T: Ord> Iterator for SymmetricDifference<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            match self.0.cmp_heads() {
+                Some(Ordering::Less) => return self.0.next_a(),
+                Some(Ordering::Greater) => return self.0.next_b(),
+                Some(Ordering::Equal) => {
+                    self.0.next_a();
+                    self.0.next_b();
+                }
+                None => return self.0.next_a().or_else(|| self.0.next_b()),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a, b) = (self.0.a.len(), self.0.b.len());
+        (a.abs_diff(b), a.checked_add(b))
+    }
+}
+
+impl<'a, T: Ord> Iterator for Merge<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        match self.0.cmp_heads() {
+            Some(Ordering::Greater) => self.0.next_b(),
+            Some(_) => self.0.next_a(),
+            None => self.0.next_a().or_else(|| self.0.next_b()),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.a.len() + self.0.b.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Ord> ExactSizeIterator for Merge<'_, T> {}
@@ -1,8 +1,8 @@
 use super::*;
 
-use core::{fmt, slice, ptr, mem};
+use core::{fmt, marker::PhantomData, mem, num::NonZeroUsize, ptr, slice};
 
-pub struct Drain<'a, T: VecUnsized + ?Sized> {
+pub struct Drain<'a, T: Vec + ?Sized> {
     /// Index of tail to preserve
     pub(super) tail_start: usize,
     /// Length of tail
@@ -10,35 +10,92 @@ pub struct Drain<'a, T: VecUnsized + ?Sized> {
     /// Current remaining range to remove
     pub(super) iter: slice::Iter<'a, T::Item>,
     pub(super) vec: ptr::NonNull<T>,
+    pub(super) _marker: PhantomData<&'a mut T>,
 }
 
-impl<'a, T: VecUnsized + ?Sized> Drain<'a, T>{    
+impl<'a, T: Vec + ?Sized> Drain<'a, T> {
     const IS_ZST: bool = mem::size_of::<T::Item>() == 0;
-}
 
-impl<T: VecUnsized<Item: fmt::Debug> + ?Sized> fmt::Debug for Drain<'_, T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("Drain").field(&self.iter.as_slice()).finish()
+    pub fn as_slice(&self) -> &[T::Item] {
+        self.iter.as_slice()
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T::Item] {
+        let len = self.iter.len();
+        unsafe { slice::from_raw_parts_mut(self.remaining_ptr(), len) }
+    }
+
+    /// Keeps the un-yielded items in the source container instead of dropping
+    /// them, closing the gap left by the yielded ones.
+    pub fn keep_rest(self) {
+        let mut this = mem::ManuallyDrop::new(self);
+        unsafe {
+            let unyielded_len = this.iter.len();
+            let unyielded_ptr = this.remaining_ptr();
+            let source_vec = this.vec.as_mut();
+            let start = source_vec.len();
+            let tail = this.tail_start;
+
+            if !Self::IS_ZST {
+                let start_ptr = source_vec.as_mut_ptr().add(start);
+                if unyielded_ptr != start_ptr {
+                    ptr::copy(unyielded_ptr, start_ptr, unyielded_len);
+                }
+                if tail != start + unyielded_len {
+                    let src = source_vec.as_ptr().add(tail);
+                    ptr::copy(src, start_ptr.add(unyielded_len), this.tail_len);
+                }
+            }
+            source_vec.set_len(start + unyielded_len + this.tail_len);
+        }
+    }
+
+    /// Drops the next `n` items without yielding them. Returns how many were
+    /// missing if fewer than `n` remained.
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let remaining = self.iter.len();
+        let step = n.min(remaining);
+        self.drop_front(step);
+        NonZeroUsize::new(n - step).map_or(Ok(()), Err)
+    }
+
+    /// Mutable pointer to the first un-yielded item.
+    fn remaining_ptr(&mut self) -> *mut T::Item {
+        if Self::IS_ZST {
+            return ptr::NonNull::dangling().as_ptr();
+        }
+        unsafe {
+            let vec_ptr = self.vec.as_mut().as_mut_ptr();
+            vec_ptr.add(self.iter.as_slice().as_ptr().offset_from(vec_ptr) as usize)
+        }
+    }
+
+    /// Drops the first `count` un-yielded items in place.
+    fn drop_front(&mut self, count: usize) {
+        let to_drop = ptr::slice_from_raw_parts_mut(self.remaining_ptr(), count);
+        let rest = self.iter.as_slice();
+        self.iter = rest[count..].iter();
+        unsafe { ptr::drop_in_place(to_drop) }
     }
 }
 
-impl<'a, T: VecUnsized + ?Sized> Drain<'a, T> {
-    pub fn as_slice(&self) -> &[T::Item] {
-        self.iter.as_slice()
+impl<T: Vec<Item: fmt::Debug> + ?Sized> fmt::Debug for Drain<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Drain").field(&self.iter.as_slice()).finish()
     }
 }
 
-impl<'a, T: VecUnsized + ?Sized> AsRef<[T::Item]> for Drain<'a, T> {
+impl<'a, T: Vec + ?Sized> AsRef<[T::Item]> for Drain<'a, T> {
     fn as_ref(&self) -> &[T::Item] {
         self.as_slice()
     }
 }
 
-unsafe impl<T: VecUnsized<Item: Sync> + ?Sized> Sync for Drain<'_, T> {}
+unsafe impl<T: Vec<Item: Sync> + Sync + ?Sized> Sync for Drain<'_, T> {}
 
-unsafe impl<T: VecUnsized<Item: Send> + ?Sized> Send for Drain<'_, T> {}
+unsafe impl<T: Vec<Item: Send> + Send + ?Sized> Send for Drain<'_, T> {}
 
-impl<V: VecUnsized + ?Sized> Iterator for Drain<'_, V> {
+impl<V: Vec + ?Sized> Iterator for Drain<'_, V> {
     type Item = V::Item;
 
     #[inline]
@@ -51,8 +108,15 @@ impl<V: VecUnsized + ?Sized> Iterator for Drain<'_, V> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.iter.size_hint()
     }
+
+    fn nth(&mut self, n: usize) -> Option<V::Item> {
+        let step = n.min(self.iter.len());
+        self.drop_front(step);
+        self.next()
+    }
 }
-impl<T: VecUnsized + ?Sized> DoubleEndedIterator for Drain<'_, T> {
+
+impl<T: Vec + ?Sized> DoubleEndedIterator for Drain<'_, T> {
     #[inline]
     fn next_back(&mut self) -> Option<T::Item> {
         self.iter
@@ -61,12 +125,12 @@ impl<T: VecUnsized + ?Sized> DoubleEndedIterator for Drain<'_, T> {
     }
 }
 
-impl<T: VecUnsized + ?Sized> Drop for Drain<'_, T> {
+impl<T: Vec + ?Sized> Drop for Drain<'_, T> {
     fn drop(&mut self) {
         /// Moves back the un-`Drain`ed elements to restore the original `Vec`.
-        struct DropGuard<'r, 'a, T: VecUnsized + ?Sized>(&'r mut Drain<'a, T>);
+        struct DropGuard<'r, 'a, T: Vec + ?Sized>(&'r mut Drain<'a, T>);
 
-        impl<'r, 'a, T: VecUnsized + ?Sized> Drop for DropGuard<'r, 'a, T> {
+        impl<'r, 'a, T: Vec + ?Sized> Drop for DropGuard<'r, 'a, T> {
             fn drop(&mut self) {
                 if self.0.tail_len > 0 {
                     unsafe {
@@ -85,14 +149,12 @@ impl<T: VecUnsized + ?Sized> Drop for Drain<'_, T> {
             }
         }
 
-        let iter = mem::take(&mut self.iter);
-        let drop_len = iter.len();
-
-        let mut vec = self.vec;
+        let drop_len = self.iter.len();
 
         if Self::IS_ZST {
+            self.iter = Default::default();
             unsafe {
-                let vec = vec.as_mut();
+                let vec = self.vec.as_mut();
                 let old_len = vec.len();
                 vec.set_len(old_len + drop_len + self.tail_len);
                 vec.truncate(old_len + self.tail_len);
@@ -101,28 +163,20 @@ impl<T: VecUnsized + ?Sized> Drop for Drain<'_, T> {
             return;
         }
 
-        let _guard = DropGuard(self);
+        let guard = DropGuard(self);
 
         if drop_len == 0 {
             return;
         }
 
-        let drop_ptr = iter.as_slice().as_ptr();
-
-        unsafe {
-            let vec_ptr = vec.as_mut().as_mut_ptr();
-            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
-                vec_ptr.add(drop_ptr.offset_from(vec_ptr) as usize),
-                drop_len,
-            ))
-        }
+        guard.0.drop_front(drop_len);
     }
 }
 
-impl<T: VecUnsized + ?Sized> ExactSizeIterator for Drain<'_, T> {
+impl<T: Vec + ?Sized> ExactSizeIterator for Drain<'_, T> {
     fn len(&self) -> usize {
-        self.tail_len
+        self.iter.len()
     }
 }
 
-impl<T: VecUnsized + ?Sized> core::iter::FusedIterator for Drain<'_, T> {}
+impl<T: Vec + ?Sized> core::iter::FusedIterator for Drain<'_, T> {}
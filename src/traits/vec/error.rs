@@ -0,0 +1,41 @@
+use core::fmt;
+
+/// Error returned when a container has no room for more items.
+///
+/// It carries the item that could not be stored, if there was one.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CapacityError<T = ()>(T);
+
+impl<T> CapacityError<T> {
+    #[inline]
+    pub const fn new(element: T) -> Self {
+        Self(element)
+    }
+
+    /// The item that did not fit.
+    #[inline]
+    pub fn element(self) -> T {
+        self.0
+    }
+
+    /// Drops the item, keeping only the error.
+    #[inline]
+    pub fn simplify(self) -> CapacityError {
+        CapacityError(())
+    }
+}
+
+impl<T> fmt::Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CapacityError: insufficient capacity")
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for CapacityError<T> {}
@@ -1,21 +1,52 @@
 mod drain;
+mod error;
+mod radix;
+mod sort;
 
 pub use drain::Drain;
+pub use error::CapacityError;
 
-use super::SliceOwner;
+use super::{RadixKey, SliceOwner};
 use core::{
+    cmp::Ordering,
+    marker::PhantomData,
     mem::{ManuallyDrop, MaybeUninit},
     ops::{Bound, Range, RangeBounds},
     ptr,
 };
 
+/// A resizable, contiguous container.
+///
+/// # Safety
+///
+/// Implementors must own an allocation of `capacity()` slots starting at
+/// `as_ptr()`, keep that allocation in place unless `reserve` is called, and
+/// make `reserve(n)` either guarantee room for `n` more items or panic.
 pub unsafe trait Vec: SliceOwner {
     fn capacity(&self) -> usize;
 
+    /// # Safety
+    ///
+    /// `new_len` must be `<= capacity()` and the first `new_len` items must be
+    /// initialized.
     unsafe fn set_len(&mut self, new_len: usize);
 
     fn reserve(&mut self, additional: usize);
 
+    /// Like [`reserve`](Vec::reserve), but reports missing room instead of
+    /// panicking. The default only looks at the spare capacity, so growable
+    /// implementors should override it.
+    fn try_reserve(&mut self, additional: usize) -> Result<(), CapacityError> {
+        if additional <= self.capacity() - self.len() {
+            Ok(())
+        } else {
+            Err(CapacityError::new(()))
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `at` must be `<= len()`.
     unsafe fn split_off_unchecked(&mut self, at: usize) -> Self
     where
         Self: Sized;
@@ -36,6 +67,9 @@ pub unsafe trait Vec: SliceOwner {
         unsafe { self.split_off_unchecked(at) }
     }
 
+    /// # Safety
+    ///
+    /// `len()` must be `< capacity()`.
     unsafe fn push_unchecked(&mut self, item: Self::Item) {
         self.as_mut_ptr().add(self.len()).write(item);
         self.set_len(self.len().unchecked_add(1));
@@ -46,6 +80,17 @@ pub unsafe trait Vec: SliceOwner {
         unsafe { self.push_unchecked(item) };
     }
 
+    fn try_push(&mut self, item: Self::Item) -> Result<(), CapacityError<Self::Item>> {
+        if self.try_reserve(1).is_err() {
+            return Err(CapacityError::new(item));
+        }
+        unsafe { self.push_unchecked(item) };
+        Ok(())
+    }
+
+    /// # Safety
+    ///
+    /// There must be room for `other.len()` more items.
     unsafe fn append_unchecked<S: SliceOwner<Item = Self::Item>>(&mut self, other: S)
     where
         Self: Sized,
@@ -59,11 +104,12 @@ pub unsafe trait Vec: SliceOwner {
         Self: Sized,
     {
         self.reserve(other.len());
-        {
-            unsafe { self.append_unchecked(other) }
-        };
+        unsafe { self.append_unchecked(other) };
     }
 
+    /// # Safety
+    ///
+    /// The container must not be empty.
     unsafe fn pop_unchecked(&mut self) -> Self::Item {
         self.set_len(self.len().unchecked_sub(1));
         self.as_mut_ptr().add(self.len()).read()
@@ -89,15 +135,21 @@ pub unsafe trait Vec: SliceOwner {
         unsafe { self.swap_remove_unchecked(index) }
     }
 
+    /// # Safety
+    ///
+    /// `index` must be `< len()`.
     unsafe fn swap_remove_unchecked(&mut self, index: usize) -> Self::Item {
         let value = core::ptr::read(self.as_ptr().add(index));
         let base_ptr = self.as_mut_ptr();
         let new_len = self.len().unchecked_sub(1);
-        base_ptr.add(new_len).copy_from(base_ptr.add(index), 1);
+        base_ptr.add(index).copy_from(base_ptr.add(new_len), 1);
         self.set_len(new_len);
         value
     }
 
+    /// # Safety
+    ///
+    /// `index` must be `< len()`.
     unsafe fn remove_unchecked(&mut self, index: usize) -> Self::Item {
         let new_len = self.len().unchecked_sub(1);
         self.set_len(new_len);
@@ -136,6 +188,9 @@ pub unsafe trait Vec: SliceOwner {
         unsafe { self.truncate_unchecked(len) };
     }
 
+    /// # Safety
+    ///
+    /// `len` must be `<= len()`.
     unsafe fn truncate_unchecked(&mut self, len: usize) {
         let remaining_len = self.len().unchecked_sub(len);
         let s = core::ptr::slice_from_raw_parts_mut(self.as_mut_ptr().add(len), remaining_len);
@@ -143,6 +198,9 @@ pub unsafe trait Vec: SliceOwner {
         core::ptr::drop_in_place(s);
     }
 
+    /// # Safety
+    ///
+    /// `index` must be `<= len()` and `len()` must be `< capacity()`.
     unsafe fn insert_unchecked(&mut self, index: usize, element: Self::Item) {
         let p = self.as_mut_ptr().add(index);
         p.copy_to(p.add(1), self.len().unchecked_sub(index));
@@ -176,19 +234,168 @@ pub unsafe trait Vec: SliceOwner {
         }
     }
 
-    fn drain<R>(&mut self, range: R) -> Drain<Self>
+    fn try_insert(
+        &mut self,
+        index: usize,
+        element: Self::Item,
+    ) -> Result<(), CapacityError<Self::Item>> {
+        check_insertion_index(index, self.len());
+        if self.try_reserve(1).is_err() {
+            return Err(CapacityError::new(element));
+        }
+        unsafe { self.insert_unchecked(index, element) };
+        Ok(())
+    }
+
+    /// Inserts clones of `slice` at `index`, shifting the following items
+    /// only once.
+    fn insert_slice(&mut self, index: usize, slice: &[Self::Item])
+    where
+        Self::Item: Clone,
+    {
+        check_insertion_index(index, self.len());
+        self.reserve(slice.len());
+        unsafe { insert_cloned_unchecked(self, index, slice) };
+    }
+
+    /// Like [`insert_slice`](Vec::insert_slice), but leaves the container
+    /// untouched when the whole slice does not fit.
+    fn try_insert_slice(&mut self, index: usize, slice: &[Self::Item]) -> Result<(), CapacityError>
+    where
+        Self::Item: Clone,
+    {
+        check_insertion_index(index, self.len());
+        self.try_reserve(slice.len())?;
+        unsafe { insert_cloned_unchecked(self, index, slice) };
+        Ok(())
+    }
+
+    /// Inserts the items of `iter` at `index`. The lower bound of the
+    /// iterator's `size_hint` is reserved up front, so exact-size iterators
+    /// shift the following items only once.
+    fn insert_from_iter<I>(&mut self, index: usize, iter: I)
+    where
+        I: IntoIterator<Item = Self::Item>,
+    {
+        check_insertion_index(index, self.len());
+        let iter = iter.into_iter();
+        let lower = iter.size_hint().0;
+        self.reserve(lower);
+        let _ = unsafe {
+            insert_from_iter_unchecked(self, index, lower, iter, |vec, item| {
+                vec.push(item);
+                Ok(())
+            })
+        };
+    }
+
+    /// Like [`insert_from_iter`](Vec::insert_from_iter), but stops at the
+    /// first item that does not fit and returns it. The items inserted before
+    /// it are kept.
+    fn try_insert_from_iter<I>(
+        &mut self,
+        index: usize,
+        iter: I,
+    ) -> Result<(), CapacityError<Self::Item>>
+    where
+        I: IntoIterator<Item = Self::Item>,
+    {
+        check_insertion_index(index, self.len());
+        let iter = iter.into_iter();
+        let lower = iter.size_hint().0;
+        let reserved = if self.try_reserve(lower).is_ok() {
+            lower
+        } else {
+            0
+        };
+        unsafe { insert_from_iter_unchecked(self, index, reserved, iter, Vec::try_push) }
+    }
+
+    /// Drops the items in `range`, shifting the following items only once.
+    fn remove_range<R>(&mut self, range: R)
     where
         R: RangeBounds<usize>,
-        Self: Sized,
     {
         let Range { start, end } = slice_range(range, self.len());
-        drain::Drain {
-            tail_start: end,
-            tail_len: unsafe { self.len().unchecked_sub(end) },
-            iter: unsafe {
-                core::slice::from_raw_parts(self.as_ptr().add(start), end - start).iter()
-            },
-            vec: ptr::NonNull::from(self),
+        let ptr = self.as_mut_ptr();
+        let _gap = FillGapOnDrop {
+            read: end,
+            write: start,
+            vec: self,
+        };
+        unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr.add(start), end - start)) };
+    }
+
+    /// Drops the items at `indices`, which must be sorted, unique and in
+    /// bounds, compacting the rest in a single pass.
+    fn remove_indices(&mut self, indices: &[usize]) {
+        #[cold]
+        #[track_caller]
+        fn assert_failed(len: usize) -> ! {
+            panic!("removal indices should be sorted, unique and < len (is {len})");
+        }
+
+        let len = self.len();
+        let sorted = indices.windows(2).all(|pair| pair[0] < pair[1]);
+        if !sorted || indices.last().is_some_and(|&last| last >= len) {
+            assert_failed(len);
+        }
+        let Some(&first) = indices.first() else {
+            return;
+        };
+        let ptr = self.as_mut_ptr();
+        let mut gap = FillGapOnDrop {
+            read: first,
+            write: first,
+            vec: self,
+        };
+        for &index in indices {
+            unsafe {
+                let kept = index - gap.read;
+                ptr.add(gap.read).copy_to(ptr.add(gap.write), kept);
+                gap.write += kept;
+                gap.read = index + 1;
+                ptr::drop_in_place(ptr.add(index));
+            }
+        }
+    }
+
+    fn drain<R>(&mut self, range: R) -> Drain<'_, Self>
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let Range { start, end } = slice_range(range, len);
+        unsafe {
+            // Leak amplification: if the `Drain` is forgotten, the drained
+            // items and the tail are lost instead of being dropped twice.
+            self.set_len(start);
+            drain::Drain {
+                tail_start: end,
+                tail_len: len.unchecked_sub(end),
+                iter: core::slice::from_raw_parts(self.as_ptr().add(start), end - start).iter(),
+                vec: ptr::NonNull::from(self),
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    /// Moves the items in `range` to the end of `other` with a single copy,
+    /// closing the gap they leave behind.
+    fn drain_into<R, V>(&mut self, range: R, other: &mut V)
+    where
+        R: RangeBounds<usize>,
+        V: Vec<Item = Self::Item> + ?Sized,
+    {
+        let len = self.len();
+        let Range { start, end } = slice_range(range, len);
+        let count = end - start;
+        other.reserve(count);
+        unsafe {
+            let src = self.as_mut_ptr().add(start);
+            extend_vec_with_raw_parts_unchecked(other, src, count);
+            src.copy_from(src.add(count), len - end);
+            self.set_len(len - count);
         }
     }
 
@@ -308,26 +515,6 @@ pub unsafe trait Vec: SliceOwner {
         if first_duplicate_idx == len {
             return;
         }
-        struct FillGapOnDrop<'a, V: Vec + ?Sized> {
-            read: usize,
-            write: usize,
-            vec: &'a mut V,
-        }
-
-        impl<'a, V: Vec + ?Sized> Drop for FillGapOnDrop<'a, V> {
-            fn drop(&mut self) {
-                unsafe {
-                    let ptr = self.vec.as_mut_ptr();
-                    let len = self.vec.len();
-                    let items_left = len.wrapping_sub(self.read);
-                    let dropped_ptr = ptr.add(self.write);
-                    let valid_ptr = ptr.add(self.read);
-                    valid_ptr.copy_to(dropped_ptr, items_left);
-                    let dropped = self.read.wrapping_sub(self.write);
-                    self.vec.set_len(len - dropped);
-                }
-            }
-        }
         let mut gap = FillGapOnDrop {
             read: first_duplicate_idx + 1,
             write: first_duplicate_idx,
@@ -356,17 +543,149 @@ pub unsafe trait Vec: SliceOwner {
         }
     }
 
+    fn dedup(&mut self)
+    where
+        Self::Item: PartialEq,
+        Self: Sized,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    /// Like [`dedup`](Vec::dedup), returning how many items were removed.
+    fn dedup_count(&mut self) -> usize
+    where
+        Self::Item: PartialEq,
+        Self: Sized,
+    {
+        let len = self.len();
+        self.dedup();
+        len - self.len()
+    }
+
+    /// Like [`dedup_by`](Vec::dedup_by), but moves the removed items to the
+    /// end of `sink`, in order, instead of dropping them. Returns how many
+    /// items were moved.
+    ///
+    /// If `same_bucket` panics or `sink` runs out of capacity, the items not
+    /// processed yet stay in `self`.
+    fn dedup_by_returning<F, S>(&mut self, mut same_bucket: F, sink: &mut S) -> usize
+    where
+        F: FnMut(&mut Self::Item, &mut Self::Item) -> bool,
+        S: Vec<Item = Self::Item> + ?Sized,
+    {
+        let len = self.len();
+        if len <= 1 {
+            return 0;
+        }
+        let start = self.as_mut_ptr();
+        let mut gap = FillGapOnDrop {
+            read: 1,
+            write: 1,
+            vec: self,
+        };
+        unsafe {
+            while gap.read < len {
+                let read_ptr = start.add(gap.read);
+                let prev_ptr = start.add(gap.write - 1);
+                if same_bucket(&mut *read_ptr, &mut *prev_ptr) {
+                    sink.reserve(1);
+                    gap.read += 1;
+                    sink.push_unchecked(read_ptr.read());
+                } else {
+                    if gap.read != gap.write {
+                        read_ptr.copy_to_nonoverlapping(start.add(gap.write), 1);
+                    }
+                    gap.write += 1;
+                    gap.read += 1;
+                }
+            }
+            let kept = gap.write;
+            gap.vec.set_len(kept);
+            core::mem::forget(gap);
+            len - kept
+        }
+    }
+
+    /// Moves every item matching `pred` before the ones that do not and
+    /// returns how many matched. The relative order of items is not kept.
+    fn partition_in_place<F>(&mut self, mut pred: F) -> usize
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        let items = self.as_mut_slice();
+        let mut front = 0;
+        let mut back = items.len();
+        loop {
+            while front < back && pred(&items[front]) {
+                front += 1;
+            }
+            while front < back && !pred(&items[back - 1]) {
+                back -= 1;
+            }
+            if front == back {
+                return front;
+            }
+            items.swap(front, back - 1);
+            front += 1;
+            back -= 1;
+        }
+    }
+
+    /// Moves consecutive repeated items to the end without dropping them and
+    /// returns the deduplicated prefix and the duplicates. The length of the
+    /// container does not change.
+    fn partition_dedup(&mut self) -> (&mut [Self::Item], &mut [Self::Item])
+    where
+        Self::Item: PartialEq,
+    {
+        self.partition_dedup_by(|a, b| a == b)
+    }
+
+    fn partition_dedup_by<F>(
+        &mut self,
+        mut same_bucket: F,
+    ) -> (&mut [Self::Item], &mut [Self::Item])
+    where
+        F: FnMut(&mut Self::Item, &mut Self::Item) -> bool,
+    {
+        let items = self.as_mut_slice();
+        let len = items.len();
+        if len <= 1 {
+            return (items, &mut []);
+        }
+        let ptr = items.as_mut_ptr();
+        let mut next_read = 1;
+        let mut next_write = 1;
+        // Only swaps are performed, so a panicking `same_bucket` leaves every
+        // item in place.
+        unsafe {
+            while next_read < len {
+                let ptr_read = ptr.add(next_read);
+                let prev_ptr_write = ptr.add(next_write - 1);
+                if !same_bucket(&mut *ptr_read, &mut *prev_ptr_write) {
+                    if next_read != next_write {
+                        core::ptr::swap(ptr_read, prev_ptr_write.add(1));
+                    }
+                    next_write += 1;
+                }
+                next_read += 1;
+            }
+        }
+        items.split_at_mut(next_write)
+    }
+
     fn resize(&mut self, new_len: usize, value: Self::Item)
     where
         Self::Item: Clone,
     {
-        if new_len > self.len() {
-            let last = unsafe { new_len.unchecked_sub(1) };
-            for i in self.len()..last {
-                unsafe { self.as_mut_ptr().add(i).write(value.clone()) };
+        let len = self.len();
+        if new_len > len {
+            self.reserve(new_len - len);
+            let mut guard = SetLenOnDrop::new(self);
+            while guard.len < new_len - 1 {
+                unsafe { guard.push_unchecked(value.clone()) };
             }
-            unsafe { self.as_mut_ptr().add(last).write(value) };
-            unsafe { self.set_len(new_len) };
+            unsafe { guard.push_unchecked(value) };
         } else {
             unsafe { self.truncate_unchecked(new_len) };
         }
@@ -377,11 +696,13 @@ pub unsafe trait Vec: SliceOwner {
         F: FnMut() -> Self::Item,
         Self: Sized,
     {
-        if new_len > self.len() {
-            for i in self.len()..new_len {
-                unsafe { self.as_mut_ptr().add(i).write(f()) };
+        let len = self.len();
+        if new_len > len {
+            self.reserve(new_len - len);
+            let mut guard = SetLenOnDrop::new(self);
+            while guard.len < new_len {
+                unsafe { guard.push_unchecked(f()) };
             }
-            unsafe { self.set_len(new_len) };
         } else {
             unsafe { self.truncate_unchecked(new_len) };
         }
@@ -404,11 +725,17 @@ pub unsafe trait Vec: SliceOwner {
         }
     }
 
+    /// # Safety
+    ///
+    /// There must be room for `slice.len()` more items.
     unsafe fn extend_from_slice_unchecked(&mut self, slice: &[Self::Item])
     where
         Self::Item: Clone,
     {
-        extend_vec_with_raw_parts_unchecked(self, slice.as_ptr(), slice.len());
+        let mut guard = SetLenOnDrop::new(self);
+        for item in slice {
+            guard.push_unchecked(item.clone());
+        }
     }
 
     fn extend_from_slice(&mut self, slice: &[Self::Item])
@@ -426,9 +753,116 @@ pub unsafe trait Vec: SliceOwner {
         Self::Item: Clone,
     {
         let range = slice_range(src, self.len());
-        let len = range.len();
-        self.reserve(len);
-        unsafe { extend_vec_with_raw_parts_unchecked(self, self.as_ptr().add(range.start), len) };
+        self.reserve(range.len());
+        let mut guard = SetLenOnDrop::new(self);
+        for i in range {
+            unsafe {
+                let item = (*guard.vec.as_ptr().add(i)).clone();
+                guard.push_unchecked(item);
+            }
+        }
+    }
+
+    /// Sorts the items without allocating, keeping equal items in order.
+    ///
+    /// Spare capacity is used as scratch space; with none left the sort falls
+    /// back to merging by rotations, which takes `O(n log² n)` time.
+    #[inline]
+    fn sort_stable_in_place(&mut self)
+    where
+        Self::Item: Ord,
+    {
+        self.sort_stable_in_place_by(Ord::cmp);
+    }
+
+    fn sort_stable_in_place_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        let len = self.len();
+        let buf_len = self.capacity() - len;
+        unsafe {
+            let base = self.as_mut_ptr();
+            sort::stable_sort(
+                core::slice::from_raw_parts_mut(base, len),
+                base.add(len),
+                buf_len,
+                &mut |a, b| compare(a, b) == Ordering::Less,
+            );
+        }
+    }
+
+    #[inline]
+    fn sort_stable_in_place_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: Ord,
+    {
+        self.sort_stable_in_place_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Stable LSD radix sort, using spare capacity as scratch.
+    ///
+    /// With fewer than `len()` spare slots this falls back to
+    /// [`sort_stable_in_place`](Vec::sort_stable_in_place).
+    #[inline]
+    fn radix_sort(&mut self)
+    where
+        Self::Item: RadixKey,
+    {
+        self.radix_sort_by_key(|item| *item);
+    }
+
+    /// # Panics
+    ///
+    /// May panic if `f` does not give an item the same key every time.
+    fn radix_sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: RadixKey,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let len = self.len();
+        if self.capacity() - len < len {
+            self.sort_stable_in_place_by_key(f);
+            return;
+        }
+        unsafe {
+            let base = self.as_mut_ptr();
+            radix::radix_sort(base, len, base.add(len), &mut f);
+        }
+    }
+
+    /// Stable LSD radix sort, using the spare capacity of `scratch` as
+    /// scratch. The items of `scratch` are left untouched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scratch` can not make room for `len()` more items.
+    #[inline]
+    fn radix_sort_with<S>(&mut self, scratch: &mut S)
+    where
+        Self::Item: RadixKey,
+        S: Vec<Item = Self::Item> + ?Sized,
+    {
+        self.radix_sort_by_key_with(|item| *item, scratch);
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `scratch` can not make room for `len()` more items, and may
+    /// panic if `f` does not give an item the same key every time.
+    fn radix_sort_by_key_with<K, F, S>(&mut self, mut f: F, scratch: &mut S)
+    where
+        K: RadixKey,
+        F: FnMut(&Self::Item) -> K,
+        S: Vec<Item = Self::Item> + ?Sized,
+    {
+        let len = self.len();
+        scratch.reserve(len);
+        unsafe {
+            let buf = scratch.as_mut_ptr().add(scratch.len());
+            radix::radix_sort(self.as_mut_ptr(), len, buf, &mut f);
+        }
     }
 }
 
@@ -464,6 +898,12 @@ unsafe impl<T> Vec for std::vec::Vec<T> {
         other
     }
 
+    #[inline(always)]
+    fn try_reserve(&mut self, additional: usize) -> Result<(), CapacityError> {
+        self.try_reserve(additional)
+            .map_err(|_| CapacityError::new(()))
+    }
+
     #[inline(always)]
     fn push(&mut self, item: Self::Item) {
         self.push(item)
@@ -540,6 +980,14 @@ unsafe impl<T> Vec for std::vec::Vec<T> {
         self.dedup_by(same_bucket);
     }
 
+    #[inline(always)]
+    fn dedup(&mut self)
+    where
+        Self::Item: PartialEq,
+    {
+        self.dedup();
+    }
+
     #[inline(always)]
     fn resize(&mut self, new_len: usize, value: Self::Item)
     where
@@ -587,15 +1035,21 @@ unsafe impl<T> Vec for std::vec::Vec<T> {
 }
 
 pub trait VecUnsized: Vec {
+    /// # Safety
+    ///
+    /// See [`Vec::split_off_unchecked`].
     unsafe fn split_off_unchecked(&mut self, at: usize) -> Self;
 
     fn split_off(&mut self, at: usize) -> Self;
 
+    /// # Safety
+    ///
+    /// See [`Vec::append_unchecked`].
     unsafe fn append_unchecked<S: SliceOwner<Item = Self::Item>>(&mut self, other: S);
 
     fn append<S: SliceOwner<Item = Self::Item>>(&mut self, other: S);
 
-    fn drain<R>(&mut self, range: R) -> Drain<Self>
+    fn drain<R>(&mut self, range: R) -> Drain<'_, Self>
     where
         R: RangeBounds<usize>;
 
@@ -646,7 +1100,7 @@ impl<T: Vec> VecUnsized for T {
         Vec::append(self, other);
     }
     #[inline(always)]
-    fn drain<R>(&mut self, range: R) -> Drain<Self>
+    fn drain<R>(&mut self, range: R) -> Drain<'_, Self>
     where
         R: RangeBounds<usize>,
     {
@@ -705,11 +1159,185 @@ unsafe fn extend_vec_with_raw_parts_unchecked<V: Vec + ?Sized>(
     src: *const V::Item,
     count: usize,
 ) {
-    src.copy_to_nonoverlapping(this.as_mut_ptr(), this.len());
+    src.copy_to_nonoverlapping(this.as_mut_ptr().add(this.len()), count);
     this.set_len(this.len().unchecked_add(count));
 }
 
-fn slice_range<R>(range: R, len: usize) -> Range<usize>
+#[inline]
+#[track_caller]
+fn check_insertion_index(index: usize, len: usize) {
+    #[cold]
+    #[track_caller]
+    fn assert_failed(index: usize, len: usize) -> ! {
+        panic!("insertion index (is {index}) should be <= len (is {len})");
+    }
+
+    if index > len {
+        assert_failed(index, len);
+    }
+}
+
+unsafe fn insert_cloned_unchecked<V: Vec + ?Sized>(vec: &mut V, index: usize, slice: &[V::Item])
+where
+    V::Item: Clone,
+{
+    let mut gap = GapGuard::open(vec, index, slice.len());
+    for item in slice {
+        gap.write(item.clone());
+    }
+}
+
+/// Fills a gap of `lower` slots at `index` from `iter`, then appends whatever
+/// is left with `push` and rotates it into place.
+unsafe fn insert_from_iter_unchecked<V, I, P>(
+    vec: &mut V,
+    index: usize,
+    lower: usize,
+    mut iter: I,
+    mut push: P,
+) -> Result<(), CapacityError<V::Item>>
+where
+    V: Vec + ?Sized,
+    I: Iterator<Item = V::Item>,
+    P: FnMut(&mut V, V::Item) -> Result<(), CapacityError<V::Item>>,
+{
+    let at = {
+        let mut gap = GapGuard::open(vec, index, lower);
+        while gap.filled < gap.gap {
+            match iter.next() {
+                Some(item) => gap.write(item),
+                None => break,
+            }
+        }
+        index + gap.filled
+    };
+    let guard = RotateOnDrop {
+        old_len: vec.len(),
+        at,
+        vec,
+    };
+    for item in iter {
+        push(guard.vec, item)?;
+    }
+    Ok(())
+}
+
+/// Holds a gap of `gap` uninitialized slots at `index`, with the `tail_len`
+/// items that followed `index` moved past it. On drop, the first `filled`
+/// slots are committed and the tail is moved back right after them.
+struct GapGuard<'a, V: Vec + ?Sized> {
+    vec: &'a mut V,
+    index: usize,
+    filled: usize,
+    gap: usize,
+    tail_len: usize,
+}
+
+impl<'a, V: Vec + ?Sized> GapGuard<'a, V> {
+    /// The caller must ensure `index <= len` and room for `gap` more items.
+    unsafe fn open(vec: &'a mut V, index: usize, gap: usize) -> Self {
+        let tail_len = vec.len() - index;
+        vec.set_len(index);
+        let p = vec.as_mut_ptr().add(index);
+        p.copy_to(p.add(gap), tail_len);
+        Self {
+            vec,
+            index,
+            filled: 0,
+            gap,
+            tail_len,
+        }
+    }
+
+    #[inline]
+    unsafe fn write(&mut self, item: V::Item) {
+        debug_assert!(self.filled < self.gap);
+        self.vec
+            .as_mut_ptr()
+            .add(self.index + self.filled)
+            .write(item);
+        self.filled += 1;
+    }
+}
+
+impl<V: Vec + ?Sized> Drop for GapGuard<'_, V> {
+    fn drop(&mut self) {
+        unsafe {
+            let p = self.vec.as_mut_ptr().add(self.index);
+            if self.filled != self.gap {
+                p.add(self.gap).copy_to(p.add(self.filled), self.tail_len);
+            }
+            self.vec.set_len(self.index + self.filled + self.tail_len);
+        }
+    }
+}
+
+/// Rotates the items pushed after `old_len` to position `at` on drop.
+struct RotateOnDrop<'a, V: Vec + ?Sized> {
+    vec: &'a mut V,
+    at: usize,
+    old_len: usize,
+}
+
+impl<V: Vec + ?Sized> Drop for RotateOnDrop<'_, V> {
+    fn drop(&mut self) {
+        let pushed = self.vec.len() - self.old_len;
+        self.vec.as_mut_slice()[self.at..].rotate_right(pushed);
+    }
+}
+
+/// Closes the gap between `write` and `read` left by removed items, keeping
+/// every item from `read` onwards.
+struct FillGapOnDrop<'a, V: Vec + ?Sized> {
+    read: usize,
+    write: usize,
+    vec: &'a mut V,
+}
+
+impl<'a, V: Vec + ?Sized> Drop for FillGapOnDrop<'a, V> {
+    fn drop(&mut self) {
+        unsafe {
+            let ptr = self.vec.as_mut_ptr();
+            let len = self.vec.len();
+            let items_left = len.wrapping_sub(self.read);
+            let dropped_ptr = ptr.add(self.write);
+            let valid_ptr = ptr.add(self.read);
+            valid_ptr.copy_to(dropped_ptr, items_left);
+            let dropped = self.read.wrapping_sub(self.write);
+            self.vec.set_len(len - dropped);
+        }
+    }
+}
+
+/// Writes items past the end of a [`Vec`] and commits the new length on drop,
+/// so a panicking producer never leaks or double-drops.
+struct SetLenOnDrop<'a, V: Vec + ?Sized> {
+    vec: &'a mut V,
+    len: usize,
+}
+
+impl<'a, V: Vec + ?Sized> SetLenOnDrop<'a, V> {
+    #[inline]
+    fn new(vec: &'a mut V) -> Self {
+        let len = vec.len();
+        Self { vec, len }
+    }
+
+    #[inline]
+    unsafe fn push_unchecked(&mut self, item: V::Item) {
+        self.vec.as_mut_ptr().add(self.len).write(item);
+        self.len += 1;
+    }
+}
+
+impl<V: Vec + ?Sized> Drop for SetLenOnDrop<'_, V> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { self.vec.set_len(self.len) };
+    }
+}
+
+pub(crate) fn slice_range<R>(range: R, len: usize) -> Range<usize>
 where
     R: RangeBounds<usize>,
 {
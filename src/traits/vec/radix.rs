@@ -0,0 +1,78 @@
+use core::{mem, ptr};
+
+use crate::traits::RadixKey;
+
+/// LSD radix sort of the `len` items at `v`, bouncing them through the `len`
+/// uninitialized slots at `buf`. Passes over bytes every key shares are
+/// skipped.
+///
+/// Each pass copies the items instead of moving them, so the source of the
+/// current pass always holds every item once: if `key` panics, it is copied
+/// back to `v`.
+///
+/// # Panics
+///
+/// Panics if `key` gives an item a different byte while counting and while
+/// scattering, before any write past its bucket.
+///
+/// # Safety
+///
+/// `v` must hold `len` items and `buf` must be valid for writes of `len`
+/// items without overlapping `v`.
+pub(super) unsafe fn radix_sort<T, K, F>(v: *mut T, len: usize, buf: *mut T, key: &mut F)
+where
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+{
+    if mem::size_of::<T>() == 0 || len < 2 {
+        return;
+    }
+    let mut passes = CopyBackOnDrop { src: v, v, len };
+    let mut dst = buf;
+    for byte in 0..K::BYTES {
+        let src = passes.src;
+        let mut ends = [0usize; 256];
+        for i in 0..len {
+            ends[key(&*src.add(i)).radix_byte(byte) as usize] += 1;
+        }
+        if ends.contains(&len) {
+            continue;
+        }
+        let mut offsets = [0usize; 256];
+        let mut sum = 0;
+        for (offset, end) in offsets.iter_mut().zip(&mut ends) {
+            *offset = sum;
+            sum += *end;
+            *end = sum;
+        }
+        for i in 0..len {
+            let item = src.add(i);
+            let bucket = key(&*item).radix_byte(byte) as usize;
+            // Buckets are sized by the first call, so a key that changed
+            // since could overrun its own.
+            assert!(
+                offsets[bucket] < ends[bucket],
+                "radix key changed between calls"
+            );
+            ptr::copy_nonoverlapping(item, dst.add(offsets[bucket]), 1);
+            offsets[bucket] += 1;
+        }
+        passes.src = dst;
+        dst = src;
+    }
+}
+
+/// Copies the `len` items at `src` to `v` when they differ.
+struct CopyBackOnDrop<T> {
+    src: *mut T,
+    v: *mut T,
+    len: usize,
+}
+
+impl<T> Drop for CopyBackOnDrop<T> {
+    fn drop(&mut self) {
+        if self.src != self.v {
+            unsafe { ptr::copy_nonoverlapping(self.src, self.v, self.len) };
+        }
+    }
+}
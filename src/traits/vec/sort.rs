@@ -0,0 +1,128 @@
+use core::{mem, ptr};
+
+/// Runs shorter than this are sorted by insertion before merging.
+const RUN_LEN: usize = 16;
+
+/// Stable merge sort of `v`, using the `buf_len` uninitialized slots at `buf`
+/// as scratch. Merges whose left run does not fit in the scratch fall back to
+/// rotations, so no allocation ever happens.
+///
+/// Items are only ever moved, never duplicated, so a panicking `is_less`
+/// leaves `v` holding every item in some order.
+///
+/// # Safety
+///
+/// `buf` must be valid for writes of `buf_len` items and must not overlap `v`.
+pub(super) unsafe fn stable_sort<T, F>(v: &mut [T], buf: *mut T, buf_len: usize, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = v.len();
+    if mem::size_of::<T>() == 0 || len < 2 {
+        return;
+    }
+    for run in v.chunks_mut(RUN_LEN) {
+        insertion_sort(run, is_less);
+    }
+    let mut width = RUN_LEN;
+    while width < len {
+        let mut start = 0;
+        while start + width < len {
+            let end = (start + 2 * width).min(len);
+            merge(&mut v[start..end], width, buf, buf_len, is_less);
+            start = end;
+        }
+        width *= 2;
+    }
+}
+
+fn insertion_sort<T, F>(v: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    for i in 1..v.len() {
+        let mut j = i;
+        while j > 0 && is_less(&v[j], &v[j - 1]) {
+            v.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+/// Merges the sorted runs `v[..mid]` and `v[mid..]`.
+unsafe fn merge<T, F>(v: &mut [T], mid: usize, buf: *mut T, buf_len: usize, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = v.len();
+    if mid == 0 || mid == len || !is_less(&v[mid], &v[mid - 1]) {
+        return;
+    }
+    if mid <= buf_len {
+        merge_buffered(v, mid, buf, is_less);
+        return;
+    }
+    // Split both runs around a pivot, rotate the middle parts into place and
+    // merge the two halves separately.
+    let (first_cut, second_cut) = if mid >= len - mid {
+        let first_cut = mid / 2;
+        let pivot = &v[first_cut];
+        let offset = v[mid..].partition_point(|x| is_less(x, pivot));
+        (first_cut, mid + offset)
+    } else {
+        let second_cut = mid + (len - mid) / 2;
+        let pivot = &v[second_cut];
+        let first_cut = v[..mid].partition_point(|x| !is_less(pivot, x));
+        (first_cut, second_cut)
+    };
+    v[first_cut..second_cut].rotate_left(mid - first_cut);
+    let new_mid = first_cut + (second_cut - mid);
+    let (left, right) = v.split_at_mut(new_mid);
+    merge(left, first_cut, buf, buf_len, is_less);
+    merge(right, second_cut - new_mid, buf, buf_len, is_less);
+}
+
+/// Moves `v[..mid]` to `buf` and merges it forward with `v[mid..]`.
+unsafe fn merge_buffered<T, F>(v: &mut [T], mid: usize, buf: *mut T, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let base = v.as_mut_ptr();
+    let right_end = base.add(v.len());
+    ptr::copy_nonoverlapping(base, buf, mid);
+    let mut hole = MergeHole {
+        start: buf,
+        end: buf.add(mid),
+        dest: base,
+    };
+    let mut right = base.add(mid);
+    // `dest` always stays behind `right`, as the gap holds the buffered items.
+    while hole.start < hole.end && right < right_end {
+        let take_right = is_less(&*right, &*hole.start);
+        let src = if take_right { right } else { hole.start };
+        ptr::copy_nonoverlapping(src, hole.dest, 1);
+        hole.dest = hole.dest.add(1);
+        if take_right {
+            right = right.add(1);
+        } else {
+            hole.start = hole.start.add(1);
+        }
+    }
+}
+
+/// The buffered items `start..end` not merged yet; dropping it moves them to
+/// `dest`, filling the gap in the slice.
+struct MergeHole<T> {
+    start: *mut T,
+    end: *mut T,
+    dest: *mut T,
+}
+
+impl<T> Drop for MergeHole<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let len = self.end.offset_from(self.start) as usize;
+            ptr::copy_nonoverlapping(self.start, self.dest, len);
+        }
+    }
+}
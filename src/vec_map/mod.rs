@@ -0,0 +1,582 @@
+use core::{
+    borrow::Borrow,
+    fmt,
+    marker::PhantomData,
+    mem,
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use crate::traits::vec::{CapacityError, Vec};
+
+/// How a [`VecMap`] lays out its entries and finds keys.
+pub trait Order<Q: ?Sized> {
+    /// Index of the entry whose key equals `key`, or the index a new entry
+    /// with that key should be inserted at.
+    fn search<K: Borrow<Q>, V>(entries: &[(K, V)], key: &Q) -> Result<usize, usize>;
+}
+
+/// Entries are kept in insertion order and looked up linearly.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Insertion;
+
+impl<Q: Eq + ?Sized> Order<Q> for Insertion {
+    #[inline]
+    fn search<K: Borrow<Q>, V>(entries: &[(K, V)], key: &Q) -> Result<usize, usize> {
+        entries
+            .iter()
+            .position(|(k, _)| k.borrow() == key)
+            .ok_or(entries.len())
+    }
+}
+
+/// Entries are kept sorted by key and looked up by binary search.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Sorted;
+
+impl<Q: Ord + ?Sized> Order<Q> for Sorted {
+    #[inline]
+    fn search<K: Borrow<Q>, V>(entries: &[(K, V)], key: &Q) -> Result<usize, usize> {
+        entries.binary_search_by(|(k, _)| k.borrow().cmp(key))
+    }
+}
+
+/// A map storing its entries in a [`Vec`] implementor `S`.
+///
+/// `O` selects whether entries stay in insertion ([`Insertion`]) or key
+/// ([`Sorted`]) order. Meant for small maps, where a scan over a contiguous
+/// buffer beats hashing.
+pub struct VecMap<K, V, S, O = Insertion> {
+    entries: S,
+    _marker: PhantomData<fn() -> (K, V)>,
+    _order: PhantomData<O>,
+}
+
+impl<K, V, S: Vec<Item = (K, V)> + Default, O> VecMap<K, V, S, O> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            entries: S::default(),
+            _marker: PhantomData,
+            _order: PhantomData,
+        }
+    }
+}
+
+impl<K, V, S: Vec<Item = (K, V)>, O> VecMap<K, V, S, O> {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[(K, V)] {
+        self.entries.as_slice()
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.entries
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.entries.retain_mut(|(k, v)| f(k, v));
+    }
+
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter(self.entries.as_slice().iter())
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut(self.entries.as_mut_slice().iter_mut())
+    }
+
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys(self.entries.as_slice().iter())
+    }
+
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values(self.entries.as_slice().iter())
+    }
+
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut(self.entries.as_mut_slice().iter_mut())
+    }
+
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        O: Order<Q>,
+    {
+        self.get_key_value(key).map(|(_, v)| v)
+    }
+
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        O: Order<Q>,
+    {
+        let index = O::search(self.entries.as_slice(), key).ok()?;
+        let (k, v) = &self.entries.as_slice()[index];
+        Some((k, v))
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        O: Order<Q>,
+    {
+        let index = O::search(self.entries.as_slice(), key).ok()?;
+        Some(&mut self.entries.as_mut_slice()[index].1)
+    }
+
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        O: Order<Q>,
+    {
+        O::search(self.entries.as_slice(), key).is_ok()
+    }
+
+    /// Inserts `value` under `key`, returning the value it replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is new and the backing vector can not grow.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        O: Order<K>,
+    {
+        match O::search(self.entries.as_slice(), &key) {
+            Ok(index) => Some(mem::replace(
+                &mut self.entries.as_mut_slice()[index].1,
+                value,
+            )),
+            Err(index) => {
+                self.entries.insert(index, (key, value));
+                None
+            }
+        }
+    }
+
+    /// Like [`insert`](VecMap::insert), but gives the entry back instead of
+    /// panicking when it does not fit.
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, CapacityError<(K, V)>>
+    where
+        O: Order<K>,
+    {
+        match O::search(self.entries.as_slice(), &key) {
+            Ok(index) => Ok(Some(mem::replace(
+                &mut self.entries.as_mut_slice()[index].1,
+                value,
+            ))),
+            Err(index) => self.entries.try_insert(index, (key, value)).map(|()| None),
+        }
+    }
+
+    /// Removes the entry for `key`, keeping the order of the others.
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        O: Order<Q>,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        O: Order<Q>,
+    {
+        let index = O::search(self.entries.as_slice(), key).ok()?;
+        Some(self.entries.remove(index))
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S>
+    where
+        O: Order<K>,
+    {
+        match O::search(self.entries.as_slice(), &key) {
+            Ok(index) => Entry::Occupied(OccupiedEntry {
+                entries: &mut self.entries,
+                index,
+            }),
+            Err(index) => Entry::Vacant(VacantEntry {
+                entries: &mut self.entries,
+                index,
+                key,
+            }),
+        }
+    }
+
+    /// Inserts every pair of `iter`, stopping at the first one that does not
+    /// fit and returning it.
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), CapacityError<(K, V)>>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        O: Order<K>,
+    {
+        for (key, value) in iter {
+            self.try_insert(key, value)?;
+        }
+        Ok(())
+    }
+
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, CapacityError<(K, V)>>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        S: Default,
+        O: Order<K>,
+    {
+        let mut map = Self::new();
+        map.try_extend(iter)?;
+        Ok(map)
+    }
+}
+
+/// A view into a single entry of a [`VecMap`].
+pub enum Entry<'a, K, V, S: Vec<Item = (K, V)>> {
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    Vacant(VacantEntry<'a, K, V, S>),
+}
+
+pub struct OccupiedEntry<'a, K, V, S: Vec<Item = (K, V)>> {
+    entries: &'a mut S,
+    index: usize,
+}
+
+pub struct VacantEntry<'a, K, V, S: Vec<Item = (K, V)>> {
+    entries: &'a mut S,
+    index: usize,
+    key: K,
+}
+
+impl<'a, K: 'a, V: 'a, S: Vec<Item = (K, V)>> Entry<'a, K, V, S> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K: 'a, V: 'a, S: Vec<Item = (K, V)>> OccupiedEntry<'a, K, V, S> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.entries.as_slice()[self.index].0
+    }
+
+    #[inline]
+    pub fn get(&self) -> &V {
+        &self.entries.as_slice()[self.index].1
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.entries.as_mut_slice()[self.index].1
+    }
+
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.entries.as_mut_slice()[self.index].1
+    }
+
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    #[inline]
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    #[inline]
+    pub fn remove_entry(self) -> (K, V) {
+        self.entries.remove(self.index)
+    }
+}
+
+impl<'a, K: 'a, V: 'a, S: Vec<Item = (K, V)>> VacantEntry<'a, K, V, S> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    #[inline]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the backing vector can not grow.
+    #[inline]
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.entries.insert(self.index, (self.key, value));
+        &mut self.entries.as_mut_slice()[self.index].1
+    }
+
+    pub fn try_insert(self, value: V) -> Result<&'a mut V, CapacityError<(K, V)>> {
+        self.entries.try_insert(self.index, (self.key, value))?;
+        Ok(&mut self.entries.as_mut_slice()[self.index].1)
+    }
+}
+
+impl<K, V, S: Vec<Item = (K, V)> + Default, O> Default for VecMap<K, V, S, O> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, S: Vec<Item = (K, V)> + Clone, O> Clone for VecMap<K, V, S, O> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            _marker: PhantomData,
+            _order: PhantomData,
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S: Vec<Item = (K, V)>, O> fmt::Debug for VecMap<K, V, S, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, S, O, S2, O2> PartialEq<VecMap<K, V, S2, O2>> for VecMap<K, V, S, O>
+where
+    V: PartialEq,
+    S: Vec<Item = (K, V)>,
+    S2: Vec<Item = (K, V)>,
+    O2: Order<K>,
+{
+    /// Maps are equal when they hold the same entries, in any order.
+    fn eq(&self, other: &VecMap<K, V, S2, O2>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(k, v)| other.get(k).is_some_and(|other| v == other))
+    }
+}
+
+impl<K, V: Eq, S: Vec<Item = (K, V)>, O: Order<K>> Eq for VecMap<K, V, S, O> {}
+
+impl<K, V, S, O, Q> Index<&Q> for VecMap<K, V, S, O>
+where
+    K: Borrow<Q>,
+    Q: ?Sized,
+    S: Vec<Item = (K, V)>,
+    O: Order<Q>,
+{
+    type Output = V;
+
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+impl<K, V, S, O, Q> IndexMut<&Q> for VecMap<K, V, S, O>
+where
+    K: Borrow<Q>,
+    Q: ?Sized,
+    S: Vec<Item = (K, V)>,
+    O: Order<Q>,
+{
+    #[inline]
+    fn index_mut(&mut self, key: &Q) -> &mut V {
+        self.get_mut(key).expect("no entry found for key")
+    }
+}
+
+impl<K, V, S: Vec<Item = (K, V)>, O: Order<K>> Extend<(K, V)> for VecMap<K, V, S, O> {
+    /// # Panics
+    ///
+    /// Panics if the backing vector can not grow; see
+    /// [`try_extend`](VecMap::try_extend).
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V, S, O> FromIterator<(K, V)> for VecMap<K, V, S, O>
+where
+    S: Vec<Item = (K, V)> + Default,
+    O: Order<K>,
+{
+    /// # Panics
+    ///
+    /// Panics if the backing vector can not grow; see
+    /// [`try_from_iter`](VecMap::try_from_iter).
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V, S, O> IntoIterator for VecMap<K, V, S, O>
+where
+    S: Vec<Item = (K, V)> + IntoIterator<Item = (K, V)>,
+{
+    type Item = (K, V);
+
+    type IntoIter = S::IntoIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, K, V, S: Vec<Item = (K, V)>, O> IntoIterator for &'a VecMap<K, V, S, O> {
+    type Item = (&'a K, &'a V);
+
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S: Vec<Item = (K, V)>, O> IntoIterator for &'a mut VecMap<K, V, S, O> {
+    type Item = (&'a K, &'a mut V);
+
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+macro_rules! entry_iter {
+    ($(#[$attr:meta])* $name:ident<$a:lifetime>, $inner:ident, $item:ty, |$entry:pat_param| $map:expr) => {
+        $(#[$attr])*
+        pub struct $name<$a, K, V>(pub(crate) slice::$inner<$a, (K, V)>);
+
+        impl<$a, K, V> Iterator for $name<$a, K, V> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.next().map(|$entry| $map)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.size_hint()
+            }
+        }
+
+        impl<$a, K, V> DoubleEndedIterator for $name<$a, K, V> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.0.next_back().map(|$entry| $map)
+            }
+        }
+
+        impl<K, V> ExactSizeIterator for $name<'_, K, V> {}
+
+        impl<K, V> core::iter::FusedIterator for $name<'_, K, V> {}
+    };
+}
+
+entry_iter!(
+    /// Iterator over the entries of a [`VecMap`].
+    Iter<'a>, Iter, (&'a K, &'a V), |(k, v)| (k, v)
+);
+entry_iter!(
+    /// Iterator over the entries of a [`VecMap`], with mutable values.
+    IterMut<'a>, IterMut, (&'a K, &'a mut V), |(k, v)| (&*k, v)
+);
+entry_iter!(
+    /// Iterator over the keys of a [`VecMap`].
+    Keys<'a>, Iter, &'a K, |(k, _)| k
+);
+entry_iter!(
+    /// Iterator over the values of a [`VecMap`].
+    Values<'a>, Iter, &'a V, |(_, v)| v
+);
+entry_iter!(
+    /// Iterator over the values of a [`VecMap`], mutably.
+    ValuesMut<'a>, IterMut, &'a mut V, |(_, v)| v
+);
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
@@ -0,0 +1,85 @@
+use std::{
+    cell::Cell,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use no_std_collections::{
+    binary_heap::PeekMut,
+    testing::{Tracked, Tracker},
+    traits::compare::{ByKey, Reversed},
+    BinaryHeap, StackVec,
+};
+
+#[test]
+fn pops_greatest_first() {
+    let mut heap = BinaryHeap::<StackVec<u32, 8>>::new();
+    for item in [5, 1, 8, 3, 9, 2] {
+        heap.push(item);
+    }
+    assert_eq!(heap.peek(), Some(&9));
+    assert_eq!(heap.pop(), Some(9));
+    assert_eq!(heap.pop(), Some(8));
+    assert_eq!(heap.into_sorted_vec(), [1, 2, 3, 5]);
+
+    let heap = BinaryHeap::from_vec(vec![4, 10, 3, 7, 7, 1, 12, 0]);
+    assert_eq!(heap.len(), 8);
+    assert!(heap.clone().drain_sorted().eq([12, 10, 7, 7, 4, 3, 1, 0]));
+    assert_eq!(heap.into_sorted_vec(), [0, 1, 3, 4, 7, 7, 10, 12]);
+}
+
+#[test]
+fn comparators_select_the_top() {
+    let mut min = BinaryHeap::<StackVec<u32, 8>, Reversed>::with_comparator(Reversed::default());
+    min.extend([5, 1, 8, 3]);
+    assert_eq!(min.pop(), Some(1));
+    assert_eq!(min.peek(), Some(&3));
+
+    let deadlines: BinaryHeap<Vec<(u32, &str)>, _> = BinaryHeap::from_vec_by(
+        vec![(30, "c"), (10, "a"), (20, "b")],
+        ByKey::new(|&(at, _): &(u32, &str)| std::cmp::Reverse(at)),
+    );
+    assert_eq!(deadlines.peek(), Some(&(10, "a")));
+}
+
+#[test]
+fn try_push_and_peek_mut() {
+    let mut heap = BinaryHeap::<StackVec<u32, 3>>::new();
+    assert!(heap.try_extend([1, 6, 4]).is_ok());
+    assert_eq!(heap.try_push(9).unwrap_err().element(), 9);
+
+    *heap.peek_mut().unwrap() = 0;
+    assert_eq!(heap.peek(), Some(&4));
+    let top = heap.peek_mut().unwrap();
+    assert_eq!(PeekMut::pop(top), 4);
+    assert_eq!(heap.into_vec(), [1, 0]);
+}
+
+#[test]
+fn panicking_comparator_keeps_every_item() {
+    let tracker = Tracker::new();
+    let calls = Cell::new(0);
+    let cmp = |a: &Tracked, b: &Tracked| {
+        calls.set(calls.get() + 1);
+        if calls.get() == 20 {
+            panic!("comparator");
+        }
+        a.value().cmp(&b.value())
+    };
+    let mut heap = BinaryHeap::<StackVec<Tracked, 32>, _>::with_comparator(&cmp);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        for value in 0..16 {
+            heap.push(tracker.track(value));
+        }
+    }));
+    assert!(result.is_err());
+    assert_eq!(tracker.live(), heap.len() as isize);
+    let mut values: Vec<u32> = heap.iter().map(Tracked::value).collect();
+    values.sort();
+    assert!(values.iter().copied().eq(0..values.len() as u32));
+
+    let mut drain = heap.drain_sorted();
+    drain.next();
+    drop(drain);
+    assert!(heap.is_empty());
+    assert_eq!(tracker.live(), 0);
+}
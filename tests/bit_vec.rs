@@ -0,0 +1,104 @@
+use no_std_collections::{
+    bit_vec::{StdBitSet, StdBitVec},
+    StackBitSet, StackBitVec,
+};
+
+#[test]
+fn push_pop_get_set() {
+    let mut bits = StackBitVec::<2>::new();
+    assert_eq!(bits.capacity(), 2 * usize::BITS as usize);
+    for i in 0..100 {
+        bits.push(i % 3 == 0);
+    }
+    assert_eq!(bits.len(), 100);
+    assert_eq!(bits.get(99), Some(true));
+    assert_eq!(bits.get(100), None);
+    assert_eq!(bits.count_ones(), 34);
+    bits.set(1, true);
+    assert_eq!(bits.get(1), Some(true));
+    assert_eq!(bits.pop(), Some(true));
+    assert_eq!(bits.pop(), Some(false));
+    assert_eq!(bits.len(), 98);
+    assert_eq!(bits.count_ones(), 34);
+    assert_eq!(bits.count_zeros(), 64);
+
+    while bits.len() < bits.capacity() {
+        bits.push(false);
+    }
+    assert!(bits.try_push(true).unwrap_err().element());
+
+    let heap: StdBitVec = bits.iter().collect();
+    assert_eq!(heap, bits);
+    bits.truncate(3);
+    assert_eq!(format!("{bits:?}"), "[110]");
+    assert_eq!(bits.as_words(), [0b011]);
+}
+
+#[test]
+fn fill_and_iter_ones() {
+    let mut bits: StdBitVec = std::iter::repeat_n(false, 200).collect();
+    bits.fill(60..130, true);
+    bits.fill(64..=127, false);
+    assert_eq!(
+        bits.iter_ones().collect::<Vec<_>>(),
+        [60, 61, 62, 63, 128, 129]
+    );
+    bits.fill(.., true);
+    assert_eq!(bits.count_ones(), 200);
+    assert!(bits.iter().rev().all(|bit| bit));
+    bits.fill(5..5, false);
+    assert_eq!(bits.count_ones(), 200);
+}
+
+#[test]
+#[should_panic]
+fn fill_out_of_bounds_panics() {
+    let mut bits = StackBitVec::<1>::new();
+    bits.push(true);
+    bits.fill(0..2, false);
+}
+
+#[test]
+fn set_operations() {
+    let mut a: StackBitSet<2> = [1, 5, 64, 100].into_iter().collect();
+    let b: StdBitSet = [5, 7, 100, 300].into_iter().collect();
+    assert!(a.contains(64));
+    assert!(!a.contains(1000));
+    assert_eq!(a.len(), 4);
+
+    let mut union = a.clone();
+    assert!(
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| union.union_with(&b))).is_err()
+    );
+    let small: StdBitSet = [5, 7].into_iter().collect();
+    a.union_with(&small);
+    assert_eq!(a.iter().collect::<Vec<_>>(), [1, 5, 7, 64, 100]);
+
+    let mut both = a.clone();
+    both.intersect_with(&b);
+    assert_eq!(both.iter().collect::<Vec<_>>(), [5, 7, 100]);
+    assert!(both.is_subset(&a) && both.is_subset(&b) && a.is_superset(&both));
+
+    let mut only_a = a.clone();
+    only_a.difference_with(&b);
+    assert_eq!(only_a.iter().collect::<Vec<_>>(), [1, 64]);
+    assert!(only_a.is_disjoint(&b));
+
+    let mut either: StdBitSet = b.clone();
+    either.symmetric_difference_with(&a);
+    assert_eq!(either.iter().collect::<Vec<_>>(), [1, 64, 300]);
+
+    assert!(a.insert(3));
+    assert!(!a.insert(3));
+    assert!(a.remove(3));
+    assert!(!a.remove(3));
+    assert!(!a.remove(5000));
+    assert_eq!(a.try_insert(128).unwrap_err().element(), 128);
+
+    let mut trimmed = StdBitSet::new();
+    trimmed.insert(300);
+    trimmed.remove(300);
+    trimmed.insert(1);
+    assert_eq!(trimmed, [1].into_iter().collect::<StackBitSet<1>>());
+    assert_eq!(format!("{trimmed:?}"), "{1}");
+}
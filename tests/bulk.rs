@@ -0,0 +1,32 @@
+use no_std_collections::{traits::vec::CapacityError, StackVec};
+
+#[test]
+fn fixed_capacity_bulk_insertion() {
+    let mut vec = StackVec::<u8, 8>::from(&b"abc"[..]);
+    assert_eq!(vec.try_insert_slice(1, b"wxyzq"), Ok(()));
+    assert_eq!(vec, b"awxyzqbc");
+    assert_eq!(vec.try_insert_slice(0, b"!"), Err(CapacityError::new(())));
+    assert_eq!(vec.try_push(b'!'), Err(CapacityError::new(b'!')));
+    assert_eq!(
+        vec.try_insert(2, b'!').map_err(CapacityError::element),
+        Err(b'!')
+    );
+    assert_eq!(vec, b"awxyzqbc");
+
+    vec.remove_range(1..6);
+    assert_eq!(vec, b"abc");
+    // Items that fit are inserted; the first one that does not is returned.
+    let result = vec.try_insert_from_iter(1, b"1234567".iter().copied());
+    assert_eq!(result.map_err(CapacityError::element), Err(b'6'));
+    assert_eq!(vec, b"a12345bc");
+
+    vec.remove_indices(&[0, 2, 4, 7]);
+    assert_eq!(vec, b"135b");
+}
+
+#[test]
+fn insert_from_iter_without_size_hint() {
+    let mut vec = StackVec::<u32, 16>::from(&[1, 2, 3][..]);
+    vec.insert_from_iter(1, (10..14).filter(|i| i % 2 == 0));
+    assert_eq!(vec, [1, 10, 12, 2, 3]);
+}
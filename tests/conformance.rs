@@ -0,0 +1,25 @@
+use no_std_collections::{testing, StackVec};
+
+#[test]
+fn stack_vec() {
+    testing::run(StackVec::<_, 32>::new, StackVec::<_, 32>::new);
+}
+
+#[test]
+fn std_vec() {
+    testing::run(std::vec::Vec::new, std::vec::Vec::new);
+}
+
+#[test]
+fn xorshift() {
+    let mut rng = testing::XorShift::new(2);
+    let mut odd = testing::XorShift::new(3);
+    assert_eq!(rng.next_u64(), odd.next_u64());
+    assert!((0..100).all(|_| rng.below(7) < 7));
+}
+
+#[test]
+#[should_panic(expected = "empty range")]
+fn xorshift_below_zero() {
+    testing::XorShift::new(1).below(0);
+}
@@ -0,0 +1,44 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use no_std_collections::StackVec;
+
+#[test]
+fn dedup_on_stack_vec() {
+    let mut vec = StackVec::<u8, 16>::from(&b"aabbbcdde"[..]);
+    assert_eq!(vec.dedup_count(), 4);
+    assert_eq!(vec, b"abcde");
+    vec.dedup();
+    assert_eq!(vec, b"abcde");
+}
+
+#[test]
+fn dedup_by_returning_stops_when_the_sink_is_full() {
+    let mut vec = StackVec::<u8, 16>::from(&b"aabbbcdde"[..]);
+    let mut sink = StackVec::<u8, 2>::new();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        vec.dedup_by_returning(|a, b| a == b, &mut sink)
+    }));
+    assert!(result.is_err());
+    assert_eq!(sink, b"ab");
+    // Items not processed when the sink filled up are kept as they were.
+    assert_eq!(vec, b"abbcdde");
+
+    let mut sink = std::vec::Vec::new();
+    assert_eq!(vec.dedup_by_returning(|a, b| a == b, &mut sink), 2);
+    assert_eq!(vec, b"abcde");
+    assert_eq!(sink, b"bd");
+}
+
+#[test]
+fn partition_helpers_on_stack_vec() {
+    let mut vec = StackVec::<u32, 8>::from([5, 2, 7, 4, 4, 1, 8, 8]);
+    let (unique, duplicates) = vec.partition_dedup();
+    assert_eq!(unique, [5, 2, 7, 4, 1, 8]);
+    assert_eq!(duplicates.len(), 2);
+    assert_eq!(vec.len(), 8);
+
+    let small = vec.partition_in_place(|&item| item < 5);
+    assert_eq!(small, 4);
+    assert!(vec[..small].iter().all(|&item| item < 5));
+    assert!(vec[small..].iter().all(|&item| item >= 5));
+}
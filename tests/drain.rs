@@ -0,0 +1,117 @@
+use core::mem::MaybeUninit;
+
+use no_std_collections::{
+    testing::{Tracked, Tracker},
+    traits::{SliceOwner, Vec},
+    StackVec,
+};
+
+/// A `Vec` implementor that is only usable behind a pointer, as
+/// `&mut Inline<T, [MaybeUninit<T>]>`.
+struct Inline<T, B: ?Sized> {
+    len: usize,
+    _item: core::marker::PhantomData<T>,
+    buf: B,
+}
+
+impl<T, const N: usize> Inline<T, [MaybeUninit<T>; N]> {
+    fn new() -> Self {
+        Self {
+            len: 0,
+            _item: core::marker::PhantomData,
+            buf: [const { MaybeUninit::uninit() }; N],
+        }
+    }
+}
+
+unsafe impl<T> SliceOwner for Inline<T, [MaybeUninit<T>]> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn as_ptr(&self) -> *const T {
+        self.buf.as_ptr() as *const T
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.buf.as_mut_ptr() as *mut T
+    }
+}
+
+unsafe impl<T> Vec for Inline<T, [MaybeUninit<T>]> {
+    fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    unsafe fn set_len(&mut self, new_len: usize) {
+        self.len = new_len;
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        assert!(additional <= self.buf.len() - self.len, "Capacity overflow");
+    }
+
+    #[allow(dead_code)]
+    unsafe fn split_off_unchecked(&mut self, _: usize) -> Self
+    where
+        Self: Sized,
+    {
+        unreachable!()
+    }
+}
+
+impl<T, B: ?Sized> Drop for Inline<T, B> {
+    fn drop(&mut self) {
+        let items = &mut self.buf as *mut B as *mut T;
+        unsafe { core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(items, self.len)) }
+    }
+}
+
+#[test]
+fn drain_unsized_implementor() {
+    let tracker = Tracker::new();
+    let mut sized = Inline::<Tracked, [MaybeUninit<Tracked>; 8]>::new();
+    let vec: &mut Inline<Tracked, [MaybeUninit<Tracked>]> = &mut sized;
+    for i in 0..8 {
+        vec.push(tracker.track(i));
+    }
+
+    let mut drain = vec.drain(2..6);
+    assert_eq!(drain.len(), 4);
+    assert_eq!(drain.next().map(|item| item.value()), Some(2));
+    assert_eq!(drain.len(), 3);
+    drop(drain);
+
+    let values: std::vec::Vec<u32> = vec.as_slice().iter().map(Tracked::value).collect();
+    assert_eq!(values, [0, 1, 6, 7]);
+    assert_eq!(tracker.live(), 4);
+    drop(sized);
+    assert_eq!(tracker.live(), 0);
+}
+
+#[test]
+fn drain_into_other_container() {
+    let mut src = StackVec::<u8, 8>::from(&b"abcdefgh"[..]);
+    let mut dst = StackVec::<u8, 8>::from(&b"xy"[..]);
+    src.drain_into(1..4, &mut dst);
+    assert_eq!(src, b"aefgh");
+    assert_eq!(dst, b"xybcd");
+
+    let mut heap = std::vec::Vec::new();
+    src.drain_into(.., &mut heap);
+    assert!(src.is_empty());
+    assert_eq!(heap, b"aefgh");
+}
+
+#[test]
+fn drain_keep_rest_after_mutation() {
+    let mut vec = StackVec::<u32, 8>::from([1, 2, 3, 4, 5, 6, 7, 8]);
+    let mut drain = vec.drain(1..7);
+    assert_eq!(drain.next(), Some(2));
+    assert_eq!(drain.next_back(), Some(7));
+    drain.as_mut_slice().iter_mut().for_each(|item| *item *= 10);
+    drain.keep_rest();
+    assert_eq!(vec, [1, 30, 40, 50, 60, 8]);
+}
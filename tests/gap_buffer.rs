@@ -0,0 +1,174 @@
+use std::fmt::Write;
+
+use no_std_collections::{
+    testing::{Tracker, XorShift},
+    GapBuffer, GapString, StackVec,
+};
+
+#[test]
+fn edit_at_the_cursor() {
+    let mut buffer = GapBuffer::<u8, 16>::new();
+    buffer.insert_slice(b"held");
+    assert_eq!(buffer.cursor(), 4);
+    assert!(buffer.move_left());
+    buffer.insert(b'l');
+    buffer.insert(b'l');
+    assert_eq!(buffer.as_slices(), (&b"helll"[..], &b"d"[..]));
+    assert_eq!(buffer.remove_before(), Some(b'l'));
+    buffer.insert_slice(b"o wor");
+    assert_eq!(buffer.len(), 10);
+    assert_eq!(buffer.remove_after(), Some(b'd'));
+    assert_eq!(buffer.remove_after(), None);
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), b"hello wor");
+
+    buffer.set_cursor(0);
+    assert!(!buffer.move_left());
+    assert_eq!(buffer.as_slices(), (&b""[..], &b"hello wor"[..]));
+    assert_eq!(buffer.remove_before(), None);
+    assert_eq!(buffer[4], b'o');
+    buffer[0] = b'j';
+    assert_eq!(buffer.get(9), None);
+    assert_eq!(buffer.make_contiguous(), b"jello wor");
+    assert_eq!(buffer.cursor(), 9);
+}
+
+#[test]
+fn capacity_errors_leave_the_buffer_untouched() {
+    let mut buffer: GapBuffer<u32, 4> = (0..3).collect();
+    buffer.set_cursor(1);
+    assert!(buffer.try_insert_slice(&[7, 8]).is_err());
+    buffer.insert(7);
+    assert_eq!(buffer.try_insert(8).unwrap_err().element(), 8);
+    assert_eq!(buffer.as_slices(), (&[0, 7][..], &[1, 2][..]));
+}
+
+#[test]
+#[should_panic(expected = "cursor index (is 4) should be <= len (is 3)")]
+fn cursor_out_of_bounds() {
+    let mut buffer: GapBuffer<u32, 4> = (0..3).collect();
+    buffer.set_cursor(4);
+}
+
+#[test]
+fn matches_a_vec_model() {
+    let mut buffer = GapBuffer::<u32, 64>::new();
+    let mut model = Vec::new();
+    let mut cursor = 0;
+    let mut rng = XorShift::new(0x2545_f491);
+    for _ in 0..2000 {
+        let state = rng.next_u64() as u32;
+        match state % 5 {
+            0 | 1 if model.len() < 64 => {
+                buffer.insert(state);
+                model.insert(cursor, state);
+                cursor += 1;
+            }
+            2 => {
+                let expected = cursor.checked_sub(1).map(|at| {
+                    cursor = at;
+                    model.remove(at)
+                });
+                assert_eq!(buffer.remove_before(), expected);
+            }
+            3 => {
+                let expected = (cursor < model.len()).then(|| model.remove(cursor));
+                assert_eq!(buffer.remove_after(), expected);
+            }
+            _ => {
+                cursor = state as usize % (model.len() + 1);
+                buffer.set_cursor(cursor);
+            }
+        }
+        assert_eq!(buffer.cursor(), cursor);
+        let (front, back) = buffer.as_slices();
+        assert_eq!([front, back].concat(), model);
+    }
+}
+
+#[test]
+fn stack_vec_round_trip() {
+    let vec = StackVec::<u32, 8>::from(&[1, 2, 3][..]);
+    let mut buffer = GapBuffer::from(vec);
+    assert_eq!(buffer.cursor(), 3);
+    buffer.set_cursor(1);
+    buffer.insert(9);
+    let vec: StackVec<u32, 8> = buffer.into();
+    assert_eq!(vec, [1, 9, 2, 3]);
+}
+
+#[test]
+fn items_are_dropped_once() {
+    let tracker = Tracker::new();
+    let mut buffer = GapBuffer::<_, 8>::new();
+    buffer.extend((0..6).map(|i| tracker.track(i)));
+    buffer.set_cursor(2);
+    drop(buffer.remove_after());
+    drop(buffer.remove_before());
+    assert_eq!(tracker.live(), 4);
+
+    let clone_source = GapBuffer::<_, 4>::from_iter([1, 2, 3]);
+    let mut clone = clone_source.clone();
+    clone.set_cursor(1);
+    assert_eq!(clone, clone_source);
+    assert_eq!(clone.as_slices(), (&[1][..], &[2, 3][..]));
+
+    let vec = buffer.into_stack_vec();
+    assert_eq!(
+        vec.iter().map(|item| item.value()).collect::<Vec<_>>(),
+        [0, 3, 4, 5]
+    );
+    drop(vec);
+    assert_eq!(tracker.live(), 0);
+
+    let mut buffer = GapBuffer::<_, 8>::new();
+    buffer.extend((0..6).map(|i| tracker.track(i)));
+    buffer.set_cursor(3);
+    drop(buffer);
+    assert_eq!(tracker.live(), 0);
+}
+
+#[test]
+fn gap_string_moves_by_chars() {
+    let mut s = GapString::<32>::from("añb€");
+    assert_eq!(s.len(), 7);
+    assert!(s.move_left());
+    assert_eq!(s.cursor(), 4);
+    assert!(s.move_left());
+    s.insert('→');
+    assert_eq!(s.as_strs(), ("añ→", "b€"));
+    assert_eq!(s.remove_before(), Some('→'));
+    assert_eq!(s.remove_before(), Some('ñ'));
+    assert_eq!(s.remove_after(), Some('b'));
+    assert!(s.move_right());
+    assert!(!s.move_right());
+    s.insert_str(" ok");
+    assert_eq!(s, "a€ ok");
+    assert_eq!(s.to_string(), "a€ ok");
+    assert_eq!(format!("{s:?}"), "\"a€ ok\"");
+    assert!(s.is_char_boundary(1) && !s.is_char_boundary(2));
+    s.set_cursor(1);
+    assert_eq!(s.chars().rev().collect::<String>(), "ko €a");
+    assert_eq!(s.make_contiguous(), "a€ ok");
+}
+
+#[test]
+#[should_panic(expected = "cursor index (is 1) should be a char boundary")]
+fn gap_string_cursor_inside_a_char() {
+    GapString::<8>::from("ñ").set_cursor(1);
+}
+
+#[test]
+fn gap_string_capacity_and_bytes() {
+    let mut s = GapString::<4>::from("abc");
+    assert_eq!(s.try_insert('€').unwrap_err().element(), '€');
+    assert!(write!(s, "de").is_err());
+    assert_eq!(s, "abc");
+    s.insert('d');
+
+    let bytes = s.into_bytes();
+    assert_eq!(bytes, *b"abcd");
+    let mut s = GapString::from_utf8(bytes).unwrap();
+    s.clear();
+    assert!(s.is_empty());
+    assert!(GapString::from_utf8(StackVec::<u8, 4>::from(&[0xff][..])).is_err());
+}
@@ -0,0 +1,100 @@
+use no_std_collections::{
+    gen_arena::{Index, StdGenArena},
+    testing::Tracker,
+    StackGenArena,
+};
+
+#[test]
+fn stale_handles_return_none() {
+    let mut arena = StackGenArena::<&str, 2>::new();
+    let a = arena.insert("a");
+    let b = arena.insert("b");
+    assert_eq!(arena.try_insert("c").unwrap_err().element(), "c");
+    assert_eq!(arena.remove(a), Some("a"));
+    assert_eq!(arena.remove(a), None);
+
+    let c = arena.insert("c");
+    assert_eq!(c.slot(), a.slot());
+    assert_ne!(c, a);
+    assert_eq!(arena.get(a), None);
+    assert_eq!(arena.get_mut(a), None);
+    assert!(!arena.contains(a));
+    assert_eq!(arena[c], "c");
+    assert_eq!(arena[b], "b");
+    assert_eq!(arena.get(Index::from_raw_parts(9, 0)), None);
+    assert_eq!(arena.len(), 2);
+}
+
+#[test]
+fn get2_mut_borrows_disjoint_slots() {
+    let mut arena = StdGenArena::new();
+    let a = arena.insert(1);
+    let b = arena.insert(2);
+    if let (Some(a), Some(b)) = arena.get2_mut(a, b) {
+        std::mem::swap(a, b);
+    }
+    assert_eq!((arena[a], arena[b]), (2, 1));
+
+    let (first, second) = arena.get2_mut(b, Index::from_raw_parts(5, 0));
+    assert_eq!((first, second), (Some(&mut 1), None));
+    arena.remove(a);
+    assert_eq!(arena.get2_mut(a, b), (None, Some(&mut 1)));
+}
+
+#[test]
+#[should_panic]
+fn get2_mut_rejects_the_same_slot() {
+    let mut arena = StdGenArena::new();
+    let a = arena.insert(1);
+    arena.remove(a);
+    let b = arena.insert(2);
+    let _ = arena.get2_mut(a, b);
+}
+
+#[test]
+fn iteration_retain_and_clear() {
+    let tracker = Tracker::new();
+    let mut arena = StackGenArena::<_, 8>::new();
+    let handles: Vec<Index> = (0..6).map(|n| arena.insert(tracker.track(n))).collect();
+    arena.remove(handles[1]);
+    assert_eq!(
+        arena
+            .iter()
+            .map(|(index, value)| (index, value.value()))
+            .collect::<Vec<_>>(),
+        [0, 2, 3, 4, 5].map(|n| (handles[n], n as u32))
+    );
+
+    arena.retain(|_, value| value.value() % 2 == 0);
+    assert_eq!(tracker.live(), 3);
+    assert_eq!(arena.iter_mut().len(), 3);
+
+    let mut drain = arena.drain();
+    assert_eq!(drain.next().map(|(index, _)| index), Some(handles[0]));
+    drop(drain);
+    assert_eq!(tracker.live(), 0);
+    assert!(arena.is_empty());
+    for handle in &handles {
+        assert!(!arena.contains(*handle));
+    }
+
+    let fresh = arena.insert(tracker.track(7));
+    assert!(!handles.contains(&fresh));
+    arena.clear();
+    assert_eq!(tracker.live(), 0);
+    assert!(!arena.contains(fresh));
+}
+
+#[test]
+fn iter_clones_without_cloning_values() {
+    struct Opaque(u32);
+    let mut arena = StackGenArena::<Opaque, 4>::new();
+    let handles: Vec<Index> = (0..3).map(|n| arena.insert(Opaque(n))).collect();
+    arena.remove(handles[0]);
+    let iter = arena.iter();
+    assert!(iter
+        .clone()
+        .map(|(index, value)| (index, value.0))
+        .eq([(handles[1], 1), (handles[2], 2)]));
+    assert_eq!(iter.len(), 2);
+}
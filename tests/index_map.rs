@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use no_std_collections::{
+    index_map::{StackIndexMap, StdIndexMap},
+    testing::XorShift,
+};
+
+#[test]
+fn keeps_insertion_order() {
+    let mut map = StdIndexMap::<&str, u32>::new();
+    for (value, key) in ["c", "a", "d", "b"].into_iter().enumerate() {
+        map.insert(key, value as u32);
+    }
+    assert_eq!(map.insert("a", 10), Some(1));
+    assert!(map.keys().eq(&["c", "a", "d", "b"]));
+    assert_eq!(map.get_index_of("d"), Some(2));
+    assert_eq!(map.get_index(1), Some((&"a", &10)));
+    assert_eq!(map.first(), Some((&"c", &0)));
+    assert_eq!(map.last(), Some((&"b", &3)));
+
+    assert_eq!(map.swap_remove("c"), Some(0));
+    assert!(map.keys().eq(&["b", "a", "d"]));
+    assert_eq!(map.shift_remove("b"), Some(3));
+    assert!(map.keys().eq(&["a", "d"]));
+    map.sort_keys();
+    assert_eq!(format!("{map:?}"), r#"{"a": 10, "d": 2}"#);
+}
+
+#[test]
+fn removals_keep_the_hash_index_consistent() {
+    let mut map = StdIndexMap::<u32, u32>::new();
+    let mut model: Vec<(u32, u32)> = Vec::new();
+    let mut rng = XorShift::new(0x9e37_79b9);
+    for step in 0..4000 {
+        let state = rng.next_u64() as u32;
+        let key = state % 64;
+        match state % 5 {
+            0 => {
+                let index = model.iter().position(|&(k, _)| k == key);
+                assert_eq!(map.swap_remove(&key), index.map(|i| model.swap_remove(i).1));
+            }
+            1 => {
+                let index = model.iter().position(|&(k, _)| k == key);
+                assert_eq!(map.shift_remove(&key), index.map(|i| model.remove(i).1));
+            }
+            _ => {
+                let (index, old) = map.insert_full(key, step);
+                match model.iter().position(|&(k, _)| k == key) {
+                    Some(i) => {
+                        assert_eq!((index, old), (i, Some(model[i].1)));
+                        model[i].1 = step;
+                    }
+                    None => {
+                        assert_eq!((index, old), (model.len(), None));
+                        model.push((key, step));
+                    }
+                }
+            }
+        }
+        assert_eq!(map.as_slice(), model.as_slice());
+        for (i, (key, _)) in model.iter().enumerate() {
+            assert_eq!(map.get_index_of(key), Some(i));
+        }
+    }
+}
+
+#[test]
+fn stack_map_falls_back_to_linear_search() {
+    // The index can hold 16 slots: enough for up to 8 entries hashed, which
+    // are searched linearly anyway, so it is never used past that.
+    let mut map = StackIndexMap::<u32, u32, 20, 16>::new();
+    assert!(map.try_extend((0..20).map(|key| (key, key * 2))).is_ok());
+    assert_eq!(map.try_insert(20, 0).unwrap_err().element(), (20, 0));
+    assert_eq!(map.try_insert(3, 0), Ok(Some(6)));
+    for key in 0..20 {
+        assert!(map.contains_key(&key));
+    }
+
+    let mut map = StackIndexMap::<u32, u32, 20, 64>::new();
+    map.extend((0..20).rev().map(|key| (key, key)));
+    assert_eq!(map.pop(), Some((0, 0)));
+    map.retain(|key, _| key % 3 != 0);
+    map.sort_unstable_by(|k1, _, k2, _| k1.cmp(k2));
+    assert!(map.keys().copied().eq((1..20).filter(|key| key % 3 != 0)));
+    for (index, key) in map.keys().enumerate() {
+        assert_eq!(map.get_index_of(key), Some(index));
+    }
+}
+
+#[test]
+fn drain_rebuilds_the_index() {
+    let mut map: StdIndexMap<u32, String> = (0..32).map(|key| (key, key.to_string())).collect();
+    let mut drained = map.drain(4..28);
+    assert_eq!(drained.next(), Some((4, "4".to_owned())));
+    assert_eq!(drained.next_back(), Some((27, "27".to_owned())));
+    drop(drained);
+    assert_eq!(map.len(), 8);
+    for (index, key) in [0, 1, 2, 3, 28, 29, 30, 31].iter().enumerate() {
+        assert_eq!(map.get_index_of(key), Some(index));
+    }
+    assert_eq!(map[&30], "30");
+    map.extend((100..120).map(|key| (key, String::new())));
+    assert_eq!(map.get_index_of(&110), Some(18));
+
+    let expected: HashMap<u32, String> = map.clone().into_iter().collect();
+    assert_eq!(expected.len(), map.len());
+    let reversed: StdIndexMap<u32, String> = map.clone().into_iter().rev().collect();
+    assert_eq!(map, reversed);
+}
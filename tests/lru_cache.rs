@@ -0,0 +1,128 @@
+use no_std_collections::{
+    testing::{Tracker, XorShift},
+    LruCache,
+};
+
+#[test]
+fn evicts_the_least_recently_used() {
+    let mut cache = LruCache::<&str, u32, 3>::new();
+    assert_eq!(cache.put("a", 1), None);
+    assert_eq!(cache.put("b", 2), None);
+    assert_eq!(cache.put("c", 3), None);
+    assert_eq!(cache.get("a"), Some(&1));
+    assert_eq!(cache.put("d", 4), Some(("b", 2)));
+    assert!(!cache.contains("b"));
+    assert_eq!(cache.len(), 3);
+    assert_eq!(
+        cache.iter().collect::<Vec<_>>(),
+        [(&"d", &4), (&"a", &1), (&"c", &3)]
+    );
+    assert_eq!(cache.iter().next_back(), Some((&"c", &3)));
+    assert_eq!(cache.peek_lru(), Some((&"c", &3)));
+}
+
+#[test]
+fn peek_keeps_the_order() {
+    let mut cache: LruCache<u32, u32, 2> = [(1, 10), (2, 20)].into_iter().collect();
+    assert_eq!(cache.peek(&1), Some(&10));
+    *cache.peek_mut(&1).unwrap() += 1;
+    assert_eq!(cache.put(3, 30), Some((1, 11)));
+    assert_eq!(cache.put(3, 31), None);
+
+    *cache.get_mut(&2).unwrap() += 1;
+    assert_eq!(cache.pop_lru(), Some((3, 31)));
+    assert_eq!(cache.pop_lru(), Some((2, 21)));
+    assert_eq!(cache.pop_lru(), None);
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn put_replaces_and_promotes() {
+    let mut cache: LruCache<u32, &str, 3> = [(1, "a"), (2, "b"), (3, "c")].into_iter().collect();
+    assert_eq!(cache.put(1, "y"), None);
+    assert_eq!(cache.replace(&1, "z"), Ok("y"));
+    assert_eq!(cache.replace(&4, "d"), Err("d"));
+    assert_eq!(
+        cache.iter().map(|(key, _)| *key).collect::<Vec<_>>(),
+        [1, 3, 2]
+    );
+    assert_eq!(cache.pop(&3), Some("c"));
+    assert_eq!(cache.pop(&3), None);
+    assert_eq!(format!("{cache:?}"), r#"{1: "z", 2: "b"}"#);
+    let clone = cache.clone();
+    cache.clear();
+    assert_eq!(cache.iter().len(), 0);
+    assert_eq!(clone.iter().len(), 2);
+}
+
+#[test]
+fn zero_capacity_stores_nothing() {
+    let mut cache = LruCache::<u32, u32, 0>::new();
+    assert_eq!(cache.put(1, 1), Some((1, 1)));
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn matches_a_vec_model() {
+    let mut cache = LruCache::<u32, u32, 8>::new();
+    // Most recently used first.
+    let mut model: Vec<(u32, u32)> = Vec::new();
+    let mut rng = XorShift::new(0x1234_5678);
+    for _ in 0..4000 {
+        let state = rng.next_u64() as u32;
+        let key = (state >> 8) % 12;
+        match state % 5 {
+            0 | 1 => {
+                let expected = match model.iter().position(|entry| entry.0 == key) {
+                    Some(at) => {
+                        model.remove(at);
+                        None
+                    }
+                    None if model.len() == 8 => model.pop(),
+                    None => None,
+                };
+                model.insert(0, (key, state));
+                assert_eq!(cache.put(key, state), expected);
+            }
+            2 => {
+                let expected = model.iter().position(|entry| entry.0 == key).map(|at| {
+                    let entry = model.remove(at);
+                    model.insert(0, entry);
+                    entry.1
+                });
+                assert_eq!(cache.get(&key).copied(), expected);
+            }
+            3 => {
+                let expected = model
+                    .iter()
+                    .position(|entry| entry.0 == key)
+                    .map(|at| model.remove(at).1);
+                assert_eq!(cache.pop(&key), expected);
+            }
+            _ => assert_eq!(cache.pop_lru(), model.pop()),
+        }
+        assert!(cache
+            .iter()
+            .map(|(k, v)| (*k, *v))
+            .eq(model.iter().copied()));
+        assert!(cache
+            .iter()
+            .rev()
+            .map(|(k, v)| (*k, *v))
+            .eq(model.iter().rev().copied()));
+    }
+}
+
+#[test]
+fn entries_are_dropped_once() {
+    let tracker = Tracker::new();
+    let mut cache = LruCache::<u32, _, 4>::new();
+    for key in 0..10 {
+        drop(cache.put(key % 6, tracker.track(key)));
+    }
+    assert_eq!(tracker.live(), 4);
+    drop(cache.pop(&3));
+    assert_eq!(tracker.live(), 3);
+    drop(cache);
+    assert_eq!(tracker.live(), 0);
+}
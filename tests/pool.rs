@@ -0,0 +1,81 @@
+#![cfg(target_has_atomic = "8")]
+
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    thread,
+};
+
+use no_std_collections::{pool::StackPool, testing::Tracker};
+
+static BUFFERS: StackPool<[u8; 64], 4> = StackPool::with_reset([[0; 64]; 4], |buf| buf.fill(0));
+
+#[test]
+fn guards_give_objects_back() {
+    let pool = StackPool::new([1, 2, 3]);
+    assert_eq!(pool.capacity(), 3);
+    let mut a = pool.acquire().unwrap();
+    let b = pool.acquire().unwrap();
+    let c = pool.acquire().unwrap();
+    assert!(pool.acquire().is_none());
+    assert_eq!((*a, *b, *c), (1, 2, 3));
+    *a += 10;
+    drop(b);
+    assert_eq!(pool.available(), 1);
+    assert_eq!(*pool.acquire().unwrap(), 2);
+    drop(a);
+    assert_eq!(*pool.acquire().unwrap(), 11);
+    drop(c);
+
+    let mut pool = pool;
+    assert_eq!(pool.iter_mut().map(|n| *n).collect::<Vec<_>>(), [11, 2, 3]);
+}
+
+#[test]
+fn reset_runs_on_return_even_when_it_panics() {
+    let pool = StackPool::<Vec<u8>, 2>::with_reset(Default::default(), |vec| {
+        assert!(vec.len() < 3);
+        vec.clear();
+    });
+    let mut guard = pool.acquire().unwrap();
+    guard.extend([1, 2]);
+    drop(guard);
+    assert!(pool.acquire().unwrap().is_empty());
+
+    let mut guard = pool.acquire().unwrap();
+    guard.extend([1, 2, 3]);
+    assert!(catch_unwind(AssertUnwindSafe(|| drop(guard))).is_err());
+    assert_eq!(pool.available(), 2);
+}
+
+#[test]
+fn objects_are_dropped_with_the_pool() {
+    let tracker = Tracker::new();
+    let pool = StackPool::new([tracker.track(0), tracker.track(1)]);
+    let guard = pool.acquire().unwrap();
+    assert_eq!(guard.value(), 0);
+    drop(guard);
+    drop(pool);
+    assert_eq!(tracker.live(), 0);
+}
+
+#[test]
+fn static_pool_is_shared_between_threads() {
+    let threads: Vec<_> = (0..8u8)
+        .map(|n| {
+            thread::spawn(move || {
+                for _ in 0..100 {
+                    if let Some(mut buf) = BUFFERS.acquire() {
+                        assert!(buf.iter().all(|&byte| byte == 0));
+                        buf.fill(n + 1);
+                        thread::yield_now();
+                        assert!(buf.iter().all(|&byte| byte == n + 1));
+                    }
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(BUFFERS.available(), 4);
+}
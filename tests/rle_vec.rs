@@ -0,0 +1,146 @@
+use no_std_collections::{rle_vec::StdRleVec, testing::XorShift, RleVec, StackRleVec, StackVec};
+
+#[test]
+fn push_merges_runs() {
+    let mut rle = StackRleVec::<char, 4>::new();
+    assert!(rle.is_empty());
+    "aaabbbbc".chars().for_each(|ch| rle.push(ch));
+    rle.push_run('c', 3);
+    rle.push_run('d', 0);
+    assert_eq!(rle.len(), 11);
+    assert_eq!(rle.run_count(), 3);
+    assert_eq!(
+        rle.runs().collect::<Vec<_>>(),
+        [(&'a', 3), (&'b', 4), (&'c', 4)]
+    );
+    assert_eq!(rle.iter().collect::<String>(), "aaabbbbcccc");
+    assert_eq!(rle.iter().rev().collect::<String>(), "ccccbbbbaaa");
+    assert_eq!(rle.iter().len(), 11);
+    assert_eq!((rle.first(), rle.last()), (Some(&'a'), Some(&'c')));
+    assert_eq!(
+        format!("{rle:?}"),
+        format!("{:?}", "aaabbbbcccc".chars().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn get_finds_the_run() {
+    let rle: StdRleVec<u8> = [1, 1, 2, 3, 3, 3, 1].into_iter().collect();
+    let expected = [1, 1, 2, 3, 3, 3, 1];
+    for (index, value) in expected.iter().enumerate() {
+        assert_eq!(rle.get(index), Some(value));
+        assert_eq!(rle[index], *value);
+    }
+    assert_eq!(rle.get(7), None);
+}
+
+#[test]
+fn full_run_table_is_reported() {
+    let mut rle = StackRleVec::<u8, 2>::new();
+    rle.extend([0, 0, 1]);
+    assert!(rle.try_push(1).is_ok());
+    assert!(rle.try_push_run(1, 10).is_ok());
+    assert_eq!(rle.try_push(2).unwrap_err().element(), 2);
+    assert_eq!(rle.try_push_run(3, 5).unwrap_err().element(), 3);
+    assert_eq!(rle.len(), 14);
+    assert_eq!(rle.run_capacity(), 2);
+}
+
+#[test]
+fn length_overflow_is_reported() {
+    let mut rle = StackRleVec::<u8, 4>::new();
+    rle.push_run(0, usize::MAX - 1);
+    assert!(rle.try_push(0).is_ok());
+    assert_eq!(rle.try_push(0).unwrap_err().element(), 0);
+    assert_eq!(rle.try_push_run(1, 1).unwrap_err().element(), 1);
+    assert_eq!(rle.len(), usize::MAX);
+    assert_eq!(rle.run_count(), 1);
+}
+
+#[test]
+fn iterators_clone_without_cloning_items() {
+    #[derive(PartialEq)]
+    struct Opaque(u8);
+    let mut rle = StackRleVec::<Opaque, 4>::new();
+    rle.push_run(Opaque(1), 2);
+    rle.push(Opaque(2));
+    let mut iter = rle.iter();
+    iter.next();
+    assert!(iter.clone().map(|item| item.0).eq([1, 2]));
+    let runs = rle.runs();
+    assert!(runs
+        .clone()
+        .map(|(item, count)| (item.0, count))
+        .eq([(1, 2), (2, 1)]));
+}
+
+#[test]
+#[should_panic(expected = "Capacity overflow")]
+fn push_panics_on_full_run_table() {
+    let mut rle = StackRleVec::<u8, 1>::new();
+    rle.extend([0, 0, 1]);
+}
+
+#[test]
+fn pop_and_truncate() {
+    let mut rle: StdRleVec<u8> = [1, 1, 2, 3, 3, 3].into_iter().collect();
+    assert_eq!(rle.pop(), Some(3));
+    assert_eq!(rle.run_count(), 3);
+    rle.truncate(3);
+    assert_eq!(rle.iter().copied().collect::<Vec<_>>(), [1, 1, 2]);
+    assert_eq!(rle.pop(), Some(2));
+    assert_eq!(rle.run_count(), 1);
+    rle.truncate(5);
+    assert_eq!(rle.len(), 2);
+    rle.truncate(1);
+    assert_eq!(rle.runs().collect::<Vec<_>>(), [(&1, 1)]);
+    rle.truncate(0);
+    assert!(rle.is_empty());
+    assert_eq!(rle.pop(), None);
+}
+
+#[test]
+fn matches_a_vec_model() {
+    let mut rle = StdRleVec::<u32>::new();
+    let mut model = Vec::new();
+    let mut rng = XorShift::new(0x9e37_79b9);
+    for _ in 0..2000 {
+        let state = rng.next_u64() as u32;
+        match state % 8 {
+            0 => assert_eq!(rle.pop(), model.pop()),
+            1 => {
+                let len = state as usize % (model.len() + 2);
+                rle.truncate(len);
+                model.truncate(len);
+            }
+            _ => {
+                let value = state >> 28;
+                let count = (state >> 8) as usize % 3;
+                rle.push_run(value, count);
+                model.extend(std::iter::repeat_n(value, count));
+            }
+        }
+        assert_eq!(rle.len(), model.len());
+        assert!(rle.iter().eq(&model));
+        assert!(rle.iter().rev().eq(model.iter().rev()));
+        let runs = rle.runs().collect::<Vec<_>>();
+        assert!(runs.windows(2).all(|pair| pair[0].0 != pair[1].0));
+        if let Some(index) = model.len().checked_sub(1) {
+            let index = state as usize % (index + 1);
+            assert_eq!(rle.get(index), Some(&model[index]));
+        }
+    }
+}
+
+#[test]
+fn conversions() {
+    let vec = StackVec::<u8, 8>::from(&[4, 4, 4, 5, 4][..]);
+    let rle: StackRleVec<u8, 4> = vec.into();
+    assert_eq!(rle.run_count(), 3);
+    assert_eq!(StackVec::<u8, 8>::from(&rle), [4, 4, 4, 5, 4]);
+
+    let heap: RleVec<u8, Vec<(u8, usize)>> = vec![7, 7, 8].into();
+    assert_eq!(Vec::from(&heap), [7, 7, 8]);
+    assert_eq!(heap.clone(), heap);
+    assert_ne!(heap, rle);
+}
@@ -0,0 +1,97 @@
+use no_std_collections::{slab::StdSlab, testing::Tracker, StackSlab};
+
+#[test]
+fn keys_stay_valid_across_removals() {
+    let mut slab = StackSlab::<&str, 4>::new();
+    let a = slab.insert("a");
+    let b = slab.insert("b");
+    let c = slab.insert("c");
+    assert_eq!((a, b, c), (0, 1, 2));
+    assert_eq!(slab.remove(b), Some("b"));
+    assert_eq!(slab.remove(b), None);
+    assert!(!slab.contains(b));
+    assert_eq!(slab[a], "a");
+    assert_eq!(slab[c], "c");
+
+    assert_eq!(slab.vacant_key(), b);
+    assert_eq!(slab.insert("d"), b);
+    assert_eq!(slab.insert("e"), 3);
+    assert_eq!(slab.len(), 4);
+    assert_eq!(slab.try_insert("f").unwrap_err().element(), "f");
+
+    *slab.get_mut(c).unwrap() = "C";
+    assert_eq!(
+        slab.iter().collect::<Vec<_>>(),
+        [(0, &"a"), (1, &"d"), (2, &"C"), (3, &"e")]
+    );
+    assert_eq!(slab.iter().next_back(), Some((3, &"e")));
+    assert_eq!(slab.get(7), None);
+    assert_eq!(slab.remove(7), None);
+}
+
+#[test]
+fn free_list_is_reused_last_in_first_out() {
+    let mut slab = StdSlab::new();
+    for i in 0..10 {
+        assert_eq!(slab.insert(i), i);
+    }
+    slab.remove(2);
+    slab.remove(7);
+    slab.remove(4);
+    assert_eq!(slab.len(), 7);
+    assert_eq!(slab.insert(40), 4);
+    assert_eq!(slab.insert(70), 7);
+    assert_eq!(slab.insert(20), 2);
+    assert_eq!(slab.insert(10), 10);
+
+    slab.retain(|key, value| {
+        *value += 1;
+        key % 3 != 0
+    });
+    assert_eq!(
+        slab.iter_mut()
+            .map(|(key, value)| (key, *value))
+            .collect::<Vec<_>>(),
+        [(1, 2), (2, 21), (4, 41), (5, 6), (7, 71), (8, 9), (10, 11)]
+    );
+    assert_eq!(slab.insert(0), 9);
+}
+
+#[test]
+fn drain_and_clear_drop_every_value() {
+    let tracker = Tracker::new();
+    let mut slab = StackSlab::<_, 8>::new();
+    for value in 0..8 {
+        slab.insert(tracker.track(value));
+    }
+    slab.remove(3);
+    slab.remove(5);
+    assert_eq!(tracker.live(), 6);
+
+    let mut drain = slab.drain();
+    assert_eq!(drain.len(), 6);
+    assert_eq!(drain.next().map(|value| value.value()), Some(0));
+    assert_eq!(drain.next_back().map(|value| value.value()), Some(7));
+    drop(drain);
+    assert_eq!(tracker.live(), 0);
+    assert!(slab.is_empty());
+    assert_eq!(slab.insert(tracker.track(9)), 0);
+    slab.insert(tracker.track(10));
+    slab.clear();
+    assert_eq!(tracker.live(), 0);
+    assert_eq!(slab.vacant_key(), 0);
+}
+
+#[test]
+fn iter_clones_without_cloning_values() {
+    struct Opaque(u32);
+    let mut slab = StackSlab::<Opaque, 4>::new();
+    for n in 0..3 {
+        slab.insert(Opaque(n));
+    }
+    slab.remove(1);
+    let mut iter = slab.iter();
+    iter.next();
+    assert!(iter.clone().map(|(key, value)| (key, value.0)).eq([(2, 2)]));
+    assert_eq!(iter.len(), 1);
+}
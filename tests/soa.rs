@@ -0,0 +1,218 @@
+use no_std_collections::{
+    soa::Heap,
+    testing::{Tracked, Tracker},
+    Soa,
+};
+
+#[derive(Soa, Debug, Clone, Copy, PartialEq)]
+struct Particle {
+    x: f32,
+    y: f32,
+    mass: u32,
+}
+
+/// Fields may share names with the generated methods.
+#[derive(Soa)]
+struct Segment {
+    start: u32,
+    len: u32,
+}
+
+#[derive(Soa)]
+struct Entity {
+    id: u32,
+    payload: Tracked,
+}
+
+/// The generated code names nothing through the caller's scope.
+mod shadowed {
+    #![allow(dead_code, unused_macros)]
+
+    use no_std_collections::Soa;
+
+    type Result<T> = core::result::Result<T, ()>;
+    type Option = ();
+
+    macro_rules! panic {
+        () => {};
+    }
+
+    macro_rules! assert {
+        () => {};
+    }
+
+    #[derive(Soa)]
+    struct Sample {
+        time: u64,
+        value: i16,
+    }
+
+    #[test]
+    fn derives_with_shadowed_prelude_names() {
+        let mut soa = SampleSoa::<2>::new();
+        soa.push(Sample { time: 1, value: -1 });
+        assert_eq!(soa.pop().map(|row| row.value), Some(-1));
+    }
+}
+
+fn particle(i: u32) -> Particle {
+    Particle {
+        x: i as f32,
+        y: -(i as f32),
+        mass: i * 10,
+    }
+}
+
+#[test]
+fn push_pop_in_lock_step() {
+    let mut soa = ParticleSoa::<4>::new();
+    assert!(soa.is_empty());
+    assert_eq!(soa.capacity(), 4);
+    (0..3).for_each(|i| soa.push(particle(i)));
+    assert_eq!(soa.len(), 3);
+    assert_eq!(soa.x_column(), [0.0, 1.0, 2.0]);
+    assert_eq!(soa.y_column(), [0.0, -1.0, -2.0]);
+    assert_eq!(soa.mass_column(), [0, 10, 20]);
+
+    assert_eq!(soa.pop(), Some(particle(2)));
+    assert_eq!(soa.len(), 2);
+    assert_eq!(soa.mass_column(), [0, 10]);
+    soa.clear();
+    assert_eq!(soa.pop(), None);
+}
+
+#[test]
+fn try_push_returns_the_row_when_full() {
+    let mut soa: ParticleSoa<2> = (0..2).map(particle).collect();
+    assert_eq!(
+        soa.try_push(particle(7)).unwrap_err().element(),
+        particle(7)
+    );
+    assert_eq!(soa.len(), 2);
+    assert_eq!(soa.mass_column(), [0, 10]);
+}
+
+#[test]
+#[should_panic(expected = "Capacity overflow")]
+fn push_panics_when_full() {
+    let mut soa = ParticleSoa::<1>::new();
+    soa.push(particle(0));
+    soa.push(particle(1));
+}
+
+#[test]
+#[should_panic(expected = "`Soa` columns out of step")]
+fn pop_checks_columns_pushed_behind_its_back() {
+    let mut soa = ParticleSoa::<4>::new();
+    // The columns are private fields, reachable from this module only.
+    soa.x.push(1.0);
+    assert_eq!(soa.len(), 1);
+    soa.pop();
+}
+
+#[test]
+fn swap_remove_moves_the_last_row() {
+    let mut soa: ParticleSoa<8> = (0..5).map(particle).collect();
+    assert_eq!(soa.swap_remove(1), particle(1));
+    assert_eq!(soa.mass_column(), [0, 40, 20, 30]);
+    assert_eq!(soa.x_column(), [0.0, 4.0, 2.0, 3.0]);
+    assert_eq!(soa.swap_remove(3), particle(3));
+    assert_eq!(soa.y_column(), [0.0, -4.0, -2.0]);
+}
+
+#[test]
+#[should_panic(expected = "swap_remove index (is 2) should be < len (is 2)")]
+fn swap_remove_out_of_bounds() {
+    let mut soa: ParticleSoa<4> = (0..2).map(particle).collect();
+    soa.swap_remove(2);
+}
+
+#[test]
+fn row_references() {
+    let mut soa: ParticleSoa<4> = (0..3).map(particle).collect();
+    let row = soa.get(1).unwrap();
+    assert_eq!((*row.x, *row.y, *row.mass), (1.0, -1.0, 10));
+    assert!(soa.get(3).is_none());
+
+    let row = soa.get_mut(2).unwrap();
+    *row.mass += 1;
+    *row.x = 0.5;
+    assert_eq!(soa.mass_column(), [0, 10, 21]);
+    assert_eq!(soa.x_column(), [0.0, 1.0, 0.5]);
+
+    for row in soa.iter_mut() {
+        *row.y = *row.x * 2.0;
+    }
+    soa.mass_column_mut()[0] = 99;
+    let rows: Vec<_> = soa.iter().map(|row| (*row.y, *row.mass)).collect();
+    assert_eq!(rows, [(0.0, 99), (2.0, 10), (1.0, 21)]);
+}
+
+#[test]
+fn fields_named_like_methods() {
+    let mut soa = SegmentSoa::<4>::new();
+    soa.push(Segment { start: 0, len: 3 });
+    soa.push(Segment { start: 3, len: 5 });
+    assert_eq!(soa.len(), 2);
+    soa.len_column_mut()[0] = 2;
+    assert_eq!(soa.len_column(), [2, 5]);
+    assert_eq!(soa.start_column(), [0, 3]);
+    let row = soa.get(1).unwrap();
+    assert_eq!((*row.start, *row.len), (3, 5));
+}
+
+#[test]
+fn heap_storage() {
+    let mut soa = ParticleSoaIn::<Heap>::new();
+    soa.extend((0..100).map(particle));
+    assert_eq!(soa.len(), 100);
+    assert!(soa.capacity() >= 100);
+    assert_eq!(soa.swap_remove(0), particle(0));
+    assert_eq!(soa.mass_column()[0], 990);
+    soa.truncate(10);
+    assert_eq!(soa.iter().count(), 10);
+
+    let copy = soa.clone();
+    assert_eq!(copy.x_column(), soa.x_column());
+}
+
+#[test]
+fn rows_are_dropped_once() {
+    let tracker = Tracker::new();
+    let mut soa = EntitySoa::<8>::new();
+    for id in 0..6 {
+        soa.push(Entity {
+            id,
+            payload: tracker.track(id),
+        });
+    }
+    let removed = soa.swap_remove(0);
+    assert_eq!((removed.id, removed.payload.value()), (0, 0));
+    drop(removed);
+    assert_eq!(soa.id_column(), [5, 1, 2, 3, 4]);
+    assert_eq!(soa.payload_column()[0].value(), 5);
+
+    soa.truncate(3);
+    assert_eq!(tracker.live(), 3);
+    let popped = soa.pop().unwrap();
+    assert_eq!(popped.payload.value(), 2);
+    drop(popped);
+    assert_eq!(tracker.live(), 2);
+
+    let mut full = EntitySoa::<1>::from_iter([Entity {
+        id: 0,
+        payload: tracker.track(0),
+    }]);
+    let rejected = full
+        .try_push(Entity {
+            id: 1,
+            payload: tracker.track(1),
+        })
+        .unwrap_err()
+        .element();
+    assert_eq!(rejected.payload.value(), 1);
+    drop(rejected);
+    drop(full);
+    drop(soa);
+    assert_eq!(tracker.live(), 0);
+}
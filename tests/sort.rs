@@ -0,0 +1,204 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use no_std_collections::{
+    testing::{Tracked, Tracker, XorShift},
+    traits::vec::Vec as _,
+    StackVec,
+};
+
+fn pseudo_random(len: usize, modulo: u32) -> Vec<(u32, usize)> {
+    let mut rng = XorShift::new(0x2545_f491);
+    (0..len)
+        .map(|index| (rng.below(modulo as usize) as u32, index))
+        .collect()
+}
+
+#[test]
+fn stable_sort_matches_std() {
+    for len in [0, 1, 2, 15, 16, 17, 100, 257, 1000] {
+        for modulo in [1, 4, 1000] {
+            let input = pseudo_random(len, modulo);
+            let mut expected = input.clone();
+            expected.sort_by_key(|&(key, _)| key);
+
+            // No spare capacity: merges go through rotations only.
+            let mut tight = input.clone();
+            tight.shrink_to_fit();
+            tight.sort_stable_in_place_by_key(|&(key, _)| key);
+            assert_eq!(tight, expected);
+
+            let mut roomy = Vec::with_capacity(len * 2);
+            roomy.extend_from_slice(&input);
+            roomy.sort_stable_in_place_by_key(|&(key, _)| key);
+            assert_eq!(roomy, expected);
+        }
+    }
+}
+
+#[test]
+fn stack_vec_sorts() {
+    let input = pseudo_random(300, 16);
+    let mut expected = input.clone();
+    expected.sort_by_key(|&(key, _)| key);
+
+    let mut full = StackVec::<(u32, usize), 300>::from(&input[..]);
+    full.sort_stable_in_place_by_key(|&(key, _)| key);
+    assert_eq!(full, expected[..]);
+
+    let mut half = StackVec::<(u32, usize), 400>::from(&input[..]);
+    half.sort_stable_in_place_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(half, expected[..]);
+
+    let mut calls = 0;
+    let mut cached = StackVec::<(u32, usize), 300>::from(&input[..]);
+    cached.sort_by_cached_key(|&(key, _)| {
+        calls += 1;
+        key
+    });
+    assert_eq!(calls, 300);
+    assert_eq!(cached, expected[..]);
+
+    let mut words = StackVec::<&str, 4>::from(["pear", "fig", "apple", "kiwi"]);
+    words.sort_stable_in_place();
+    assert_eq!(words, ["apple", "fig", "kiwi", "pear"]);
+}
+
+#[test]
+fn sort_by_cached_key_handles_more_zsts_than_slots() {
+    let mut units = StackVec::<(), 2>::new();
+    for _ in 0..64 {
+        units.push(());
+    }
+    units.sort_by_cached_key(|_| 0u64);
+    assert_eq!(units.len(), 64);
+}
+
+#[test]
+fn panicking_comparator_keeps_every_item() {
+    for capacity in [200, 400] {
+        let tracker = Tracker::new();
+        let mut vec = Vec::with_capacity(capacity);
+        vec.extend(
+            pseudo_random(200, 50)
+                .into_iter()
+                .map(|(key, _)| tracker.track(key)),
+        );
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            vec.sort_stable_in_place_by(|a: &Tracked, b: &Tracked| {
+                calls += 1;
+                assert!(calls < 900);
+                a.value().cmp(&b.value())
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(tracker.live(), 200);
+        let mut values: Vec<u32> = vec.iter().map(Tracked::value).collect();
+        values.sort();
+        let mut expected: Vec<u32> = pseudo_random(200, 50)
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect();
+        expected.sort();
+        assert_eq!(values, expected);
+        drop(vec);
+        assert_eq!(tracker.live(), 0);
+    }
+}
+
+#[test]
+fn radix_sort_matches_std() {
+    for len in [0, 1, 2, 100, 1000] {
+        let input: Vec<i32> = pseudo_random(len, u32::MAX)
+            .into_iter()
+            .map(|(key, _)| key as i32 >> (key % 24))
+            .collect();
+        let mut expected = input.clone();
+        expected.sort_unstable();
+
+        let mut roomy = Vec::with_capacity(len * 2);
+        roomy.extend_from_slice(&input);
+        roomy.radix_sort();
+        assert_eq!(roomy, expected);
+
+        // Not enough spare capacity: falls back to the merge sort.
+        let mut tight = input.clone();
+        tight.shrink_to_fit();
+        tight.radix_sort();
+        assert_eq!(tight, expected);
+
+        let mut scratch = StackVec::<i32, 1000>::new();
+        let mut stack = StackVec::<i32, 1000>::from(&input[..]);
+        stack.radix_sort_with(&mut scratch);
+        assert_eq!(stack, expected[..]);
+        assert!(scratch.is_empty());
+    }
+}
+
+#[test]
+fn radix_sort_by_key_is_stable() {
+    let input = pseudo_random(500, 300);
+    let mut expected = input.clone();
+    expected.sort_by_key(|&(key, _)| key);
+
+    let mut readings = StackVec::<(u32, usize), 1000>::from(&input[..]);
+    readings.radix_sort_by_key(|&(key, _)| key as u16);
+    assert_eq!(readings, expected[..]);
+
+    let mut scratch = vec![(7, 7)];
+    let mut readings = input.clone();
+    readings.radix_sort_by_key_with(|&(key, _)| key, &mut scratch);
+    assert_eq!(readings, expected);
+    assert_eq!(scratch, [(7, 7)]);
+
+    let mut chars = StackVec::<char, 8>::from(&['z', 'é', 'a', 'B'][..]);
+    chars.radix_sort();
+    assert_eq!(chars, ['B', 'a', 'z', 'é']);
+}
+
+#[test]
+fn radix_sort_panicking_key_keeps_every_item() {
+    let tracker = Tracker::new();
+    let mut vec = Vec::with_capacity(400);
+    vec.extend(
+        pseudo_random(200, u32::MAX)
+            .into_iter()
+            .map(|(key, _)| tracker.track(key)),
+    );
+    let mut calls = 0;
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        vec.radix_sort_by_key(|item: &Tracked| {
+            calls += 1;
+            // Panics while scattering the second byte, out of the scratch buffer.
+            assert!(calls < 700);
+            item.value()
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(tracker.live(), 200);
+    let mut values: Vec<u32> = vec.iter().map(Tracked::value).collect();
+    values.sort();
+    let mut expected: Vec<u32> = pseudo_random(200, u32::MAX)
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+    expected.sort();
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn radix_sort_survives_a_changing_key() {
+    let mut vec = StackVec::<String, 4>::new();
+    vec.extend(["a", "b"].map(String::from));
+    let mut calls = 0u32;
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        vec.radix_sort_by_key(|_| {
+            calls += 1;
+            calls - 1
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(vec.len(), 2);
+    vec.sort_stable_in_place();
+    assert_eq!(vec, ["a", "b"]);
+}
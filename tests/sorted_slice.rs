@@ -0,0 +1,57 @@
+use no_std_collections::{traits::SortedSliceExt, StackVec};
+
+const A: [u32; 6] = [1, 3, 5, 7, 9, 11];
+const B: [u32; 5] = [2, 3, 4, 9, 12];
+
+#[test]
+fn lazy_set_operations() {
+    let union: Vec<u32> = A.union(&B).copied().collect();
+    assert_eq!(union, [1, 2, 3, 4, 5, 7, 9, 11, 12]);
+    let intersection: Vec<u32> = A.intersection(&B).copied().collect();
+    assert_eq!(intersection, [3, 9]);
+    let difference: Vec<u32> = A.difference(&B[..]).copied().collect();
+    assert_eq!(difference, [1, 5, 7, 11]);
+    let symmetric: Vec<u32> = B.symmetric_difference(&A).copied().collect();
+    assert_eq!(symmetric, [1, 2, 4, 5, 7, 11, 12]);
+    let merge = A.merge(&B);
+    assert_eq!(merge.len(), 11);
+    let merge: Vec<u32> = merge.copied().collect();
+    assert_eq!(merge, [1, 2, 3, 3, 4, 5, 7, 9, 9, 11, 12]);
+
+    let empty: [u32; 0] = [];
+    assert_eq!(empty.union(&A).count(), A.len());
+    assert_eq!(A.difference(&empty).count(), A.len());
+    assert_eq!(empty.intersection(&A).next(), None);
+}
+
+#[test]
+fn operations_into_vec_implementors() {
+    let mut out = StackVec::<u32, 16>::new();
+    A.union_into(&B, &mut out);
+    assert_eq!(out, [1, 2, 3, 4, 5, 7, 9, 11, 12]);
+
+    let mut out = vec![0];
+    A.intersection_into(&B, &mut out);
+    A.symmetric_difference_into(&B, &mut out);
+    assert_eq!(out, [0, 3, 9, 1, 2, 4, 5, 7, 11, 12]);
+
+    let left = StackVec::<u32, 8>::from(&A[..]);
+    let mut out = StackVec::<u32, 16>::new();
+    left.difference_into(&B, &mut out);
+    left.merge_into(&left, &mut out);
+    assert_eq!(out, [1, 5, 7, 11, 1, 1, 3, 3, 5, 5, 7, 7, 9, 9, 11, 11]);
+}
+
+#[test]
+fn fallible_operations_leave_the_output_untouched() {
+    let mut out = StackVec::<u32, 4>::from(&[42][..]);
+    assert!(A.try_union_into(&B, &mut out).is_err());
+    assert_eq!(out, [42]);
+    assert!(A.try_merge_into(&B, &mut out).is_err());
+    assert_eq!(out, [42]);
+    assert_eq!(A.try_intersection_into(&B, &mut out), Ok(()));
+    assert_eq!(out, [42, 3, 9]);
+    assert!(A.try_difference_into(&B, &mut out).is_err());
+    assert!(A.try_symmetric_difference_into(&B, &mut out).is_err());
+    assert_eq!(out, [42, 3, 9]);
+}
@@ -0,0 +1,99 @@
+use std::{
+    cell::Cell,
+    cmp::Ordering,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use no_std_collections::{
+    testing::{Tracked, Tracker},
+    traits::{
+        compare::{ByKey, Reversed},
+        vec::CapacityError,
+    },
+    SortedVec, SortedVecBy, StackVec,
+};
+
+#[test]
+fn insert_remove_and_lookup() {
+    let mut set = SortedVec::<StackVec<u32, 8>>::new();
+    for item in [5, 1, 4, 1, 3] {
+        let _ = set.insert(item);
+    }
+    assert_eq!(set.as_slice(), [1, 3, 4, 5]);
+    assert_eq!(set.insert(4), Err((2, 4)));
+    assert_eq!(set.insert(2), Ok(1));
+    assert!(set.contains(&3));
+    assert_eq!(set.remove_value(&3), Some(3));
+    assert_eq!(set.remove_value(&3), None);
+    assert_eq!(set.range(2..5), [2, 4]);
+    assert_eq!(set.range(..=4), [1, 2, 4]);
+    assert_eq!(
+        set.range((std::ops::Bound::Excluded(1), std::ops::Bound::Unbounded)),
+        [2, 4, 5]
+    );
+    assert_eq!(set.range(6..), [] as [u32; 0]);
+}
+
+#[test]
+fn try_insert_reports_a_full_container() {
+    let mut set = SortedVec::<StackVec<u32, 2>>::from_unsorted(StackVec::from([9, 3]));
+    assert_eq!(set.try_insert(3), Ok(Err((0, 3))));
+    assert_eq!(set.try_insert(4).map_err(CapacityError::element), Err(4));
+    assert_eq!(set.as_slice(), [3, 9]);
+}
+
+#[test]
+fn custom_comparators() {
+    let set: SortedVecBy<Vec<u32>, Reversed> = [1, 7, 3, 7].into_iter().collect();
+    assert_eq!(set.as_slice(), [7, 3, 1]);
+
+    let by_len = ByKey::new(|item: &&str| item.len());
+    let mut set =
+        SortedVecBy::<Vec<&str>, _>::from_unsorted_by(vec!["ccc", "a", "bb", "dd"], by_len);
+    assert_eq!(set.as_slice(), ["a", "bb", "ccc"]);
+    assert_eq!(set.insert("xx"), Err((1, "xx")));
+}
+
+#[test]
+fn merge_drops_duplicates_and_keeps_own_items() {
+    let tracker = Tracker::new();
+    let key = ByKey::new(Tracked::value);
+    let mut set = SortedVecBy::from_unsorted_by(
+        StackVec::<Tracked, 16>::from_iter([1, 4, 6, 9].map(|value| tracker.track(value))),
+        key,
+    );
+    let mut other = Vec::new();
+    other.extend([0, 4, 4, 5, 9, 10, 10].map(|value| tracker.track(value)));
+    set.merge(other);
+    let values: Vec<u32> = set.iter().map(Tracked::value).collect();
+    assert_eq!(values, [0, 1, 4, 5, 6, 9, 10]);
+    assert_eq!(tracker.live(), 7);
+    drop(set);
+    assert_eq!(tracker.live(), 0);
+}
+
+#[test]
+fn merge_stays_sorted_when_the_comparator_panics() {
+    let tracker = Tracker::new();
+    let calls = Cell::new(0);
+    let cmp = |a: &Tracked, b: &Tracked| -> Ordering {
+        calls.set(calls.get() + 1);
+        if calls.get() > 6 {
+            panic!("comparator");
+        }
+        a.value().cmp(&b.value())
+    };
+    let mut set = SortedVecBy::<StackVec<Tracked, 16>, _>::with_comparator(&cmp);
+    set.extend([2, 4, 6, 8].map(|value| tracker.track(value)));
+    calls.set(0);
+    let other =
+        StackVec::<Tracked, 8>::from_iter([1, 3, 5, 7, 9].map(|value| tracker.track(value)));
+    let result = catch_unwind(AssertUnwindSafe(|| set.merge(other)));
+    assert!(result.is_err());
+    let values: Vec<u32> = set.iter().map(Tracked::value).collect();
+    assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(values.starts_with(&[2, 4]) && values.ends_with(&[9]));
+    assert_eq!(tracker.live(), set.len() as isize);
+    drop(set);
+    assert_eq!(tracker.live(), 0);
+}
@@ -0,0 +1,97 @@
+use no_std_collections::{
+    sparse_set::{StdSparseMap, StdSparseSet},
+    testing::{Tracker, XorShift},
+    StackSparseMap, StackSparseSet,
+};
+
+#[test]
+fn insert_remove_contains() {
+    let mut set = StackSparseSet::<16, 4>::new();
+    assert!(set.insert(9));
+    assert!(set.insert(3));
+    assert!(!set.insert(9));
+    assert!(set.insert(15));
+    assert!(set.contains(3) && set.contains(9) && !set.contains(4));
+    assert_eq!(set.as_slice(), [9, 3, 15]);
+
+    assert!(set.remove(9));
+    assert!(!set.remove(9));
+    assert_eq!(set.as_slice(), [15, 3]);
+    assert_eq!(set.index_of(15), Some(0));
+
+    assert_eq!(set.try_insert(16).unwrap_err().element(), 16);
+    set.extend([0, 1]);
+    assert_eq!(set.try_insert(2).unwrap_err().element(), 2);
+    assert_eq!(set.len(), 4);
+
+    set.clear();
+    assert!(set.is_empty());
+    assert!(!set.contains(15) && !set.contains(0));
+    assert!(set.insert(3));
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [3]);
+}
+
+#[test]
+fn matches_a_model() {
+    let mut set = StdSparseSet::new();
+    let mut model = std::collections::BTreeSet::new();
+    let mut rng = XorShift::new(0x1234_5678);
+    for _ in 0..2000 {
+        let state = rng.next_u64() as u32;
+        let id = (state % 100) as usize;
+        match state % 4 {
+            0 => assert_eq!(set.remove(id), model.remove(&id)),
+            1 if state % 40 == 1 => {
+                set.clear();
+                model.clear();
+            }
+            _ => assert_eq!(set.insert(id), model.insert(id)),
+        }
+        assert_eq!(set.len(), model.len());
+    }
+    let mut members = set.as_slice().to_vec();
+    members.sort();
+    assert!(members.iter().eq(model.iter()));
+}
+
+#[test]
+fn sparse_map_values() {
+    let tracker = Tracker::new();
+    let mut map = StackSparseMap::<_, 32, 3>::new();
+    assert!(map.insert(7, tracker.track(70)).is_none());
+    assert!(map.insert(2, tracker.track(20)).is_none());
+    let old = map.insert(7, tracker.track(71)).unwrap();
+    assert_eq!(old.value(), 70);
+    drop(old);
+    assert!(map.insert(31, tracker.track(310)).is_none());
+    assert_eq!(
+        map.try_insert(4, tracker.track(40))
+            .unwrap_err()
+            .element()
+            .value(),
+        40
+    );
+    assert_eq!(
+        map.try_insert(32, tracker.track(320))
+            .unwrap_err()
+            .element()
+            .value(),
+        320
+    );
+
+    assert_eq!(map.get(2).map(|v| v.value()), Some(20));
+    assert_eq!(map.remove(7).map(|v| v.value()), Some(71));
+    assert!(map.remove(7).is_none());
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [31, 2]);
+    assert!(map.get_mut(31).is_some() && map.contains_key(2));
+    assert_eq!(tracker.live(), 2);
+    map.clear();
+    assert_eq!(tracker.live(), 0);
+    assert!(map.get(2).is_none());
+
+    let mut heap: StdSparseMap<&str> = [(5, "five"), (1000, "thousand")].into_iter().collect();
+    for value in heap.values_mut() {
+        *value = &value[..1];
+    }
+    assert_eq!(format!("{heap:?}"), r#"{5: "f", 1000: "t"}"#);
+}
@@ -0,0 +1,262 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    mem,
+    ops::Bound::{Excluded, Included},
+};
+
+use no_std_collections::{
+    stack_btree_map::Entry,
+    testing::{Tracker, XorShift},
+    StackBTreeMap, StackBTreeSet,
+};
+
+#[test]
+fn iterates_in_key_order() {
+    let mut map = StackBTreeMap::<u32, char, 8>::new();
+    for (key, value) in [(5, 'e'), (1, 'a'), (3, 'c'), (2, 'b'), (4, 'd')] {
+        assert_eq!(map.insert(key, value), None);
+    }
+    assert_eq!(map.insert(3, 'C'), Some('c'));
+    assert_eq!(map.len(), 5);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    assert_eq!(map.values().rev().collect::<String>(), "edCba");
+    assert_eq!(map.first_key_value(), Some((&1, &'a')));
+    assert_eq!(map.last_key_value(), Some((&5, &'e')));
+    assert_eq!(map[&2], 'b');
+    assert_eq!(map.get(&6), None);
+    assert_eq!(
+        format!("{map:?}"),
+        "{1: 'a', 2: 'b', 3: 'C', 4: 'd', 5: 'e'}"
+    );
+}
+
+#[test]
+fn range_queries_match_std() {
+    let map: StackBTreeMap<u32, u32, 64> = (0..200).map(|key| (key * 2, key)).collect();
+    let model: BTreeMap<u32, u32> = (0..200).map(|key| (key * 2, key)).collect();
+    assert!(map.range(..).eq(model.range(..)));
+    assert!(map.range(17..).eq(model.range(17..)));
+    assert!(map.range(..=100).eq(model.range(..=100)));
+    assert!(map.range(40..41).eq(model.range(40..41)));
+    assert!(map.range(41..42).eq(model.range(41..42)));
+    assert!(map.range(390..).rev().eq(model.range(390..).rev()));
+    assert!(map.range(500..).eq(model.range(500..)));
+
+    let mut range = map.range(10..=20);
+    assert_eq!(range.next(), Some((&10, &5)));
+    assert_eq!(range.next_back(), Some((&20, &10)));
+    assert_eq!(
+        range.map(|(key, _)| *key).collect::<Vec<_>>(),
+        [12, 14, 16, 18]
+    );
+}
+
+#[test]
+fn iterators_meet_in_the_middle() {
+    let map: StackBTreeMap<u32, u32, 160> = (0..500).map(|key| (key, key)).collect();
+    let mut iter = map.iter();
+    // Each end is one edge, whatever the height of the tree.
+    assert!(mem::size_of_val(&iter) <= 6 * mem::size_of::<usize>());
+    let (mut front, mut back) = (Vec::new(), Vec::new());
+    while let Some((&key, _)) = iter.next() {
+        front.push(key);
+        assert_eq!(iter.len(), 500 - front.len() - back.len());
+        back.extend(iter.next_back().map(|(&key, _)| key));
+    }
+    assert_eq!(iter.next_back(), None);
+    back.reverse();
+    front.append(&mut back);
+    assert!(front.into_iter().eq(0..500));
+
+    let mut range = map.range(100..400);
+    let rest = range.clone();
+    assert_eq!(range.nth(150), Some((&250, &250)));
+    assert_eq!(range.next_back(), Some((&399, &399)));
+    assert_eq!(range.count(), 148);
+    assert_eq!(rest.rev().count(), 300);
+}
+
+#[test]
+fn crossed_bounds_give_empty_ranges() {
+    let map: StackBTreeMap<u32, (), 8> = (0..20).map(|key| (key, ())).collect();
+    assert_eq!(map.range((Included(10), Included(5))).count(), 0);
+    assert_eq!(map.range((Excluded(7), Excluded(7))).count(), 0);
+    assert_eq!(map.range((Excluded(7), Excluded(8))).count(), 0);
+    assert_eq!(map.range((Excluded(7), Included(7))).count(), 0);
+    assert_eq!(map.range(7..7).count(), 0);
+    assert_eq!(map.range(7..=7).count(), 1);
+    assert_eq!(map.range(30..).count(), 0);
+    assert_eq!(map.range(..0).next_back(), None);
+}
+
+#[test]
+fn iterators_are_send_and_sync() {
+    fn check<T: Send + Sync>(_: T) {}
+    let mut map = StackBTreeMap::<u32, u32, 4>::new();
+    check(map.iter());
+    check(map.range(1..));
+    check(map.keys());
+    check(map.values());
+    check(map.iter_mut());
+    check(map.range_mut(..1));
+    check(map.values_mut());
+    let set = StackBTreeSet::<u32, 4>::new();
+    check(set.iter());
+    check(set.range(..));
+}
+
+#[test]
+fn mutable_iteration() {
+    let mut map: StackBTreeMap<u32, u32, 32> = (0..100).map(|key| (key, key)).collect();
+    for (key, value) in map.range_mut(50..) {
+        *value += key;
+    }
+    map.values_mut().rev().take(10).for_each(|value| *value = 0);
+    map.iter_mut().take(2).for_each(|(_, value)| *value = 7);
+    let expected = (0..100).map(|key| match key {
+        0 | 1 => 7,
+        90.. => 0,
+        50.. => key * 2,
+        _ => key,
+    });
+    assert!(map.values().copied().eq(expected));
+}
+
+#[test]
+fn entry_api() {
+    let mut map = StackBTreeMap::<&str, u32, 4>::new();
+    *map.entry("a").or_insert(1) += 10;
+    *map.entry("a").or_insert(1) += 10;
+    map.entry("b").and_modify(|value| *value = 0).or_default();
+    assert_eq!(map.entry("c").key(), &"c");
+    match map.entry("a") {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(entry.insert(5), 21);
+            assert_eq!(entry.remove_entry(), ("a", 5));
+        }
+        Entry::Vacant(_) => unreachable!(),
+    }
+    match map.entry("d") {
+        Entry::Vacant(entry) => assert_eq!(entry.try_insert(4).ok(), Some(&mut 4)),
+        Entry::Occupied(_) => unreachable!(),
+    }
+    assert_eq!(map.iter().collect::<Vec<_>>(), [(&"b", &0), (&"d", &4)]);
+}
+
+#[test]
+fn try_insert_reports_pool_exhaustion() {
+    // A single node holds 11 entries; the twelfth needs a split.
+    let mut map = StackBTreeMap::<u32, u32, 1>::new();
+    for key in 0..11 {
+        assert_eq!(map.try_insert(key, key).unwrap(), None);
+    }
+    assert_eq!(map.free_nodes(), 0);
+    assert_eq!(map.try_insert(11, 11).unwrap_err().element(), (11, 11));
+    assert_eq!(map.try_insert(3, 30).unwrap(), Some(3));
+    assert_eq!(map.len(), 11);
+    assert!(map.keys().copied().eq(0..11));
+
+    let mut map = StackBTreeMap::<u32, (), 4>::new();
+    let mut key = 0;
+    while map.try_insert(key, ()).is_ok() {
+        key += 1;
+    }
+    assert_eq!(map.len(), key as usize);
+    assert!(map.keys().copied().eq(0..key));
+    while map.pop_last().is_some() {}
+    assert_eq!(map.free_nodes(), 4);
+}
+
+#[test]
+#[should_panic(expected = "Capacity overflow")]
+fn insert_panics_when_the_pool_is_exhausted() {
+    let mut map = StackBTreeMap::<u32, u32, 1>::new();
+    map.extend((0..12).map(|key| (key, key)));
+}
+
+#[test]
+fn drops_every_value() {
+    let tracker = Tracker::new();
+    let mut map = StackBTreeMap::<u32, _, 16>::new();
+    for key in 0..100 {
+        drop(map.insert(key % 60, tracker.track(key)));
+    }
+    assert_eq!(tracker.live(), 60);
+    for key in 0..20 {
+        drop(map.remove(&(key * 3)));
+    }
+    assert_eq!(tracker.live(), 40);
+    let clone = map.clone();
+    assert_eq!(tracker.live(), 80);
+    drop(clone.into_iter().take(5).collect::<Vec<_>>());
+    assert_eq!(tracker.live(), 40);
+    drop(map);
+    assert_eq!(tracker.live(), 0);
+}
+
+#[test]
+fn matches_std_model() {
+    let mut map = StackBTreeMap::<u32, u32, 40>::new();
+    let mut model = BTreeMap::new();
+    let mut rng = XorShift::new(0xdead_beef);
+    for _ in 0..20000 {
+        let state = rng.next_u64() as u32;
+        let key = (state >> 8) % 500;
+        match state % 8 {
+            0..=2 => assert_eq!(map.insert(key, state), model.insert(key, state)),
+            3 | 4 => assert_eq!(map.remove(&key), model.remove(&key)),
+            5 => assert_eq!(map.pop_first(), model.pop_first()),
+            6 => assert_eq!(map.pop_last(), model.pop_last()),
+            _ => {
+                let end = key + (state >> 20) % 50;
+                assert!(map.range(key..end).eq(model.range(key..end)));
+                assert!(map.range(..=key).rev().eq(model.range(..=key).rev()));
+            }
+        }
+        assert_eq!(map.len(), model.len());
+        assert_eq!(map.get(&key), model.get(&key));
+    }
+    assert!(map.iter().eq(model.iter()));
+    assert!(map.iter().rev().eq(model.iter().rev()));
+}
+
+#[test]
+fn set_operations() {
+    let mut set = StackBTreeSet::<u32, 8>::new();
+    assert!(set.insert(3));
+    assert!(set.insert(1));
+    assert!(!set.insert(3));
+    set.extend([9, 4, 7]);
+    assert_eq!((set.first(), set.last()), (Some(&1), Some(&9)));
+    assert!(set.contains(&4));
+    assert_eq!(set.take(&4), Some(4));
+    assert!(!set.remove(&4));
+    assert_eq!(set.range(2..8).copied().collect::<Vec<_>>(), [3, 7]);
+    assert_eq!(format!("{set:?}"), "{1, 3, 7, 9}");
+    assert_eq!(set.pop_first(), Some(1));
+    assert_eq!(set.pop_last(), Some(9));
+    assert_eq!(set, [7, 3].into_iter().collect::<StackBTreeSet<_, 1>>());
+
+    let mut full = StackBTreeSet::<u32, 1>::new();
+    full.extend(0..11);
+    assert_eq!(full.try_insert(11).unwrap_err().element(), 11);
+    assert_eq!(full.try_insert(10).ok(), Some(false));
+}
+
+#[test]
+fn set_matches_std_model() {
+    let mut set = StackBTreeSet::<u16, 80>::new();
+    let mut model = BTreeSet::new();
+    let mut rng = XorShift::new(0x1234_5678);
+    for _ in 0..5000 {
+        let state = rng.next_u64() as u32;
+        let item = (state >> 4) as u16 % 300;
+        if state.is_multiple_of(3) {
+            assert_eq!(set.remove(&item), model.remove(&item));
+        } else {
+            assert_eq!(set.insert(item), model.insert(item));
+        }
+    }
+    assert!(set.iter().eq(model.iter()));
+    assert!(set.into_iter().rev().eq(model.into_iter().rev()));
+}
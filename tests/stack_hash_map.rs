@@ -0,0 +1,207 @@
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, BuildHasherDefault, Hash, Hasher},
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use no_std_collections::{
+    stack_hash_map::Entry,
+    testing::{Tracked, Tracker, XorShift},
+    StackHashMap, StackHashSet,
+};
+
+/// Sends every key to the same few slots, to exercise long probe sequences.
+#[derive(Default)]
+struct Colliding(u64);
+
+impl Hasher for Colliding {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0 += bytes.iter().map(|&b| b as u64).sum::<u64>() % 3;
+    }
+
+    fn finish(&self) -> u64 {
+        self.0 << 32
+    }
+}
+
+type CollidingMap<V, const N: usize> = StackHashMap<u32, V, N, BuildHasherDefault<Colliding>>;
+
+#[test]
+fn insert_get_remove() {
+    let mut map = StackHashMap::<&str, u32, 8>::new();
+    assert_eq!(map.insert("a", 1), None);
+    assert_eq!(map.insert("b", 2), None);
+    assert_eq!(map.insert("a", 3), Some(1));
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get("a"), Some(&3));
+    assert_eq!(map["b"], 2);
+    *map.get_mut("b").unwrap() += 1;
+    assert_eq!(map.remove("b"), Some(3));
+    assert_eq!(map.remove("b"), None);
+    assert!(!map.contains_key("b"));
+    assert_eq!(format!("{map:?}"), r#"{"a": 3}"#);
+}
+
+#[test]
+fn full_map_reports_capacity_errors() {
+    let mut map = CollidingMap::<u32, 4>::default();
+    assert!(map.try_extend((0..4).map(|key| (key, key))).is_ok());
+    assert_eq!(map.try_insert(2, 20), Ok(Some(2)));
+    assert_eq!(map.try_insert(9, 9).unwrap_err().element(), (9, 9));
+    match map.entry(9) {
+        Entry::Vacant(entry) => assert_eq!(entry.try_insert(9).unwrap_err().element(), (9, 9)),
+        Entry::Occupied(_) => unreachable!(),
+    }
+    let result = catch_unwind(AssertUnwindSafe(|| map.insert(10, 10)));
+    assert!(result.is_err());
+    // Lookups of missing keys terminate on a full table.
+    assert_eq!(map.get(&10), None);
+    assert_eq!(map.remove(&0), Some(0));
+    assert_eq!(map.try_insert(9, 9), Ok(None));
+    let mut keys: Vec<u32> = map.keys().copied().collect();
+    keys.sort();
+    assert_eq!(keys, [1, 2, 3, 9]);
+}
+
+#[test]
+fn entry_api() {
+    let mut counts = StackHashMap::<char, usize, 8>::new();
+    for c in "abracadabra".chars() {
+        *counts.entry(c).or_default() += 1;
+    }
+    assert_eq!(counts.len(), 5);
+    assert_eq!(counts[&'a'], 5);
+    match counts.entry('c') {
+        Entry::Occupied(entry) => assert_eq!(entry.remove_entry(), ('c', 1)),
+        Entry::Vacant(_) => unreachable!(),
+    }
+    assert_eq!(*counts.entry('z').and_modify(|v| *v = 0).or_insert(9), 9);
+    counts.entry('a').and_modify(|v| *v = 0);
+    assert_eq!(counts[&'a'], 0);
+}
+
+#[test]
+fn removal_keeps_colliding_keys_reachable() {
+    let mut map = CollidingMap::<u32, 16>::default();
+    let mut model = HashMap::new();
+    let mut rng = XorShift::new(0x2545_f491);
+    for _ in 0..5000 {
+        let state = rng.next_u64() as u32;
+        let key = state % 24;
+        if state & 0x100 == 0 {
+            let result = map.try_insert(key, state).map_err(|err| err.element());
+            match model.insert(key, state) {
+                Some(old) => assert_eq!(result, Ok(Some(old))),
+                None if model.len() > 16 => {
+                    model.remove(&key);
+                    assert!(result.is_err());
+                }
+                None => assert_eq!(result, Ok(None)),
+            }
+        } else {
+            assert_eq!(map.remove(&key), model.remove(&key));
+        }
+        assert_eq!(map.len(), model.len());
+        for (key, value) in &model {
+            assert_eq!(map.get(key), Some(value));
+        }
+    }
+}
+
+#[test]
+fn retain_drops_rejected_entries_even_if_the_predicate_panics() {
+    let tracker = Tracker::new();
+    let mut map = CollidingMap::<Tracked, 16>::default();
+    for key in 0..12 {
+        map.insert(key, tracker.track(key));
+    }
+    let mut calls = 0;
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        map.retain(|&key, _| {
+            calls += 1;
+            if calls == 10 {
+                panic!("retain");
+            }
+            key % 3 != 0
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(tracker.live(), map.len() as isize);
+    for (key, value) in &map {
+        assert_eq!(value.value(), *key);
+        assert_eq!(map.get(key).map(Tracked::value), Some(*key));
+    }
+
+    map.retain(|&key, _| key % 2 == 0);
+    let mut keys: Vec<u32> = map.keys().copied().collect();
+    keys.sort();
+    assert!(keys.iter().all(|key| key % 2 == 0));
+    for key in keys {
+        assert!(map.contains_key(&key));
+    }
+    let clone = map.clone();
+    assert_eq!(clone.len(), map.len());
+    let mut iter = clone.into_iter();
+    iter.next();
+    drop(iter);
+    drop(map);
+    assert_eq!(tracker.live(), 0);
+}
+
+#[test]
+fn clone_drops_a_cloned_key_when_its_value_panics() {
+    #[derive(Clone, PartialEq)]
+    struct Key(Tracked);
+
+    impl Eq for Key {}
+
+    impl Hash for Key {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.0.value().hash(state);
+        }
+    }
+
+    let tracker = Tracker::new();
+    let mut map = StackHashMap::<Key, Tracked, 8>::new();
+    for key in 0..3 {
+        map.insert(Key(tracker.track(key)), tracker.track(key));
+    }
+    // The first key and value clone fine, then the second value panics.
+    tracker.panic_after_clones(3);
+    assert!(catch_unwind(AssertUnwindSafe(|| map.clone())).is_err());
+    assert_eq!(tracker.live(), 6);
+    tracker.disarm();
+    drop(map);
+    assert_eq!(tracker.live(), 0);
+}
+
+#[test]
+fn iterators_clone_without_cloning_values() {
+    struct Opaque(u32);
+    let mut map = StackHashMap::<u32, Opaque, 4>::new();
+    map.insert(1, Opaque(10));
+    assert_eq!(map.values().clone().map(|value| value.0).sum::<u32>(), 10);
+    assert_eq!(map.keys().clone().count(), 1);
+}
+
+#[test]
+fn hash_set() {
+    let mut set: StackHashSet<u32, 8> = [3, 1, 4, 1, 5].into_iter().collect();
+    assert_eq!(set.len(), 4);
+    assert!(set.insert(9));
+    assert!(!set.insert(3));
+    assert!(set.contains(&4));
+    assert!(set.remove(&4));
+    assert_eq!(set.take(&5), Some(5));
+    set.retain(|&item| item != 1);
+    let mut items: Vec<u32> = set.iter().copied().collect();
+    items.sort();
+    assert_eq!(items, [3, 9]);
+    assert_eq!(set, [9, 3].into_iter().collect::<StackHashSet<u32, 4>>());
+
+    let mut full = StackHashSet::<u32, 2>::new();
+    assert_eq!(full.try_insert(1), Ok(true));
+    assert_eq!(full.try_insert(2), Ok(true));
+    assert_eq!(full.try_insert(3).unwrap_err().element(), 3);
+    assert_eq!(full.hasher().hash_one(1), full.hasher().hash_one(1));
+}
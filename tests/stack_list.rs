@@ -0,0 +1,200 @@
+use std::collections::VecDeque;
+
+use no_std_collections::{
+    testing::{Tracker, XorShift},
+    StackList,
+};
+
+#[test]
+fn push_pop_both_ends() {
+    let mut list = StackList::<u32, 4>::new();
+    list.push_back(2);
+    list.push_front(1);
+    list.push_back(3);
+    assert_eq!(list.len(), 3);
+    assert_eq!((list.front(), list.back()), (Some(&1), Some(&3)));
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [3, 2, 1]);
+    list.iter_mut().for_each(|value| *value *= 10);
+    *list.back_mut().unwrap() += 1;
+    assert_eq!(list.pop_front(), Some(10));
+    assert_eq!(list.pop_back(), Some(31));
+    assert_eq!(list.pop_back(), Some(20));
+    assert_eq!(list.pop_back(), None);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn keys_allow_removal_from_the_middle() {
+    let mut list = StackList::<char, 4, u8>::new();
+    let a = list.push_back('a');
+    let b = list.push_back('b');
+    let c = list.push_back('c');
+    assert_eq!(list.remove(b), Some('b'));
+    assert_eq!(list.remove(b), None);
+    assert_eq!(list.get(b), None);
+    assert_eq!((list.get(a), list.get(c)), (Some(&'a'), Some(&'c')));
+
+    // The freed node is reused before the unused ones.
+    assert_eq!(list.push_front('z'), b);
+    list.push_back('d');
+    assert_eq!(list.try_push_back('e').unwrap_err().element(), 'e');
+    assert_eq!(list.try_push_front('e').unwrap_err().element(), 'e');
+    assert_eq!(format!("{list:?}"), "['z', 'a', 'c', 'd']");
+}
+
+#[test]
+fn cursor_walks_through_the_ghost() {
+    let mut list: StackList<u32, 8, u16> = (1..=3).collect();
+    let mut cursor = list.cursor_front_mut();
+    assert_eq!(cursor.current(), Some(&mut 1));
+    cursor.move_prev();
+    assert_eq!(cursor.key(), None);
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.peek_next(), Some(&mut 1));
+    assert_eq!(cursor.peek_prev(), Some(&mut 3));
+    cursor.move_prev();
+    assert_eq!(cursor.current(), Some(&mut 3));
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&mut 1));
+}
+
+#[test]
+fn cursor_edits() {
+    let mut list: StackList<u32, 8> = (1..=3).collect();
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    cursor.insert_before(10);
+    cursor.insert_after(20);
+    assert_eq!(cursor.remove_current(), Some(2));
+    assert_eq!(cursor.current(), Some(&mut 20));
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.key(), None);
+    assert_eq!(cursor.remove_current(), None);
+    cursor.insert_after(0);
+    cursor.insert_before(99);
+    assert_eq!(
+        list.iter().copied().collect::<Vec<_>>(),
+        [0, 1, 10, 20, 3, 99]
+    );
+    assert!(list.cursor_mut(7).is_none());
+}
+
+#[test]
+fn cursor_at_a_key() {
+    let mut list = StackList::<&str, 4>::new();
+    list.push_back("a");
+    let b = list.push_back("b");
+    list.push_back("c");
+    let mut cursor = list.cursor_mut(b).unwrap();
+    assert_eq!(cursor.key(), Some(b));
+    assert_eq!(cursor.remove_current(), Some("b"));
+    assert!(list.cursor_mut(b).is_none());
+    assert_eq!(list, StackList::<&str, 2, u8>::from_iter(["a", "c"]));
+}
+
+#[test]
+fn splice() {
+    let mut list: StackList<u32, 8> = [1, 5].into_iter().collect();
+    let mut cursor = list.cursor_front_mut();
+    cursor.splice_after(StackList::<u32, 4, u8>::from_iter([2, 3]));
+    assert_eq!(cursor.current(), Some(&mut 1));
+    cursor.move_prev();
+    cursor.move_prev();
+    cursor.splice_before(StackList::<u32, 4>::from_iter([4]));
+    assert_eq!(cursor.current(), Some(&mut 5));
+    let rejected = cursor
+        .try_splice_before(StackList::<u32, 4>::from_iter([6, 7, 8, 9]))
+        .unwrap_err()
+        .element();
+    assert_eq!(rejected.len(), 4);
+    cursor.move_next();
+    cursor.splice_before(StackList::<u32, 4>::from_iter([6, 7, 8]));
+    cursor.splice_after(StackList::<u32, 4>::new());
+    assert_eq!(
+        list.iter().copied().collect::<Vec<_>>(),
+        [1, 2, 3, 4, 5, 6, 7, 8]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Capacity overflow")]
+fn splice_panics_when_full() {
+    let mut list: StackList<u32, 2> = [1].into_iter().collect();
+    list.cursor_back_mut()
+        .splice_after(StackList::<u32, 2>::from_iter([2, 3]));
+}
+
+#[test]
+fn matches_a_vec_deque_model() {
+    let mut list = StackList::<u32, 16, u8>::new();
+    let mut model = VecDeque::new();
+    let mut rng = XorShift::new(0xdead_beef);
+    for _ in 0..3000 {
+        let state = rng.next_u64() as u32;
+        match state % 6 {
+            0 => assert_eq!(
+                list.try_push_back(state).is_ok(),
+                model.len() < 16 && {
+                    model.push_back(state);
+                    true
+                }
+            ),
+            1 => assert_eq!(
+                list.try_push_front(state).is_ok(),
+                model.len() < 16 && {
+                    model.push_front(state);
+                    true
+                }
+            ),
+            2 => assert_eq!(list.pop_front(), model.pop_front()),
+            3 => assert_eq!(list.pop_back(), model.pop_back()),
+            _ => {
+                let at = state as usize % (model.len() + 1);
+                let mut cursor = list.cursor_front_mut();
+                for _ in 0..at {
+                    cursor.move_next();
+                }
+                if state & 0x100 == 0 {
+                    assert_eq!(cursor.remove_current(), model.remove(at));
+                } else if model.len() < 16 {
+                    cursor.insert_before(state);
+                    model.insert(at, state);
+                }
+            }
+        }
+        assert!(list.iter().eq(&model));
+        assert!(list.iter().rev().eq(model.iter().rev()));
+    }
+}
+
+#[test]
+fn values_are_dropped_once() {
+    let tracker = Tracker::new();
+    let mut list = StackList::<_, 8>::new();
+    let keys: Vec<_> = (0..6).map(|i| list.push_back(tracker.track(i))).collect();
+    drop(list.remove(keys[2]));
+    drop(list.cursor_front_mut().remove_current());
+    assert_eq!(tracker.live(), 4);
+
+    let clone = list.clone();
+    assert_eq!(tracker.live(), 8);
+    assert!(clone.iter().map(|item| item.value()).eq([1, 3, 4, 5]));
+    drop(clone);
+
+    let mut into_iter = list.into_iter();
+    assert_eq!(into_iter.next().map(|item| item.value()), Some(1));
+    assert_eq!(into_iter.next_back().map(|item| item.value()), Some(5));
+    drop(into_iter);
+    assert_eq!(tracker.live(), 0);
+}
+
+#[test]
+fn iterators_are_send_and_sync() {
+    fn check<T: Send + Sync>(_: T) {}
+    let mut list = StackList::<u32, 4, u8>::new();
+    check(list.iter());
+    check(list.iter_mut());
+}
@@ -0,0 +1,111 @@
+use core::num::NonZeroUsize;
+
+use no_std_collections::{
+    testing::{Tracked, Tracker, Zst},
+    StackVec,
+};
+
+fn tracked<const N: usize>(tracker: &Tracker, len: u32) -> StackVec<Tracked, N> {
+    (0..len).map(|i| tracker.track(i)).collect()
+}
+
+fn values<I: IntoIterator<Item = Tracked>>(iter: I) -> std::vec::Vec<u32> {
+    iter.into_iter().map(|item| item.value()).collect()
+}
+
+#[test]
+fn yields_from_both_ends() {
+    let tracker = Tracker::new();
+    let mut iter = tracked::<8>(&tracker, 6).into_iter();
+    assert_eq!(iter.next_back().map(|item| item.value()), Some(5));
+    assert_eq!(iter.next().map(|item| item.value()), Some(0));
+    assert_eq!(iter.next_back().map(|item| item.value()), Some(4));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(values(iter.by_ref().rev()), [3, 2, 1]);
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+    drop(iter);
+    assert_eq!(tracker.live(), 0);
+}
+
+#[test]
+fn drops_only_the_remaining_window() {
+    let tracker = Tracker::new();
+    let mut iter = tracked::<16>(&tracker, 10).into_iter();
+    let first = iter.next().unwrap();
+    let last = iter.next_back().unwrap();
+    assert_eq!(tracker.live(), 10);
+    drop(iter);
+    assert_eq!(tracker.live(), 2);
+    drop((first, last));
+    assert_eq!(tracker.live(), 0);
+}
+
+#[test]
+fn clone_copies_the_remaining_window() {
+    let tracker = Tracker::new();
+    let mut iter = tracked::<8>(&tracker, 5).into_iter();
+    iter.next();
+    iter.next_back();
+    let clone = iter.clone();
+    assert_eq!(tracker.live(), 6);
+    assert_eq!(values(clone), [1, 2, 3]);
+    assert_eq!(values(iter), [1, 2, 3]);
+    assert_eq!(tracker.live(), 0);
+
+    let mut iter = tracked::<8>(&tracker, 5).into_iter();
+    iter.next();
+    tracker.panic_after_clones(2);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| iter.clone()));
+    assert!(result.is_err());
+    assert_eq!(tracker.live(), 4);
+    drop(iter);
+    assert_eq!(tracker.live(), 0);
+}
+
+#[test]
+fn into_stack_vec_keeps_the_remaining_items() {
+    let tracker = Tracker::new();
+    let mut iter = tracked::<8>(&tracker, 7).into_iter();
+    iter.next();
+    iter.next();
+    iter.next_back();
+    iter.as_mut_slice()[0] = tracker.track(20);
+    let vec = iter.into_stack_vec();
+    assert_eq!(tracker.live(), 4);
+    assert_eq!(values(vec), [20, 3, 4, 5]);
+    assert_eq!(tracker.live(), 0);
+}
+
+#[test]
+fn advance_and_nth_drop_skipped_items() {
+    let tracker = Tracker::new();
+    let mut iter = tracked::<16>(&tracker, 10).into_iter();
+    assert_eq!(iter.advance_by(3), Ok(()));
+    assert_eq!(tracker.live(), 7);
+    assert_eq!(iter.nth(1).map(|item| item.value()), Some(4));
+    assert_eq!(iter.nth_back(2).map(|item| item.value()), Some(7));
+    assert_eq!(tracker.live(), 2);
+    assert_eq!(iter.as_slice().len(), 2);
+    assert_eq!(iter.advance_back_by(5), Err(NonZeroUsize::new(3).unwrap()));
+    assert_eq!(iter.advance_by(1), Err(NonZeroUsize::new(1).unwrap()));
+    assert!(iter.nth(1).is_none());
+    assert_eq!(tracker.live(), 0);
+}
+
+#[test]
+fn zero_sized_items_beyond_the_array_length() {
+    let base = Zst::live();
+    let mut vec = StackVec::<Zst, 0>::new();
+    for _ in 0..5 {
+        vec.push(Zst::new());
+    }
+    let mut iter = vec.into_iter();
+    assert!(iter.next_back().is_some());
+    assert_eq!(iter.advance_by(2), Ok(()));
+    assert_eq!(Zst::live() - base, 2);
+    let rest = iter.into_stack_vec();
+    assert_eq!(rest.len(), 2);
+    drop(rest);
+    assert_eq!(Zst::live(), base);
+}
@@ -0,0 +1,58 @@
+use no_std_collections::{
+    testing::Tracker,
+    traits::compare::{ByKey, Reversed},
+    TopK,
+};
+
+#[test]
+fn keeps_the_greatest_items() {
+    let mut top = TopK::<u32, 3>::new();
+    assert_eq!(top.push(5), None);
+    assert_eq!(top.push(1), None);
+    assert_eq!(top.push(7), None);
+    assert!(top.is_full());
+    assert_eq!(top.threshold(), Some(&1));
+    assert_eq!(top.push(0), Some(0));
+    assert_eq!(top.push(6), Some(1));
+    top.extend([2, 9, 5]);
+    assert_eq!(top.into_sorted_stack_vec(), [9, 7, 6]);
+
+    let top: TopK<u32, 4> = (0..100).map(|n| (n * 37) % 101).collect();
+    assert_eq!(top.into_sorted_stack_vec(), [100, 99, 98, 97]);
+
+    let mut none = TopK::<u32, 0>::new();
+    assert_eq!(none.push(3), Some(3));
+    assert!(none.is_empty());
+}
+
+#[test]
+fn custom_orders() {
+    let smallest: TopK<i32, 2, Reversed> = [4, -2, 8, 0].into_iter().collect();
+    assert_eq!(smallest.into_sorted_stack_vec(), [-2, 0]);
+
+    let mut longest = TopK::<&str, 2, _>::with_comparator(ByKey::new(|s: &&str| s.len()));
+    longest.extend(["a", "abcd", "ab", "abc"]);
+    assert_eq!(longest.into_sorted_stack_vec(), ["abcd", "abc"]);
+}
+
+#[test]
+fn evicted_items_are_dropped_once() {
+    let tracker = Tracker::new();
+    let mut top =
+        TopK::<_, 4, _>::with_comparator(ByKey::new(|t: &no_std_collections::testing::Tracked| {
+            t.value()
+        }));
+    for value in 0..32 {
+        drop(top.push(tracker.track(value)));
+    }
+    assert_eq!(tracker.live(), 4);
+    let values: Vec<u32> = top
+        .clone()
+        .into_sorted_stack_vec()
+        .iter()
+        .map(|t| t.value())
+        .collect();
+    assert_eq!(values, [31, 30, 29, 28]);
+    drop(top);
+    assert_eq!(tracker.live(), 0);
+}
@@ -0,0 +1,75 @@
+use no_std_collections::{
+    vec_map::{Entry, Insertion, Sorted},
+    StackVec, VecMap,
+};
+
+type StackMap<O> = VecMap<u32, &'static str, StackVec<(u32, &'static str), 4>, O>;
+
+#[test]
+fn iteration_follows_the_selected_order() {
+    let pairs = [(3, "c"), (1, "a"), (2, "b")];
+    let insertion: StackMap<Insertion> = pairs.into_iter().collect();
+    let sorted: StackMap<Sorted> = pairs.into_iter().collect();
+    assert!(insertion.keys().eq(&[3, 1, 2]));
+    assert!(sorted.keys().eq(&[1, 2, 3]));
+    assert_eq!(insertion, sorted);
+    assert_eq!(sorted[&2], "b");
+}
+
+#[test]
+fn insert_get_remove() {
+    let mut map = VecMap::<String, u32, Vec<_>, Sorted>::new();
+    assert_eq!(map.insert("b".to_owned(), 2), None);
+    assert_eq!(map.insert("a".to_owned(), 1), None);
+    assert_eq!(map.insert("b".to_owned(), 3), Some(2));
+    assert_eq!(map.get("b"), Some(&3));
+    *map.get_mut("a").unwrap() += 10;
+    assert!(map.values().eq(&[11, 3]));
+    for (_, value) in &mut map {
+        *value *= 2;
+    }
+    assert_eq!(map.remove("a"), Some(22));
+    assert_eq!(map.remove("a"), None);
+    assert!(!map.contains_key("a"));
+    assert_eq!(format!("{map:?}"), r#"{"b": 6}"#);
+}
+
+#[test]
+fn entry_api() {
+    let mut counts = VecMap::<char, usize, StackVec<_, 8>>::new();
+    for c in "abracadabra".chars() {
+        *counts.entry(c).or_default() += 1;
+    }
+    assert_eq!(
+        counts.as_slice(),
+        [('a', 5), ('b', 2), ('r', 2), ('c', 1), ('d', 1)]
+    );
+
+    match counts.entry('c') {
+        Entry::Occupied(entry) => assert_eq!(entry.remove_entry(), ('c', 1)),
+        Entry::Vacant(_) => unreachable!(),
+    }
+    let value = counts.entry('z').and_modify(|v| *v = 0).or_insert(9);
+    assert_eq!(*value, 9);
+    assert_eq!(counts.entry('a').and_modify(|v| *v = 0).key(), &'a');
+    assert_eq!(counts[&'a'], 0);
+}
+
+#[test]
+fn capacity_errors_are_reported() {
+    let mut map =
+        StackMap::<Sorted>::try_from_iter([(1, "a"), (2, "b"), (3, "c"), (4, "d")]).unwrap();
+    assert_eq!(map.try_insert(2, "B"), Ok(Some("b")));
+    assert_eq!(map.try_insert(5, "e").unwrap_err().element(), (5, "e"));
+    match map.entry(0) {
+        Entry::Vacant(entry) => assert!(entry.try_insert("z").is_err()),
+        Entry::Occupied(_) => unreachable!(),
+    }
+    assert_eq!(map.len(), 4);
+
+    let err = StackMap::<Insertion>::try_from_iter((0..6).map(|key| (key, "x"))).unwrap_err();
+    assert_eq!(err.element(), (4, "x"));
+    map.retain(|&key, _| key % 2 == 0);
+    assert!(map.try_extend([(6, "f"), (8, "h")]).is_ok());
+    assert!(map.keys().eq(&[2, 4, 6, 8]));
+}